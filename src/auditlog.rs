@@ -0,0 +1,544 @@
+use crate::client::{OpsviewClient, Params};
+use crate::config::ConfigObjectType;
+use crate::prelude::*;
+use crate::util::{parse_api_timestamp, API_TIME_FORMAT};
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::fmt;
+
+/// The most words in the name of a [`ConfigObjectType`], as in "Host Check Command".
+const MAX_OBJECT_TYPE_WORDS: usize = 3;
+
+lazy_static! {
+    static ref REF_REGEX: Regex = Regex::new(r"/rest/config/([a-z_]+)/(\d+)").unwrap();
+    static ref ID_REGEX: Regex = Regex::new(r"\bid\s*[=:]?\s*(\d+)").unwrap();
+    static ref QUOTED_NAME_REGEX: Regex = Regex::new(r#"'([^']*)'|"([^"]*)""#).unwrap();
+    static ref NAME_FIELD_REGEX: Regex = Regex::new(r"\bname\s*[=:]\s*([^\s,()]+)").unwrap();
+}
+
+/// The kind of action recorded by an [`AuditLogEntry`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuditAction {
+    /// An object was created.
+    Create,
+    /// An object was updated.
+    Update,
+    /// An object was deleted.
+    Delete,
+    /// The configuration was reloaded.
+    Reload,
+    /// A user logged in.
+    Login,
+    /// Any other action, with the text that could not be classified.
+    Other(String),
+}
+
+impl AuditAction {
+    /// Classifies the text of an audit log entry into an `AuditAction` by the verb it starts with.
+    ///
+    /// Quoted names and `name=` fields are ignored, so an object named e.g. `'deleted-hosts'` does
+    /// not change the action.
+    fn from_text(text: &str) -> AuditAction {
+        let stripped = strip_names(text).to_lowercase();
+        let mut words = stripped.split_whitespace();
+        let verb = words.next().unwrap_or_default();
+
+        match verb {
+            "deleted" | "delete" | "removed" | "remove" => AuditAction::Delete,
+            "created" | "create" | "added" | "add" => AuditAction::Create,
+            "updated" | "update" | "edited" | "edit" | "modified" | "changed" => {
+                AuditAction::Update
+            }
+            _ if verb.starts_with("reload") => AuditAction::Reload,
+            "login" => AuditAction::Login,
+            "logged" | "log" if words.next() == Some("in") => AuditAction::Login,
+            _ => AuditAction::Other(text.to_string()),
+        }
+    }
+}
+
+/// Replaces quoted names and `name=` fields in the text of an audit log entry with spaces, so that
+/// words in object names are not taken for actions or object types.
+fn strip_names(text: &str) -> String {
+    let unquoted = QUOTED_NAME_REGEX.replace_all(text, " ");
+    NAME_FIELD_REGEX.replace_all(&unquoted, " ").into_owned()
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuditAction::Create => write!(f, "create"),
+            AuditAction::Update => write!(f, "update"),
+            AuditAction::Delete => write!(f, "delete"),
+            AuditAction::Reload => write!(f, "reload"),
+            AuditAction::Login => write!(f, "login"),
+            AuditAction::Other(_) => write!(f, "other"),
+        }
+    }
+}
+
+/// A reference to the configuration object an [`AuditLogEntry`] refers to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditObjectRef {
+    /// The type of the object.
+    pub object_type: ConfigObjectType,
+    /// The ID of the object, if it could be determined.
+    pub id: Option<u64>,
+    /// The name of the object, if it could be determined.
+    pub name: Option<String>,
+}
+
+impl AuditObjectRef {
+    /// Returns the `ref_` of the object, e.g. `/rest/config/host/12`, if the ID is known.
+    pub fn ref_(&self) -> Option<String> {
+        self.id.map(|id| self.object_type.ref_for_id(id))
+    }
+
+    /// Returns true if the referenced object is of the [`ConfigObject`] type `T`.
+    pub fn is<T: ConfigObject>(&self) -> bool {
+        ConfigObjectType::of::<T>() == Some(self.object_type)
+    }
+
+    /// Fetches the referenced object from the Opsview API as the [`ConfigObject`] type `T`.
+    ///
+    /// The object is looked up by its `ref_` if the ID is known, and by its name otherwise. Note
+    /// that an object that has since been deleted can no longer be fetched.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewClientError::InvalidRef` if the object is not of type `T`, an
+    /// `OpsviewClientError::MissingIdentifiers` if neither ID nor name is known, or any error
+    /// returned while fetching the object.
+    pub async fn resolve<T: ConfigObject>(
+        &self,
+        client: &OpsviewClient,
+    ) -> Result<T, OpsviewClientError> {
+        if !self.is::<T>() {
+            return Err(OpsviewClientError::InvalidRef(
+                format!(
+                    "Object is a {} and not of the requested type",
+                    self.object_type
+                ),
+                self.ref_().unwrap_or_default(),
+            ));
+        }
+
+        match (self.ref_(), &self.name) {
+            (Some(ref_), _) => client.get_object_config_by_ref::<T>(&ref_, None).await,
+            (None, Some(name)) => {
+                client
+                    .get_object_config_by_key::<T>("name", name, None)
+                    .await
+            }
+            (None, None) => Err(OpsviewClientError::MissingIdentifiers(
+                "Cannot resolve object: neither id nor name are known.".to_string(),
+            )),
+        }
+    }
+
+    /// Extracts an object reference from the text of an audit log entry.
+    ///
+    /// A `/rest/config/...` ref in the text is preferred. Otherwise the first phrase of up to three
+    /// words naming an object type is used, together with any quoted name or `name=` field and
+    /// any `id=` field in the text.
+    fn from_text(text: &str) -> Option<AuditObjectRef> {
+        let name = QUOTED_NAME_REGEX
+            .captures(text)
+            .and_then(|c| c.get(1).or_else(|| c.get(2)))
+            .or_else(|| NAME_FIELD_REGEX.captures(text).and_then(|c| c.get(1)))
+            .map(|m| m.as_str().to_string());
+
+        if let Some(captures) = REF_REGEX.captures(text) {
+            if let (Some(object_type), Ok(id)) = (
+                ConfigObjectType::from_endpoint(&captures[1]),
+                captures[2].parse::<u64>(),
+            ) {
+                return Some(AuditObjectRef {
+                    object_type,
+                    id: Some(id),
+                    name,
+                });
+            }
+        }
+
+        // Only look for the object type outside of any names, preferring the longest phrase so
+        // that e.g. "host check command" is not taken for a host.
+        let unquoted = strip_names(text);
+        let words: Vec<&str> = unquoted
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        let object_type = (0..words.len()).find_map(|i| {
+            (1..=MAX_OBJECT_TYPE_WORDS)
+                .rev()
+                .filter_map(|n| words.get(i..i + n))
+                .find_map(|phrase| phrase.join(" ").parse().ok())
+        })?;
+
+        let id = ID_REGEX
+            .captures(&unquoted)
+            .and_then(|c| c[1].parse::<u64>().ok());
+
+        Some(AuditObjectRef {
+            object_type,
+            id,
+            name,
+        })
+    }
+}
+
+/// Represents a single entry in the Opsview audit log.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(try_from = "RawAuditLogEntry")]
+pub struct AuditLogEntry {
+    /// The ID of the entry.
+    pub id: Option<u64>,
+    /// The time the action was performed.
+    pub timestamp: DateTime<Utc>,
+    /// The name of the user that performed the action.
+    pub username: String,
+    /// The kind of action performed.
+    pub action: AuditAction,
+    /// The object the action was performed on, if it could be determined.
+    pub object: Option<AuditObjectRef>,
+    /// The full text of the entry.
+    pub text: String,
+    /// The ID of the reload the entry was applied in, if any.
+    pub reload_id: Option<u64>,
+}
+
+/// The audit log entry as returned by the API, before any type conversion.
+#[derive(Deserialize)]
+struct RawAuditLogEntry {
+    #[serde(default, deserialize_with = "deserialize_string_or_number_to_u64")]
+    id: Option<u64>,
+    #[serde(alias = "timestamp", alias = "time")]
+    datetime: Value,
+    #[serde(alias = "user")]
+    username: String,
+    text: String,
+    #[serde(default, deserialize_with = "deserialize_string_or_number_to_u64")]
+    reloadid: Option<u64>,
+}
+
+impl TryFrom<RawAuditLogEntry> for AuditLogEntry {
+    type Error = OpsviewClientError;
+
+    fn try_from(raw: RawAuditLogEntry) -> Result<Self, Self::Error> {
+        Ok(AuditLogEntry {
+            id: raw.id,
+            timestamp: parse_api_timestamp(&raw.datetime)?,
+            username: raw.username,
+            action: AuditAction::from_text(&raw.text),
+            object: AuditObjectRef::from_text(&raw.text),
+            text: raw.text,
+            reload_id: raw.reloadid,
+        })
+    }
+}
+
+/// A query against the Opsview audit log.
+///
+/// Use [`AuditLogQuery::builder`] to construct a new query and pass it to
+/// [`OpsviewClient::get_audit_log`](crate::client::OpsviewClient::get_audit_log).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AuditLogQuery {
+    /// Only include entries at or after this time.
+    pub start_time: Option<DateTime<Utc>>,
+    /// Only include entries at or before this time.
+    pub end_time: Option<DateTime<Utc>>,
+    /// Only include entries by this user.
+    pub username: Option<String>,
+    /// Only include entries referring to an object of this type.
+    pub object_type: Option<ConfigObjectType>,
+    /// Only include entries whose text contains this string.
+    pub text: Option<String>,
+    /// The number of entries to fetch per page.
+    pub rows_per_page: Option<u64>,
+}
+
+impl AuditLogQuery {
+    /// Returns a builder for constructing an `AuditLogQuery`.
+    pub fn builder() -> AuditLogQueryBuilder {
+        AuditLogQueryBuilder::default()
+    }
+
+    /// Returns the query as a list of parameters to be used with the `/rest/auditlog` endpoint.
+    ///
+    /// The time range, user and text are sent as a `json_filter`. The object type cannot be
+    /// filtered on by the API and is instead applied by [`AuditLogQuery::matches`].
+    pub fn params(&self) -> Params {
+        let mut filters: Vec<Value> = Vec::new();
+
+        if let Some(start_time) = self.start_time {
+            filters.push(json!({
+                "datetime": {">=": start_time.format(API_TIME_FORMAT).to_string()}
+            }));
+        }
+
+        if let Some(end_time) = self.end_time {
+            filters.push(json!({
+                "datetime": {"<=": end_time.format(API_TIME_FORMAT).to_string()}
+            }));
+        }
+
+        if let Some(username) = &self.username {
+            filters.push(json!({ "username": username }));
+        }
+
+        if let Some(text) = &self.text {
+            filters.push(json!({ "text": {"-like": format!("%{}%", text)} }));
+        }
+
+        let mut params: Params = vec![("order".to_string(), "datetime".to_string())];
+
+        if !filters.is_empty() {
+            params.push((
+                "json_filter".to_string(),
+                json!({ "-and": filters }).to_string(),
+            ));
+        }
+
+        if let Some(rows) = self.rows_per_page {
+            params.push(("rows".to_string(), rows.to_string()));
+        }
+
+        params
+    }
+
+    /// Returns true if the entry matches the filters of the query that are applied client side.
+    pub fn matches(&self, entry: &AuditLogEntry) -> bool {
+        match self.object_type {
+            Some(object_type) => entry
+                .object
+                .as_ref()
+                .is_some_and(|o| o.object_type == object_type),
+            None => true,
+        }
+    }
+}
+
+/// Builder for [`AuditLogQuery`].
+#[derive(Clone, Debug, Default)]
+pub struct AuditLogQueryBuilder {
+    start_time: Option<DateTime<Utc>>,
+    end_time: Option<DateTime<Utc>>,
+    username: Option<String>,
+    object_type: Option<ConfigObjectType>,
+    text: Option<String>,
+    rows_per_page: Option<u64>,
+}
+
+impl AuditLogQueryBuilder {
+    /// Sets the start of the time range.
+    ///
+    /// # Arguments
+    /// * `start_time` - Only entries at or after this time will be included.
+    pub fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Sets the end of the time range.
+    ///
+    /// # Arguments
+    /// * `end_time` - Only entries at or before this time will be included.
+    pub fn end_time(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Sets the user to filter on.
+    ///
+    /// # Arguments
+    /// * `username` - Only entries by this user will be included.
+    pub fn username(mut self, username: &str) -> Self {
+        self.username = Some(username.to_string());
+        self
+    }
+
+    /// Sets the object type to filter on.
+    ///
+    /// # Arguments
+    /// * `object_type` - Only entries referring to an object of this type will be included.
+    pub fn object_type(mut self, object_type: ConfigObjectType) -> Self {
+        self.object_type = Some(object_type);
+        self
+    }
+
+    /// Sets the text to search for.
+    ///
+    /// # Arguments
+    /// * `text` - Only entries whose text contains this string will be included.
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Sets the number of entries to fetch per page.
+    ///
+    /// # Arguments
+    /// * `rows` - The number of entries per page. Must be greater than 0.
+    pub fn rows_per_page(mut self, rows: u64) -> Self {
+        self.rows_per_page = Some(rows);
+        self
+    }
+
+    /// Builds a new `AuditLogQuery`.
+    ///
+    /// # Errors
+    /// Returns an error if the start time is after the end time, if the username or text is
+    /// empty, or if `rows_per_page` is 0.
+    pub fn build(self) -> Result<AuditLogQuery, OpsviewConfigError> {
+        if let (Some(start), Some(end)) = (self.start_time, self.end_time) {
+            if start > end {
+                return Err(OpsviewConfigError::InvalidTimestamp(format!(
+                    "start time {} is after end time {}",
+                    start, end
+                )));
+            }
+        }
+
+        if self.username.as_deref() == Some("") {
+            return Err(OpsviewConfigError::RequiredFieldEmpty(
+                "username".to_string(),
+            ));
+        }
+
+        if self.text.as_deref() == Some("") {
+            return Err(OpsviewConfigError::RequiredFieldEmpty("text".to_string()));
+        }
+
+        if self.rows_per_page == Some(0) {
+            return Err(OpsviewConfigError::RequiredFieldEmpty(
+                "rows_per_page".to_string(),
+            ));
+        }
+
+        Ok(AuditLogQuery {
+            start_time: self.start_time,
+            end_time: self.end_time,
+            username: self.username,
+            object_type: self.object_type,
+            text: self.text,
+            rows_per_page: self.rows_per_page,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Host;
+    use chrono::TimeZone;
+
+    fn entry(text: &str) -> AuditLogEntry {
+        serde_json::from_value(json!({
+            "id": "1",
+            "datetime": "2024-03-01 12:30:00",
+            "username": "admin",
+            "text": text,
+            "reloadid": null
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_entry_with_ref() {
+        let e = entry("Deleted host 'web01' (/rest/config/host/12)");
+
+        assert_eq!(
+            e.timestamp,
+            Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 0).unwrap()
+        );
+        assert_eq!(e.action, AuditAction::Delete);
+
+        let object = e.object.unwrap();
+        assert!(object.is::<Host>());
+        assert_eq!(object.name, Some("web01".to_string()));
+        assert_eq!(object.ref_(), Some("/rest/config/host/12".to_string()));
+    }
+
+    #[test]
+    fn test_deserialize_entry_without_ref() {
+        let e = entry("Created host template: name=Network-Base, id=7");
+        let object = e.object.unwrap();
+
+        assert_eq!(e.action, AuditAction::Create);
+        assert_eq!(object.object_type, ConfigObjectType::HostTemplate);
+        assert_eq!(object.name, Some("Network-Base".to_string()));
+        assert_eq!(object.id, Some(7));
+    }
+
+    #[test]
+    fn test_object_type_not_taken_from_quoted_name() {
+        let e = entry("Updated service check 'host check'");
+        let object = e.object.unwrap();
+
+        assert_eq!(e.action, AuditAction::Update);
+        assert_eq!(object.object_type, ConfigObjectType::ServiceCheck);
+        assert_eq!(object.id, None);
+    }
+
+    #[test]
+    fn test_action_not_taken_from_names() {
+        let e = entry("Created host 'deleted-hosts'");
+        assert_eq!(e.action, AuditAction::Create);
+        assert_eq!(e.object.unwrap().name, Some("deleted-hosts".to_string()));
+
+        let e = entry("Updated role: name=remove-me");
+        assert_eq!(e.action, AuditAction::Update);
+
+        let e = entry("Host 'web01' was added to the reload queue");
+        assert!(matches!(e.action, AuditAction::Other(_)));
+
+        assert_eq!(entry("Reloaded configuration").action, AuditAction::Reload);
+        assert_eq!(entry("Logged in from 10.0.0.1").action, AuditAction::Login);
+    }
+
+    #[test]
+    fn test_longest_object_type_phrase_wins() {
+        let e = entry("Created host check command 'ping'");
+        assert_eq!(
+            e.object.unwrap().object_type,
+            ConfigObjectType::HostCheckCommand
+        );
+
+        let e = entry("Deleted host template: name=Base, id=3");
+        assert_eq!(
+            e.object.unwrap().object_type,
+            ConfigObjectType::HostTemplate
+        );
+    }
+
+    #[test]
+    fn test_unclassified_entry() {
+        let e = entry("Something unexpected happened");
+        assert!(matches!(e.action, AuditAction::Other(_)));
+        assert_eq!(e.object, None);
+    }
+
+    #[test]
+    fn test_audit_log_query() {
+        let query = AuditLogQuery::builder()
+            .username("admin")
+            .text("web01")
+            .object_type(ConfigObjectType::Host)
+            .build()
+            .unwrap();
+
+        let params = query.params();
+        let filter = params.iter().find(|(k, _)| k == "json_filter").unwrap();
+        let filter: Value = serde_json::from_str(&filter.1).unwrap();
+        assert_eq!(
+            filter,
+            json!({"-and": [{"username": "admin"}, {"text": {"-like": "%web01%"}}]})
+        );
+
+        assert!(query.matches(&entry("Deleted host 'web01'")));
+        assert!(!query.matches(&entry("Deleted role 'web01'")));
+    }
+}
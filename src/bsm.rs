@@ -0,0 +1,528 @@
+use crate::{
+    config::*,
+    instance::OpsviewInstance,
+    prelude::*,
+    state::{HostState, ServiceCheckState},
+};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// The operational state of a [`BSMComponent`] or a [`BSMService`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum BSMState {
+    /// All hosts of the component, or all components of the service, are fully available.
+    Operational,
+    /// Something has failed, but the quorum is still met.
+    Impacted,
+    /// The quorum is not met.
+    Offline,
+    /// The state of a service cannot be worked out, because none of its components were found.
+    Unknown,
+}
+
+impl fmt::Display for BSMState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BSMState::Operational => write!(f, "Operational"),
+            BSMState::Impacted => write!(f, "Impacted"),
+            BSMState::Offline => write!(f, "Offline"),
+            BSMState::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// The states of hosts and service checks to simulate [`BSMService`] availability with.
+///
+/// Hosts that have not been given a state are assumed to be `Up`, and service checks that have not
+/// been given a state are assumed to be `Ok`, so only the failures of interest need to be set.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MonitoringStates {
+    hosts: HashMap<String, HostState>,
+    service_checks: HashMap<(String, String), ServiceCheckState>,
+}
+
+impl MonitoringStates {
+    /// Creates a new `MonitoringStates` where every host is `Up` and every service check is `Ok`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the state of a host.
+    ///
+    /// # Arguments
+    /// * `host` - The name of the host.
+    /// * `state` - The `HostState` of the host.
+    pub fn host(mut self, host: &str, state: HostState) -> Self {
+        self.set_host(host, state);
+        self
+    }
+
+    /// Sets the state of a service check on a host.
+    ///
+    /// # Arguments
+    /// * `host` - The name of the host.
+    /// * `service_check` - The name of the service check.
+    /// * `state` - The `ServiceCheckState` of the service check.
+    pub fn service_check(
+        mut self,
+        host: &str,
+        service_check: &str,
+        state: ServiceCheckState,
+    ) -> Self {
+        self.set_service_check(host, service_check, state);
+        self
+    }
+
+    /// Sets the state of a host in place.
+    ///
+    /// # Arguments
+    /// * `host` - The name of the host.
+    /// * `state` - The `HostState` of the host.
+    pub fn set_host(&mut self, host: &str, state: HostState) {
+        self.hosts.insert(host.to_string(), state);
+    }
+
+    /// Sets the state of a service check on a host in place.
+    ///
+    /// # Arguments
+    /// * `host` - The name of the host.
+    /// * `service_check` - The name of the service check.
+    /// * `state` - The `ServiceCheckState` of the service check.
+    pub fn set_service_check(&mut self, host: &str, service_check: &str, state: ServiceCheckState) {
+        self.service_checks
+            .insert((host.to_string(), service_check.to_string()), state);
+    }
+
+    /// Returns the state of a host.
+    pub fn host_state(&self, host: &str) -> HostState {
+        self.hosts.get(host).cloned().unwrap_or(HostState::Up)
+    }
+
+    /// Returns the state of a service check on a host.
+    pub fn service_check_state(&self, host: &str, service_check: &str) -> ServiceCheckState {
+        self.service_checks
+            .get(&(host.to_string(), service_check.to_string()))
+            .cloned()
+            .unwrap_or(ServiceCheckState::Ok)
+    }
+}
+
+/// The availability of a single host within a [`BSMComponent`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HostAvailability {
+    /// The name of the host.
+    pub host: String,
+    /// Whether the host counts towards the quorum of the component.
+    pub available: bool,
+    /// The reasons the host is not fully available, such as the host being down or a service check
+    /// being in a `WARNING` state. Empty if the host is fully available.
+    pub problems: Vec<String>,
+}
+
+/// The simulated availability of a [`BSMComponent`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComponentAvailability {
+    /// The name of the component.
+    pub name: String,
+    /// The operational state of the component.
+    pub state: BSMState,
+    /// The quorum of the component.
+    pub quorum: Quorum,
+    /// The availability of each host in the component, ordered by host name.
+    pub hosts: Vec<HostAvailability>,
+}
+
+impl ComponentAvailability {
+    /// Returns the number of hosts that count towards the quorum.
+    pub fn available_hosts(&self) -> usize {
+        self.hosts.iter().filter(|h| h.available).count()
+    }
+
+    /// Returns the hosts that do not count towards the quorum.
+    pub fn failed_hosts(&self) -> Vec<&HostAvailability> {
+        self.hosts.iter().filter(|h| !h.available).collect()
+    }
+}
+
+impl fmt::Display for ComponentAvailability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} ({} of {} hosts available, {} required)",
+            self.name,
+            self.state,
+            self.available_hosts(),
+            self.hosts.len(),
+            self.quorum.required_hosts()
+        )
+    }
+}
+
+/// The simulated availability of a [`BSMService`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ServiceAvailability {
+    /// The name of the service.
+    pub name: String,
+    /// The operational state of the service.
+    pub state: BSMState,
+    /// The availability of each component of the service, ordered by component name.
+    pub components: Vec<ComponentAvailability>,
+    /// The names of the components of the service that could not be found.
+    pub missing_components: Vec<String>,
+}
+
+impl fmt::Display for ServiceAvailability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}: {}", self.name, self.state)?;
+        for component in &self.components {
+            writeln!(f, "  {}", component)?;
+            for host in component.hosts.iter().filter(|h| !h.problems.is_empty()) {
+                writeln!(f, "    {}: {}", host.host, host.problems.join(", "))?;
+            }
+        }
+        for missing in &self.missing_components {
+            writeln!(f, "  {}: not found", missing)?;
+        }
+        Ok(())
+    }
+}
+
+/// An offline calculator for the operational state of [`BSMComponent`] and [`BSMService`] objects
+/// given a set of [`MonitoringStates`].
+///
+/// The states are worked out as follows:
+/// * A host of a component is unavailable if it is `DOWN` or `UNREACHABLE`, or if any service
+///   check of the component's host template is `CRITICAL` or `UNKNOWN` on it. A service check in
+///   a `WARNING` state leaves the host available, but is reported as a problem.
+/// * A component is `Offline` if fewer hosts are available than its quorum requires, `Impacted` if
+///   the quorum is met but any host has a problem, and `Operational` otherwise.
+/// * A service is `Offline` if any of its components is `Offline`, `Impacted` if any of its
+///   components is `Impacted`, and `Operational` otherwise. A service whose components are all
+///   missing is `Unknown`.
+///
+/// # Example
+/// ```rust
+/// use opsview::bsm::{BSMSimulator, BSMState, MonitoringStates};
+/// use opsview::config::{BSMComponent, BSMService, HostTemplate};
+/// use opsview::prelude::*;
+/// use opsview::state::HostState;
+///
+/// let component: BSMComponent = serde_json::from_value(serde_json::json!({
+///     "name": "Databases",
+///     "host_template": {"name": "Database"},
+///     "hosts": [{"name": "db01"}, {"name": "db02"}, {"name": "db03"}],
+///     "quorum_pct": "66.67"
+/// }))
+/// .unwrap();
+///
+/// let mut components = ConfigObjectMap::<BSMComponent>::new();
+/// components.add(component);
+///
+/// let service = BSMService::builder()
+///     .name("Payments")
+///     .components(&components)
+///     .build()
+///     .unwrap();
+///
+/// let templates = ConfigObjectMap::<HostTemplate>::new();
+/// let simulator = BSMSimulator::new(&components, &templates);
+///
+/// let one_down = MonitoringStates::new().host("db01", HostState::Down);
+/// assert_eq!(simulator.service(&service, &one_down).state, BSMState::Impacted);
+///
+/// let two_down = one_down.host("db02", HostState::Down);
+/// assert_eq!(simulator.service(&service, &two_down).state, BSMState::Offline);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BSMSimulator<'a> {
+    components: &'a ConfigObjectMap<BSMComponent>,
+    host_templates: &'a ConfigObjectMap<HostTemplate>,
+}
+
+impl<'a> BSMSimulator<'a> {
+    /// Creates a new `BSMSimulator`.
+    ///
+    /// # Arguments
+    /// * `components` - The components that services are made up of.
+    /// * `host_templates` - The host templates whose service checks decide whether a host of a
+    ///   component is available.
+    pub fn new(
+        components: &'a ConfigObjectMap<BSMComponent>,
+        host_templates: &'a ConfigObjectMap<HostTemplate>,
+    ) -> Self {
+        BSMSimulator {
+            components,
+            host_templates,
+        }
+    }
+
+    /// Creates a new `BSMSimulator` from the components and host templates of an
+    /// [`OpsviewInstance`].
+    ///
+    /// # Arguments
+    /// * `instance` - The `OpsviewInstance` to simulate.
+    pub fn from_instance(instance: &'a OpsviewInstance) -> Self {
+        Self::new(&instance.bsm_components, &instance.host_templates)
+    }
+
+    /// Works out the availability of a component.
+    ///
+    /// # Arguments
+    /// * `component` - The `BSMComponent` to simulate.
+    /// * `states` - The states of the hosts and service checks.
+    pub fn component(
+        &self,
+        component: &BSMComponent,
+        states: &MonitoringStates,
+    ) -> ComponentAvailability {
+        let service_checks: Vec<String> = component
+            .host_template
+            .as_ref()
+            .and_then(|t| self.host_templates.get(&t.name()))
+            .and_then(|t| t.servicechecks.clone())
+            .map(|checks| checks.values().map(|c| c.name()).collect())
+            .unwrap_or_default();
+
+        let mut host_names: Vec<String> = component
+            .hosts
+            .iter()
+            .flat_map(|hosts| hosts.values().map(|h| h.name()))
+            .collect();
+        host_names.sort();
+
+        let hosts: Vec<HostAvailability> = host_names
+            .into_iter()
+            .map(|host| host_availability(host, &service_checks, states))
+            .collect();
+
+        // A missing or invalid quorum falls back to requiring all hosts, like a new component.
+        let quorum = component
+            .quorum()
+            .unwrap_or_else(|_| Quorum::all(hosts.len()));
+        let required_hosts = quorum.required_hosts();
+
+        let available = hosts.iter().filter(|h| h.available).count();
+        let state = if available < required_hosts {
+            BSMState::Offline
+        } else if hosts.iter().any(|h| !h.problems.is_empty()) {
+            BSMState::Impacted
+        } else {
+            BSMState::Operational
+        };
+
+        ComponentAvailability {
+            name: component.name.clone(),
+            state,
+            quorum,
+            hosts,
+        }
+    }
+
+    /// Works out the availability of a service and each of its components.
+    ///
+    /// # Arguments
+    /// * `service` - The `BSMService` to simulate.
+    /// * `states` - The states of the hosts and service checks.
+    pub fn service(&self, service: &BSMService, states: &MonitoringStates) -> ServiceAvailability {
+        let mut components = Vec::new();
+        let mut missing_components = Vec::new();
+
+        for component_ref in service.components.iter().flat_map(|c| c.values()) {
+            match self.find_component(component_ref) {
+                Some(component) => components.push(self.component(component, states)),
+                None => missing_components.push(component_ref.name()),
+            }
+        }
+
+        components.sort_by(|a, b| a.name.cmp(&b.name));
+        missing_components.sort();
+
+        let state = match components.iter().map(|c| c.state).max() {
+            Some(state) => state,
+            None if !missing_components.is_empty() => BSMState::Unknown,
+            None => BSMState::Operational,
+        };
+
+        ServiceAvailability {
+            name: service.name.clone(),
+            state,
+            components,
+            missing_components,
+        }
+    }
+
+    /// Works out the availability of every service in a [`ConfigObjectMap`], keyed by service
+    /// name.
+    ///
+    /// # Arguments
+    /// * `services` - The `BSMService` objects to simulate.
+    /// * `states` - The states of the hosts and service checks.
+    pub fn services(
+        &self,
+        services: &ConfigObjectMap<BSMService>,
+        states: &MonitoringStates,
+    ) -> BTreeMap<String, ServiceAvailability> {
+        services
+            .values()
+            .map(|service| (service.name.clone(), self.service(service, states)))
+            .collect()
+    }
+
+    /// Finds the component a [`BSMComponentRef`] refers to, by reference string if it has one and
+    /// by name otherwise.
+    fn find_component(&self, component_ref: &BSMComponentRef) -> Option<&'a BSMComponent> {
+        let ref_ = component_ref.ref_();
+        self.components
+            .values()
+            .find(|c| match &ref_ {
+                Some(ref_) => c.ref_.as_ref() == Some(ref_),
+                None => c.name == component_ref.name(),
+            })
+            .map(|c| c.as_ref())
+    }
+}
+
+/// Works out the availability of a single host of a component.
+fn host_availability(
+    host: String,
+    service_checks: &[String],
+    states: &MonitoringStates,
+) -> HostAvailability {
+    let mut available = true;
+    let mut problems = Vec::new();
+
+    let host_state = states.host_state(&host);
+    if host_state != HostState::Up {
+        available = false;
+        problems.push(format!("host is {}", host_state));
+    }
+
+    for check in service_checks {
+        let state = states.service_check_state(&host, check);
+        match state {
+            ServiceCheckState::Ok => {}
+            ServiceCheckState::Warning => problems.push(format!("'{}' is {}", check, state)),
+            ServiceCheckState::Critical | ServiceCheckState::Unknown => {
+                available = false;
+                problems.push(format!("'{}' is {}", check, state));
+            }
+        }
+    }
+
+    HostAvailability {
+        host,
+        available,
+        problems,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn component(name: &str, hosts: &[&str], quorum_pct: &str) -> BSMComponent {
+        let hosts: Vec<_> = hosts.iter().map(|h| json!({"name": h})).collect();
+        serde_json::from_value(json!({
+            "name": name,
+            "host_template": {"name": "Web"},
+            "hosts": hosts,
+            "quorum_pct": quorum_pct
+        }))
+        .unwrap()
+    }
+
+    fn fixtures() -> (ConfigObjectMap<BSMComponent>, ConfigObjectMap<HostTemplate>) {
+        let components: ConfigObjectMap<BSMComponent> = vec![
+            component("Web Servers", &["web01", "web02", "web03"], "66.67"),
+            component("Load Balancers", &["lb01", "lb02"], "100.00"),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut template = HostTemplate::minimal("Web").unwrap();
+        template.servicechecks =
+            Some(serde_json::from_value(json!([{"name": "HTTP"}, {"name": "Disk"}])).unwrap());
+        let templates: ConfigObjectMap<HostTemplate> = vec![template].into_iter().collect();
+
+        (components, templates)
+    }
+
+    fn service(components: &ConfigObjectMap<BSMComponent>) -> BSMService {
+        BSMService::builder()
+            .name("Shop")
+            .components(components)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_component_quorum() {
+        let (components, templates) = fixtures();
+        let simulator = BSMSimulator::new(&components, &templates);
+        let web = components.get("Web Servers").unwrap();
+
+        let result = simulator.component(&web, &MonitoringStates::new());
+        assert_eq!(result.state, BSMState::Operational);
+        assert_eq!(result.quorum.to_string(), "2 of 3 hosts");
+
+        let states =
+            MonitoringStates::new().service_check("web01", "HTTP", ServiceCheckState::Warning);
+        let result = simulator.component(&web, &states);
+        assert_eq!(result.state, BSMState::Impacted);
+        assert_eq!(result.available_hosts(), 3);
+
+        let states = MonitoringStates::new()
+            .host("web01", HostState::Down)
+            .service_check("web02", "Disk", ServiceCheckState::Critical);
+        let result = simulator.component(&web, &states);
+        assert_eq!(result.state, BSMState::Offline);
+        assert_eq!(result.failed_hosts().len(), 2);
+        assert_eq!(
+            result.to_string(),
+            "Web Servers: Offline (1 of 3 hosts available, 2 required)"
+        );
+    }
+
+    #[test]
+    fn test_service_state() {
+        let (components, templates) = fixtures();
+        let simulator = BSMSimulator::new(&components, &templates);
+        let shop = service(&components);
+
+        assert_eq!(
+            simulator.service(&shop, &MonitoringStates::new()).state,
+            BSMState::Operational
+        );
+
+        let states = MonitoringStates::new().host("web01", HostState::Unreachable);
+        assert_eq!(simulator.service(&shop, &states).state, BSMState::Impacted);
+
+        let states = states.host("lb02", HostState::Down);
+        let result = simulator.service(&shop, &states);
+        assert_eq!(result.state, BSMState::Offline);
+        assert_eq!(result.components[0].name, "Load Balancers");
+        assert!(result.to_string().contains("lb02: host is DOWN"));
+
+        let mut services = ConfigObjectMap::<BSMService>::new();
+        services.add(shop);
+        assert_eq!(
+            simulator.services(&services, &states)["Shop"].state,
+            BSMState::Offline
+        );
+    }
+
+    #[test]
+    fn test_missing_component() {
+        let (components, templates) = fixtures();
+        let shop = service(&components);
+        let empty = ConfigObjectMap::<BSMComponent>::new();
+
+        let result = BSMSimulator::new(&empty, &templates).service(&shop, &MonitoringStates::new());
+        assert_eq!(result.state, BSMState::Unknown);
+        assert_eq!(
+            result.missing_components,
+            vec!["Load Balancers", "Web Servers"]
+        );
+    }
+}
@@ -0,0 +1,585 @@
+use crate::perfdata::NagiosRange;
+use crate::{prelude::*, state::ServiceCheckState};
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// The flags of the warning threshold of a plugin.
+pub const WARNING_FLAGS: [&str; 2] = ["-w", "--warning"];
+
+/// The flags of the critical threshold of a plugin.
+pub const CRITICAL_FLAGS: [&str; 2] = ["-c", "--critical"];
+
+/// A [`NagiosRange`] in the arguments of a plugin, which may be given as a percentage, such as the
+/// `80%` in `-w 500.0,80%`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PluginRange {
+    /// The range.
+    pub range: NagiosRange,
+    /// Whether the range is a percentage.
+    pub percent: bool,
+}
+
+impl FromStr for PluginRange {
+    type Err = OpsviewConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let (range, percent) = match trimmed.strip_suffix('%') {
+            Some(range) => (range, true),
+            None => (trimmed, false),
+        };
+        Ok(PluginRange {
+            range: range.parse()?,
+            percent,
+        })
+    }
+}
+
+impl fmt::Display for PluginRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.range, if self.percent { "%" } else { "" })
+    }
+}
+
+/// The value of a warning or critical threshold of a plugin: one or more comma separated
+/// [`PluginRange`]s, such as `10:`, `~:20`, `@5:10` or `5,4,3`.
+///
+/// # Example
+/// ```rust
+/// use opsview::checkargs::PluginThreshold;
+///
+/// let threshold: PluginThreshold = "500.0,80%".parse().unwrap();
+/// assert_eq!(threshold.ranges.len(), 2);
+/// assert!(threshold.alerts(0, 600.0));
+/// assert!(!threshold.alerts(1, 50.0));
+/// assert_eq!(threshold.to_string(), "500,80%");
+///
+/// assert!("20:10".parse::<PluginThreshold>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PluginThreshold {
+    /// The ranges, one for each value the plugin checks.
+    pub ranges: Vec<PluginRange>,
+}
+
+impl PluginThreshold {
+    /// Returns the range that applies to the value at an index: the range at the same index, or
+    /// the only range if there is just one.
+    pub fn range_for(&self, index: usize) -> Option<&PluginRange> {
+        match self.ranges.len() {
+            1 => self.ranges.first(),
+            _ => self.ranges.get(index),
+        }
+    }
+
+    /// Returns `true` if the value at an index raises an alert. Percentages are compared as plain
+    /// numbers, so the value must be given in the same unit as the range.
+    pub fn alerts(&self, index: usize, value: f64) -> bool {
+        self.range_for(index)
+            .is_some_and(|range| range.range.alerts(value))
+    }
+}
+
+impl FromStr for PluginThreshold {
+    type Err = OpsviewConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(PluginThreshold {
+            ranges: s.split(',').map(str::parse).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl fmt::Display for PluginThreshold {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ranges: Vec<String> = self.ranges.iter().map(ToString::to_string).collect();
+        write!(f, "{}", ranges.join(","))
+    }
+}
+
+/// A token of the arguments of a plugin.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Token {
+    /// The value of the token, with quotes and escapes removed.
+    value: String,
+    /// The byte range of the token in the arguments, including any quotes.
+    span: Range<usize>,
+}
+
+/// Where the value of a flag is found.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Occurrence {
+    /// The flag and its value are separate tokens, such as `-w 10`. Holds the token indexes.
+    Separate(usize, usize),
+    /// The value is attached to the flag, such as `-w10` or `--warning=10`. Holds the token
+    /// index and the length of the flag including any `=`.
+    Attached(usize, usize),
+    /// The flag is the last token and has no value. Holds the token index.
+    Missing(usize),
+}
+
+/// The arguments of a plugin, split into shell-like tokens so that options, and in particular the
+/// warning and critical thresholds, can be read, validated and rewritten.
+///
+/// Tokens are separated by whitespace. Single quotes, double quotes and backslash escapes are
+/// understood, and Opsview macros such as `$HOSTADDRESS$` and `%VARIABLE%` are left as they are.
+/// Rewriting an option only changes the text of that option, so the rest of the arguments keep
+/// their original spacing and quoting.
+///
+/// Options are looked up as separate tokens (`-w 10`, `--warning 10`), attached to a short flag
+/// (`-w10`) or joined with `=` to a long flag (`--warning=10`). A value starting with `-` must be a
+/// number or range, so `-w -c 10` leaves `-w` without a value, and an attached value must be a
+/// number, range or macro, so `-wait` is not read as `-w ait`.
+///
+/// # Example
+/// ```rust
+/// use opsview::checkargs::CheckArgs;
+/// use opsview::state::ServiceCheckState;
+///
+/// let mut args = CheckArgs::parse("-H $HOSTADDRESS$ -w 80 --critical=95 -t 'a b'").unwrap();
+/// assert_eq!(args.warning(), Some("80"));
+/// assert_eq!(args.critical(), Some("95"));
+/// assert_eq!(args.value_of("-t"), Some("a b"));
+/// assert_eq!(args.evaluate(&[90.0]).unwrap(), ServiceCheckState::Warning);
+///
+/// args.set_warning("~:85").unwrap();
+/// args.set_critical("@90:100").unwrap();
+/// assert_eq!(
+///     args.to_string(),
+///     "-H $HOSTADDRESS$ -w ~:85 --critical=@90:100 -t 'a b'"
+/// );
+///
+/// assert!(args.set_warning("20:10").is_err());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckArgs {
+    args: String,
+    tokens: Vec<Token>,
+}
+
+impl CheckArgs {
+    /// Splits the arguments of a plugin into tokens.
+    ///
+    /// # Returns
+    /// An error if a quote is not closed or the arguments end with a backslash.
+    pub fn parse(args: &str) -> Result<Self, OpsviewConfigError> {
+        Ok(CheckArgs {
+            args: args.to_string(),
+            tokens: tokenize(args)?,
+        })
+    }
+
+    /// Returns the arguments as a string.
+    pub fn as_str(&self) -> &str {
+        &self.args
+    }
+
+    /// Returns the tokens, with quotes and escapes removed.
+    pub fn tokens(&self) -> Vec<&str> {
+        self.tokens.iter().map(|t| t.value.as_str()).collect()
+    }
+
+    /// Returns the value of the first occurrence of a flag such as `-w` or `--warning`, or `None`
+    /// if the flag is not present or has no value.
+    pub fn value_of(&self, flag: &str) -> Option<&str> {
+        match self.find(flag)? {
+            Occurrence::Separate(_, value) => Some(&self.tokens[value].value),
+            Occurrence::Attached(token, len) => Some(&self.tokens[token].value[len..]),
+            Occurrence::Missing(_) => None,
+        }
+    }
+
+    /// Returns `true` if a flag is present.
+    pub fn contains(&self, flag: &str) -> bool {
+        self.find(flag).is_some()
+    }
+
+    /// Sets the value of the first occurrence of a flag, or appends the flag and the value if the
+    /// flag is not present. The value is quoted if needed.
+    pub fn set(&mut self, flag: &str, value: &str) {
+        let quoted = quote(value);
+        match self.find(flag) {
+            Some(Occurrence::Separate(_, token)) => {
+                self.replace(self.tokens[token].span.clone(), &quoted)
+            }
+            Some(Occurrence::Attached(token, len)) => {
+                let span = self.tokens[token].span.clone();
+                let prefix = &self.tokens[token].value[..len];
+                let replacement = format!("{}{}", prefix, quoted);
+                self.replace(span, &replacement)
+            }
+            Some(Occurrence::Missing(token)) => {
+                let end = self.tokens[token].span.end;
+                self.replace(end..end, &format!(" {}", quoted))
+            }
+            None => {
+                let trimmed = self.args.trim_end().len();
+                let separator = if trimmed == 0 { "" } else { " " };
+                let addition = format!("{}{} {}", separator, flag, quoted);
+                self.replace(trimmed..self.args.len(), &addition)
+            }
+        }
+    }
+
+    /// Removes the first occurrence of a flag and its value, returning `true` if it was present.
+    pub fn remove(&mut self, flag: &str) -> bool {
+        let (first, last) = match self.find(flag) {
+            Some(Occurrence::Separate(flag, value)) => (flag, value),
+            Some(Occurrence::Attached(token, _)) | Some(Occurrence::Missing(token)) => {
+                (token, token)
+            }
+            None => return false,
+        };
+
+        let start = self.tokens[first].span.start;
+        let span = match self.tokens.get(last + 1) {
+            Some(next) => start..next.span.start,
+            None => {
+                let start = match first {
+                    0 => start,
+                    _ => self.tokens[first - 1].span.end,
+                };
+                start..self.tokens[last].span.end
+            }
+        };
+        self.replace(span, "");
+        true
+    }
+
+    /// Returns the value of the warning threshold, given as `-w` or `--warning`.
+    pub fn warning(&self) -> Option<&str> {
+        self.first_value_of(&WARNING_FLAGS)
+    }
+
+    /// Returns the value of the critical threshold, given as `-c` or `--critical`.
+    pub fn critical(&self) -> Option<&str> {
+        self.first_value_of(&CRITICAL_FLAGS)
+    }
+
+    /// Returns the parsed warning threshold, if present.
+    pub fn warning_threshold(&self) -> Result<Option<PluginThreshold>, OpsviewConfigError> {
+        self.warning().map(str::parse).transpose()
+    }
+
+    /// Returns the parsed critical threshold, if present.
+    pub fn critical_threshold(&self) -> Result<Option<PluginThreshold>, OpsviewConfigError> {
+        self.critical().map(str::parse).transpose()
+    }
+
+    /// Sets the warning threshold, replacing the value of `-w` or `--warning` if present and
+    /// adding `-w` otherwise.
+    ///
+    /// # Returns
+    /// An error, leaving the arguments unchanged, if the threshold is not valid.
+    pub fn set_warning(&mut self, threshold: &str) -> Result<(), OpsviewConfigError> {
+        self.set_threshold(&WARNING_FLAGS, threshold)
+    }
+
+    /// Sets the critical threshold, replacing the value of `-c` or `--critical` if present and
+    /// adding `-c` otherwise.
+    ///
+    /// # Returns
+    /// An error, leaving the arguments unchanged, if the threshold is not valid.
+    pub fn set_critical(&mut self, threshold: &str) -> Result<(), OpsviewConfigError> {
+        self.set_threshold(&CRITICAL_FLAGS, threshold)
+    }
+
+    /// Checks that the warning and critical thresholds, where present, are valid.
+    ///
+    /// Thresholds made up of Opsview macros, such as `%WARNING%` or `$ARG1$`, are only known when
+    /// the check runs and are not validated.
+    pub fn validate_thresholds(&self) -> Result<(), OpsviewConfigError> {
+        for value in [self.warning(), self.critical()].into_iter().flatten() {
+            if !is_macro(value) {
+                value.parse::<PluginThreshold>()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates values against the warning and critical thresholds.
+    ///
+    /// Each value is compared with the range at the same index, or with the only range if the
+    /// threshold has just one.
+    ///
+    /// # Returns
+    /// `Critical` or `Warning` if any value raises an alert for the respective threshold,
+    /// otherwise `Ok`. An error if a threshold is not valid.
+    pub fn evaluate(&self, values: &[f64]) -> Result<ServiceCheckState, OpsviewConfigError> {
+        let warning = self.warning_threshold()?;
+        let critical = self.critical_threshold()?;
+        let alerts = |threshold: &Option<PluginThreshold>| {
+            threshold.as_ref().is_some_and(|t| {
+                values
+                    .iter()
+                    .enumerate()
+                    .any(|(i, value)| t.alerts(i, *value))
+            })
+        };
+
+        Ok(if alerts(&critical) {
+            ServiceCheckState::Critical
+        } else if alerts(&warning) {
+            ServiceCheckState::Warning
+        } else {
+            ServiceCheckState::Ok
+        })
+    }
+
+    fn set_threshold(&mut self, flags: &[&str], threshold: &str) -> Result<(), OpsviewConfigError> {
+        if !is_macro(threshold) {
+            threshold.parse::<PluginThreshold>()?;
+        }
+        let flag = flags
+            .iter()
+            .copied()
+            .find(|flag| self.contains(flag))
+            .unwrap_or(flags[0]);
+        self.set(flag, threshold);
+        Ok(())
+    }
+
+    fn first_value_of(&self, flags: &[&str]) -> Option<&str> {
+        flags.iter().find_map(|flag| self.value_of(flag))
+    }
+
+    fn find(&self, flag: &str) -> Option<Occurrence> {
+        let long = flag.starts_with("--");
+        self.tokens.iter().enumerate().find_map(|(i, token)| {
+            let value = token.value.as_str();
+            if value == flag {
+                return Some(match self.tokens.get(i + 1) {
+                    Some(next) if is_value(&next.value) => Occurrence::Separate(i, i + 1),
+                    _ => Occurrence::Missing(i),
+                });
+            }
+            let rest = value.strip_prefix(flag)?;
+            if long {
+                rest.starts_with('=')
+                    .then_some(Occurrence::Attached(i, flag.len() + 1))
+            } else {
+                (flag.len() == 2 && is_range(rest)).then_some(Occurrence::Attached(i, 2))
+            }
+        })
+    }
+
+    fn replace(&mut self, span: Range<usize>, replacement: &str) {
+        self.args.replace_range(span, replacement);
+        // The arguments were valid before, and the replacement is quoted, so they still are.
+        self.tokens = tokenize(&self.args).unwrap_or_default();
+    }
+}
+
+impl FromStr for CheckArgs {
+    type Err = OpsviewConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CheckArgs::parse(s)
+    }
+}
+
+impl fmt::Display for CheckArgs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.args)
+    }
+}
+
+/// Splits arguments into shell-like tokens.
+fn tokenize(args: &str) -> Result<Vec<Token>, OpsviewConfigError> {
+    let invalid =
+        |reason: &str| OpsviewConfigError::InvalidCheckArgs(format!("{}: {}", reason, args));
+    let mut tokens = Vec::new();
+    let mut chars = args.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let mut value = String::new();
+        let mut end = start;
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+            chars.next();
+            end = i + c.len_utf8();
+
+            match c {
+                '\'' => loop {
+                    match chars.next() {
+                        Some((i, '\'')) => {
+                            end = i + 1;
+                            break;
+                        }
+                        Some((_, c)) => value.push(c),
+                        None => return Err(invalid("unclosed single quote")),
+                    }
+                },
+                '"' => loop {
+                    match chars.next() {
+                        Some((i, '"')) => {
+                            end = i + 1;
+                            break;
+                        }
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, c @ ('"' | '\\' | '$' | '`'))) => value.push(c),
+                            Some((_, c)) => {
+                                value.push('\\');
+                                value.push(c);
+                            }
+                            None => return Err(invalid("unclosed double quote")),
+                        },
+                        Some((_, c)) => value.push(c),
+                        None => return Err(invalid("unclosed double quote")),
+                    }
+                },
+                '\\' => match chars.next() {
+                    Some((i, c)) => {
+                        value.push(c);
+                        end = i + c.len_utf8();
+                    }
+                    None => return Err(invalid("trailing backslash")),
+                },
+                c => value.push(c),
+            }
+        }
+
+        tokens.push(Token {
+            value,
+            span: start..end,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Quotes a value with single quotes if it contains characters that would otherwise be
+/// interpreted.
+fn quote(value: &str) -> String {
+    let safe = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_alphanumeric() || "@~:.,%_+-/=$".contains(c));
+    if safe {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+}
+
+/// Returns `true` if a value is an Opsview macro, such as `%WARNING%` or `$ARG1$`.
+/// Returns `true` if a token can be the value of the flag before it: anything but another flag,
+/// where a negative number or range such as `-5` or `-10:` is a value and not a flag.
+fn is_value(token: &str) -> bool {
+    !token.starts_with('-') || token.parse::<f64>().is_ok() || is_range(token)
+}
+
+/// Returns `true` if a value is a number, range or threshold, or an Opsview macro standing in for
+/// one.
+fn is_range(value: &str) -> bool {
+    is_macro(value) || value.parse::<PluginThreshold>().is_ok()
+}
+
+fn is_macro(value: &str) -> bool {
+    let macro_ = |delimiter: char| {
+        value.len() > 2 && value.starts_with(delimiter) && value.ends_with(delimiter)
+    };
+    macro_('%') || macro_('$')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let args = CheckArgs::parse(r#"  -a 'one two' -b "x \"y\" \$z" c\ d e'f'g  "#).unwrap();
+        assert_eq!(
+            args.tokens(),
+            vec!["-a", "one two", "-b", r#"x "y" $z"#, "c d", "efg"]
+        );
+        assert!(CheckArgs::parse("-a 'open").is_err());
+        assert!(CheckArgs::parse("-a \"open").is_err());
+        assert!(CheckArgs::parse("-a \\").is_err());
+        assert!(CheckArgs::parse("").unwrap().tokens().is_empty());
+    }
+
+    #[test]
+    fn test_flags() {
+        let args = CheckArgs::parse("-w10 --critical=@5:10 -t").unwrap();
+        assert_eq!(args.warning(), Some("10"));
+        assert_eq!(args.critical(), Some("@5:10"));
+        assert!(args.contains("-t"));
+        assert_eq!(args.value_of("-t"), None);
+        assert_eq!(args.value_of("--crit"), None);
+
+        let args = CheckArgs::parse("-w -c 10 -t -5 -x -10: -y --z").unwrap();
+        assert!(args.contains("-w"));
+        assert_eq!(args.warning(), None);
+        assert_eq!(args.critical(), Some("10"));
+        assert_eq!(args.value_of("-t"), Some("-5"));
+        assert_eq!(args.value_of("-x"), Some("-10:"));
+        assert_eq!(args.value_of("-y"), None);
+
+        let args = CheckArgs::parse("-wait -c%CRITICAL% -ttimeout").unwrap();
+        assert!(!args.contains("-w"));
+        assert_eq!(args.warning(), None);
+        assert_eq!(args.critical(), Some("%CRITICAL%"));
+        assert!(!args.contains("-t"));
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let mut args = CheckArgs::parse("-H $HOSTADDRESS$  -w10 -t 5").unwrap();
+        args.set_warning("10:").unwrap();
+        args.set_critical("~:20").unwrap();
+        assert_eq!(args.to_string(), "-H $HOSTADDRESS$  -w10: -t 5 -c ~:20");
+
+        args.set("-x", "it's");
+        assert_eq!(args.value_of("-x"), Some("it's"));
+
+        assert!(args.remove("-t"));
+        assert!(args.remove("-x"));
+        assert!(!args.remove("-t"));
+        assert_eq!(args.to_string(), "-H $HOSTADDRESS$  -w10: -c ~:20");
+
+        args.set("-t", "");
+        assert_eq!(args.to_string(), "-H $HOSTADDRESS$  -w10: -c ~:20 -t ''");
+
+        let mut missing = CheckArgs::parse("-w").unwrap();
+        missing.set_warning("5").unwrap();
+        assert_eq!(missing.to_string(), "-w 5");
+
+        let mut empty = CheckArgs::parse("").unwrap();
+        empty.set_critical("5").unwrap();
+        assert_eq!(empty.to_string(), "-c 5");
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let args = CheckArgs::parse("-w 5,4,3 -c 10,8,6").unwrap();
+        assert!(args.validate_thresholds().is_ok());
+        assert_eq!(
+            args.evaluate(&[1.0, 1.0, 1.0]).unwrap(),
+            ServiceCheckState::Ok
+        );
+        assert_eq!(
+            args.evaluate(&[1.0, 4.5, 1.0]).unwrap(),
+            ServiceCheckState::Warning
+        );
+        assert_eq!(
+            args.evaluate(&[1.0, 1.0, 7.0]).unwrap(),
+            ServiceCheckState::Critical
+        );
+
+        let args = CheckArgs::parse("-w %WARNING% -c abc").unwrap();
+        assert_eq!(
+            args.validate_thresholds(),
+            Err(OpsviewConfigError::InvalidRange("abc".to_string()))
+        );
+        assert!(args.evaluate(&[1.0]).is_err());
+    }
+}
@@ -1,8 +1,13 @@
 //! # Opsview Client
 //! Contains the [`OpsviewClient`] struct and methods for interacting with the Opsview API.
-use crate::{config::*, prelude::*};
+use crate::{
+    auditlog::*, config::*, event::*, graph::*, opspack::*, prelude::*, reload::*, testcheck::*,
+};
+use futures::stream::{self, Stream, TryStreamExt};
 use reqwest::{self, StatusCode};
+use serde::de::DeserializeOwned;
 use serde_json::{json, Value};
+use std::time::{Duration, Instant};
 use url::Url;
 
 /// A builder for constructing an `OpsviewClient` instance.
@@ -310,6 +315,112 @@ impl OpsviewClient {
         handle_http_response(self.client.put(url.as_ref()).json(data).send().await?).await
     }
 
+    /// Performs a GET request to a specified path in the Opsview API and returns the raw body of
+    /// the response, for endpoints that do not return JSON.
+    ///
+    /// # Errors
+    /// Returns an error if the HTTP request fails or if the response status is not `200 OK`.
+    async fn get_bytes(
+        &self,
+        path: &str,
+        params: Option<Params>,
+    ) -> Result<Vec<u8>, OpsviewClientError> {
+        let url = Url::parse(&format!("{}/rest{}", self.url, path))?;
+        let mut request = self.client.get(url.as_ref());
+        if let Some(params) = params {
+            request = request.query(&params);
+        }
+
+        let response = request.send().await?;
+        if response.status() != StatusCode::OK {
+            return handle_http_response(response).await.map(|_| Vec::new());
+        }
+
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// Sends a POST request with a `multipart/form-data` body to the Opsview API.
+    ///
+    /// # Errors
+    /// Returns an error if the HTTP request fails, or if the response cannot be parsed into a
+    /// JSON object.
+    async fn post_multipart(
+        &self,
+        path: &str,
+        form: reqwest::multipart::Form,
+    ) -> Result<Value, OpsviewClientError> {
+        let url = Url::parse(&format!("{}/rest{}", self.url, path))?;
+        handle_http_response(
+            self.client
+                .post(url.as_ref())
+                .multipart(form)
+                .send()
+                .await?,
+        )
+        .await
+    }
+
+    /// Gets all items of a paged list endpoint as a stream, one page at a time.
+    ///
+    /// The next page is only requested once all items from the previous page have been consumed.
+    fn get_paged_list<'a, T: DeserializeOwned + 'a>(
+        &'a self,
+        path: &'a str,
+        params: Params,
+    ) -> impl Stream<Item = Result<T, OpsviewClientError>> + 'a {
+        stream::try_unfold(Some(1), move |page: Option<u64>| {
+            let params = params.clone();
+            async move {
+                let Some(page) = page else {
+                    return Ok(None);
+                };
+                let (items, next_page) = self.get_list_page(path, params, page).await?;
+                Ok::<_, OpsviewClientError>(Some((
+                    stream::iter(items.into_iter().map(Ok)),
+                    next_page,
+                )))
+            }
+        })
+        .try_flatten()
+    }
+
+    /// Gets a single page of a paged list endpoint and the number of the next page, if any.
+    async fn get_list_page<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        mut params: Params,
+        page: u64,
+    ) -> Result<(Vec<T>, Option<u64>), OpsviewClientError> {
+        if page > 1 {
+            params.push(("page".to_string(), page.to_string()));
+        }
+
+        let response = self.get(path, Some(params)).await?;
+        let summary = parse_summary(&response)?;
+
+        let items = response
+            .get("list")
+            .ok_or(OpsviewClientError::ObjectNotFound(format!(
+                "'list' not found in response from '{}'",
+                path
+            )))?
+            .as_array()
+            .ok_or(OpsviewClientError::NotAnArray(format!(
+                "List at '{}' is not an array",
+                path
+            )))?;
+
+        let items: Vec<T> = serde_json::from_value(Value::Array(items.to_vec()))?;
+
+        let next_page = if page < summary.totalpages {
+            Some(page + 1)
+        } else {
+            None
+        };
+
+        Ok((items, next_page))
+    }
+
     // Reload management -------------------------------------------------------------------------//
     // Methods related to applying and checking for pending configuration changes.
 
@@ -319,27 +430,133 @@ impl OpsviewClient {
     /// configuration changes. It's typically used after making a series of configuration
     /// updates to ensure the changes take effect.
     ///
+    /// The Opsview API only responds once the reload has finished. See
+    /// [`OpsviewClient::apply_changes_and_wait`] for a non-blocking alternative that reports the
+    /// progress of the reload.
+    ///
     /// # Returns
-    /// A `Result` wrapping a `serde_json::Value` that contains the response from the Opsview API.
-    /// The response includes details like the number of audit log entries, average duration,
-    /// configuration status, last updated timestamp, messages, and server status.
+    /// A `Result` wrapping a [`ReloadStatus`] with details like the number of audit log entries,
+    /// average duration, configuration status, last updated timestamp, messages, and server
+    /// status. Use [`ReloadStatus::outcome`] to check whether the reload succeeded.
+    ///
+    /// # Errors
+    /// Returns an error if the HTTP request fails or if the Opsview API returns an error response.
+    pub async fn apply_changes(&self) -> Result<ReloadStatus, OpsviewClientError> {
+        let response = self.post("/reload", &Value::Null).await?;
+        Ok(serde_json::from_value(response)?)
+    }
+
+    /// Starts applying pending configuration changes and polls the Opsview API until the reload
+    /// has finished.
     ///
-    /// Example response:
-    /// ```json
-    /// {
-    ///   "auditlog_entries": "0",
-    ///   "average_duration": "10",
-    ///   "configuration_status": "uptodate",
-    ///   "lastupdated": "1702453777",
-    ///   "messages": [],
-    ///   "server_status": "0"
+    /// The reload is requested asynchronously, after which the reload status is polled every
+    /// `poll_interval`. The response to the request may still describe the state before the
+    /// reload, so until the server reports that it is reloading, a status that has changes pending
+    /// and no failure is taken to mean that the reload has not started yet. A
+    /// [`ReloadProgress::Reloading`] item is yielded for each poll while the reload is in
+    /// progress, and the stream ends with a single [`ReloadProgress::Finished`] item holding the
+    /// final [`ReloadStatus`] and the structured [`ReloadOutcome`].
+    ///
+    /// A failed reload is reported as a [`ReloadOutcome::Failed`] rather than as an error, so that
+    /// the messages produced by the reload are available to the caller.
+    ///
+    /// # Arguments
+    /// * `poll_interval` - How long to wait between each poll of the reload status.
+    /// * `max_wait` - How long to wait in total for the reload to finish.
+    ///
+    /// # Errors
+    /// The stream yields an error if an HTTP request fails, if a response cannot be parsed, or
+    /// with [`OpsviewClientError::ReloadTimedOut`] if the reload has not finished within
+    /// `max_wait`, after which it ends.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use opsview::client::OpsviewClient;
+    /// use opsview::prelude::*;
+    /// use opsview::reload::ReloadProgress;
+    /// use std::time::Duration;
+    ///
+    /// async fn example(client: &OpsviewClient) -> Result<(), OpsviewClientError> {
+    ///     let mut progress = Box::pin(client.apply_changes_and_wait(
+    ///         Duration::from_secs(2),
+    ///         Duration::from_secs(600),
+    ///     ));
+    ///     while let Some(p) = progress.try_next().await? {
+    ///         match p {
+    ///             ReloadProgress::Reloading { elapsed, .. } => println!("Reloading for {:?}", elapsed),
+    ///             ReloadProgress::Finished { outcome, .. } => println!("Finished: {:?}", outcome),
+    ///         }
+    ///     }
+    ///     Ok(())
     /// }
     /// ```
+    pub fn apply_changes_and_wait(
+        &self,
+        poll_interval: Duration,
+        max_wait: Duration,
+    ) -> impl Stream<Item = Result<ReloadProgress, OpsviewClientError>> + '_ {
+        enum PollState {
+            Start,
+            Starting(Instant),
+            Polling(Instant),
+            Done,
+        }
+
+        stream::try_unfold(PollState::Start, move |state| async move {
+            let (started, status, reload_seen) = match state {
+                PollState::Done => return Ok(None),
+                PollState::Start => {
+                    let started = Instant::now();
+                    let response = self.post("/reload?asynchronous=1", &Value::Null).await?;
+                    let status: ReloadStatus = serde_json::from_value(response)?;
+                    (started, status, false)
+                }
+                PollState::Starting(started) => {
+                    tokio::time::sleep(poll_interval).await;
+                    (started, self.reload_status().await?, false)
+                }
+                PollState::Polling(started) => {
+                    tokio::time::sleep(poll_interval).await;
+                    (started, self.reload_status().await?, true)
+                }
+            };
+
+            let reloading = ReloadProgress::Reloading {
+                elapsed: started.elapsed(),
+                average_duration: status.average_duration.map(Duration::from_secs),
+            };
+            let next = match status.outcome() {
+                None => (reloading, PollState::Polling(started)),
+                Some(outcome)
+                    if !reload_seen && outcome.is_success() && status.has_pending_changes() =>
+                {
+                    (reloading, PollState::Starting(started))
+                }
+                Some(outcome) => (
+                    ReloadProgress::Finished { status, outcome },
+                    PollState::Done,
+                ),
+            };
+
+            if !matches!(next.1, PollState::Done) && started.elapsed() >= max_wait {
+                return Err(OpsviewClientError::ReloadTimedOut(max_wait));
+            }
+
+            Ok::<_, OpsviewClientError>(Some(next))
+        })
+    }
+
+    /// Gets the current [`ReloadStatus`] of the Opsview system without applying any changes.
+    ///
+    /// # Returns
+    /// A `Result` wrapping the current [`ReloadStatus`].
     ///
     /// # Errors
-    /// Returns an error if the HTTP request fails or if the Opsview API returns an error response.
-    pub async fn apply_changes(&self) -> Result<Value, OpsviewClientError> {
-        self.post("/reload", &Value::Null).await
+    /// Returns an error if the HTTP request fails or if the response cannot be parsed.
+    pub async fn reload_status(&self) -> Result<ReloadStatus, OpsviewClientError> {
+        let response = self.get("/reload", None).await?;
+        Ok(serde_json::from_value(response)?)
     }
 
     /// Checks if there are pending configuration changes in the Opsview system.
@@ -400,6 +617,409 @@ impl OpsviewClient {
         Ok(last_updated)
     }
 
+    // Audit log -------------------------------------------------------------------------------//
+    // Methods related to querying the audit log of configuration changes.
+
+    /// Gets the entries matching an [`AuditLogQuery`] from the Opsview audit log as a stream.
+    ///
+    /// Entries are fetched one page at a time from the `/rest/auditlog` endpoint. Filtering on
+    /// [`ConfigObjectType`] is not supported by the API and is applied to each page as it arrives.
+    ///
+    /// # Arguments
+    /// * `query` - The [`AuditLogQuery`] to filter entries by.
+    ///
+    /// # Returns
+    /// A `Stream` of `Result`s wrapping an [`AuditLogEntry`] each.
+    ///
+    /// # Errors
+    /// The stream yields an error if an HTTP request fails or if a page cannot be parsed, after
+    /// which no further pages are requested.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use opsview::auditlog::AuditLogQuery;
+    /// use opsview::client::OpsviewClient;
+    /// use opsview::config::ConfigObjectType;
+    /// use opsview::prelude::*;
+    ///
+    /// async fn example(client: &OpsviewClient) -> Result<(), OpsviewError> {
+    ///     let query = AuditLogQuery::builder()
+    ///         .username("admin")
+    ///         .object_type(ConfigObjectType::Host)
+    ///         .build()?;
+    ///
+    ///     let mut entries = Box::pin(client.get_audit_log(&query));
+    ///     while let Some(entry) = entries.try_next().await? {
+    ///         println!("{} {} {}", entry.timestamp, entry.username, entry.text);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_audit_log<'a>(
+        &'a self,
+        query: &AuditLogQuery,
+    ) -> impl Stream<Item = Result<AuditLogEntry, OpsviewClientError>> + 'a {
+        let query = query.clone();
+        self.get_paged_list("/auditlog", query.params())
+            .try_filter(move |entry| futures::future::ready(query.matches(entry)))
+    }
+
+    /// Gets all entries matching an [`AuditLogQuery`] from the Opsview audit log.
+    ///
+    /// This is a convenience wrapper around [`OpsviewClient::get_audit_log`] that collects all
+    /// pages.
+    ///
+    /// # Arguments
+    /// * `query` - The [`AuditLogQuery`] to filter entries by.
+    ///
+    /// # Returns
+    /// A `Result` wrapping a `Vec` of all matching [`AuditLogEntry`]s, in the order returned by
+    /// the API.
+    pub async fn get_all_audit_log_entries(
+        &self,
+        query: &AuditLogQuery,
+    ) -> Result<Vec<AuditLogEntry>, OpsviewClientError> {
+        self.get_audit_log(query).try_collect().await
+    }
+
+    // Event history ---------------------------------------------------------------------------//
+    // Methods related to querying historical state changes.
+
+    /// Gets the events matching an [`EventQuery`] from the Opsview event history as a stream.
+    ///
+    /// Events are fetched one page at a time from the `/rest/event` endpoint, and the next page is
+    /// only requested once all events from the previous page have been consumed.
+    ///
+    /// # Arguments
+    /// * `query` - The [`EventQuery`] to filter events by.
+    ///
+    /// # Returns
+    /// A `Stream` of `Result`s wrapping an [`Event`] each.
+    ///
+    /// # Errors
+    /// The stream yields an error if an HTTP request fails or if a page cannot be parsed, after
+    /// which no further pages are requested.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use opsview::client::OpsviewClient;
+    /// use opsview::event::{EventQuery, StateType};
+    /// use opsview::prelude::*;
+    ///
+    /// async fn example(client: &OpsviewClient) -> Result<(), OpsviewError> {
+    ///     let query = EventQuery::builder()
+    ///         .hostname("My_Host")
+    ///         .state_type(StateType::Hard)
+    ///         .build()?;
+    ///
+    ///     let mut events = Box::pin(client.get_events(&query));
+    ///     while let Some(event) = events.try_next().await? {
+    ///         println!("{}: {:?}", event.time, event.state_change);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_events<'a>(
+        &'a self,
+        query: &EventQuery,
+    ) -> impl Stream<Item = Result<Event, OpsviewClientError>> + 'a {
+        self.get_paged_list("/event", query.params())
+    }
+
+    /// Gets all events matching an [`EventQuery`] from the Opsview event history.
+    ///
+    /// This is a convenience wrapper around [`OpsviewClient::get_events`] that collects all pages.
+    ///
+    /// # Arguments
+    /// * `query` - The [`EventQuery`] to filter events by.
+    ///
+    /// # Returns
+    /// A `Result` wrapping a `Vec` of all matching [`Event`]s, in the order returned by the API.
+    pub async fn get_all_events(
+        &self,
+        query: &EventQuery,
+    ) -> Result<Vec<Event>, OpsviewClientError> {
+        self.get_events(query).try_collect().await
+    }
+
+    // Performance data ------------------------------------------------------------------------//
+    // Methods related to retrieving the performance data stored by Opsview for graphing.
+
+    /// Gets the performance metrics available for graphing for a host, optionally limited to a
+    /// single service check.
+    ///
+    /// # Arguments
+    /// * `hostname` - The name of the [`Host`].
+    /// * `servicename` - Optional name of the [`ServiceCheck`] to limit the metrics to.
+    ///
+    /// # Returns
+    /// A `Result` wrapping a `Vec` of the [`PerformanceMetric`]s available.
+    ///
+    /// # Errors
+    /// Returns an error if the HTTP request fails or if parsing the response fails.
+    pub async fn get_performance_metrics(
+        &self,
+        hostname: &str,
+        servicename: Option<&str>,
+    ) -> Result<Vec<PerformanceMetric>, OpsviewClientError> {
+        let mut params: Params = vec![
+            ("hostname".to_string(), hostname.to_string()),
+            ("rows".to_string(), "all".to_string()),
+        ];
+
+        if let Some(servicename) = servicename {
+            params.push(("servicename".to_string(), servicename.to_string()));
+        }
+
+        let response = self.get("/runtime/performancemetric", Some(params)).await?;
+
+        let metrics = required_response_field(&response, "list")?;
+        if !metrics.is_array() {
+            return Err(OpsviewClientError::NotAnArray(
+                "Performance metric list is not an array".to_string(),
+            ));
+        }
+
+        Ok(serde_json::from_value(metrics)?)
+    }
+
+    /// Gets the time series for the metrics in a [`MetricQuery`].
+    ///
+    /// If the query has a resolution set, it is sent to the graph endpoint and each returned
+    /// [`TimeSeries`] is also averaged into buckets of that many seconds, in case the server
+    /// returns finer data than requested.
+    ///
+    /// # Arguments
+    /// * `query` - The [`MetricQuery`] describing the metrics and time range to fetch.
+    ///
+    /// # Returns
+    /// A `Result` wrapping a `Vec` of [`TimeSeries`], one for each metric in the query and in the
+    /// same order.
+    ///
+    /// # Errors
+    /// Returns an error if the HTTP request fails, if parsing the response fails, or if the
+    /// response does not contain a time series described by the `hsm` of each requested metric.
+    pub async fn get_time_series(
+        &self,
+        query: &MetricQuery,
+    ) -> Result<Vec<TimeSeries>, OpsviewClientError> {
+        let response = self.get("/graph", Some(query.params())).await?;
+
+        let list = response
+            .get("list")
+            .ok_or(OpsviewClientError::MissingResponseField("list".to_string()))?
+            .as_array()
+            .ok_or(OpsviewClientError::NotAnArray(
+                "Graph list is not an array".to_string(),
+            ))?;
+
+        if list.len() != query.metrics.len() {
+            return Err(OpsviewClientError::RowCountObjectCountMismatch {
+                expected: query.metrics.len() as u64,
+                actual: list.len() as u64,
+            });
+        }
+
+        query
+            .metrics
+            .iter()
+            .map(|metric| {
+                let hsm = metric.hsm();
+                let value = list
+                    .iter()
+                    .find(|v| v.get("description").and_then(|d| d.as_str()) == Some(&hsm))
+                    .ok_or(OpsviewClientError::MetricNotReturned(hsm))?;
+
+                let series = TimeSeries::from_graph_value(metric, value)?;
+                Ok(match query.resolution {
+                    Some(resolution) => series.downsample(resolution),
+                    None => series,
+                })
+            })
+            .collect()
+    }
+
+    // Opspacks --------------------------------------------------------------------------------//
+    // Methods related to importing, exporting and listing Opspacks.
+
+    /// Lists the Opspacks installed on the Opsview instance.
+    ///
+    /// # Returns
+    /// A `Result` wrapping a `Vec` of the [`InstalledOpspack`]s, including their versions.
+    ///
+    /// # Errors
+    /// Returns an error if the HTTP request fails or if the response cannot be parsed.
+    pub async fn list_opspacks(&self) -> Result<Vec<InstalledOpspack>, OpsviewClientError> {
+        let params: Params = vec![("rows".to_string(), "all".to_string())];
+        let response = self.get("/opspack", Some(params)).await?;
+        let list = required_response_field(&response, "list")?;
+        Ok(serde_json::from_value(list)?)
+    }
+
+    /// Exports an installed Opspack by name as the raw bytes of its `.tar.gz` archive.
+    ///
+    /// The bytes are returned exactly as sent by the server, which makes this method suitable for
+    /// storing Opspacks in version control. Use [`OpsviewClient::export_opspack`] to get a parsed
+    /// [`Opspack`] instead.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the Opspack to export.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewClientError::OpspackNotFound` if no Opspack with the given name is
+    /// installed, or any other error if the HTTP request fails.
+    pub async fn export_opspack_archive(&self, name: &str) -> Result<Vec<u8>, OpsviewClientError> {
+        let params: Params = vec![("name".to_string(), name.to_string())];
+        self.get_bytes("/opspack/export", Some(params))
+            .await
+            .map_err(|e| match e {
+                OpsviewClientError::ResourceNotFound(_) => {
+                    OpsviewClientError::OpspackNotFound(name.to_string())
+                }
+                other => other,
+            })
+    }
+
+    /// Exports an installed Opspack by name and parses it into an [`Opspack`].
+    ///
+    /// # Arguments
+    /// * `name` - The name of the Opspack to export.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`OpsviewClient::export_opspack_archive`], or an
+    /// `OpsviewClientError::ResponseParseError` if the archive returned by the server is not a
+    /// valid Opspack.
+    pub async fn export_opspack(&self, name: &str) -> Result<Opspack, OpsviewClientError> {
+        let bytes = self.export_opspack_archive(name).await?;
+        Opspack::from_bytes(&bytes)
+            .map_err(|e| OpsviewClientError::ResponseParseError(e.to_string()))
+    }
+
+    /// Uploads and imports an Opspack archive.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the Opspack, used for the uploaded file name and in errors.
+    /// * `archive` - The raw bytes of the `.tar.gz` archive.
+    /// * `overwrite` - Whether to replace the Opspack if it is already installed.
+    ///
+    /// # Returns
+    /// A `Result` wrapping an [`OpspackImportResult`] with the name, version and any messages
+    /// reported by the server.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewClientError::OpspackAlreadyInstalled` if the Opspack is already
+    /// installed and `overwrite` is false, an `OpsviewClientError::OpspackImportFailed` with the
+    /// reason given by the server if the import fails, or any other error if the HTTP request
+    /// fails.
+    pub async fn import_opspack_archive(
+        &self,
+        name: &str,
+        archive: Vec<u8>,
+        overwrite: bool,
+    ) -> Result<OpspackImportResult, OpsviewClientError> {
+        let file = reqwest::multipart::Part::bytes(archive)
+            .file_name(format!("{}.tar.gz", name))
+            .mime_str("application/gzip")?;
+        let form = reqwest::multipart::Form::new()
+            .part("filename", file)
+            .text("overwrite", if overwrite { "1" } else { "0" });
+
+        let import_error = |reason: String| {
+            let lower = reason.to_lowercase();
+            if lower.contains("already installed") || lower.contains("already exists") {
+                OpsviewClientError::OpspackAlreadyInstalled(name.to_string())
+            } else {
+                OpsviewClientError::OpspackImportFailed {
+                    name: name.to_string(),
+                    reason,
+                }
+            }
+        };
+
+        let response = self
+            .post_multipart("/opspack/import", form)
+            .await
+            .map_err(|e| match e {
+                OpsviewClientError::BadRequest(reason)
+                | OpsviewClientError::InternalServerError(reason)
+                | OpsviewClientError::UndefinedError(reason) => import_error(reason),
+                other => other,
+            })?;
+
+        OpspackImportResult::from_response(name, &response).map_err(import_error)
+    }
+
+    /// Writes an [`Opspack`] to an archive and imports it.
+    ///
+    /// See [`OpsviewClient::import_opspack_archive`] for details.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewClientError::UndefinedError` if the Opspack cannot be written to an
+    /// archive, or any of the errors returned by [`OpsviewClient::import_opspack_archive`].
+    pub async fn import_opspack(
+        &self,
+        opspack: &Opspack,
+        overwrite: bool,
+    ) -> Result<OpspackImportResult, OpsviewClientError> {
+        let archive = opspack
+            .to_bytes()
+            .map_err(|e| OpsviewClientError::UndefinedError(e.to_string()))?;
+        self.import_opspack_archive(&opspack.info.name, archive, overwrite)
+            .await
+    }
+
+    // Service check testing -------------------------------------------------------------------//
+    // Methods related to test-running plugins without saving any configuration.
+
+    /// Test-runs a plugin with arguments against a host on a monitoring cluster.
+    ///
+    /// Host macros in the arguments are substituted before the request is sent, see
+    /// [`ServiceCheckTest::command_args`]. Nothing is saved, which makes this method suitable as a
+    /// preflight check before creating service checks in bulk.
+    ///
+    /// # Arguments
+    /// * `test` - The [`ServiceCheckTest`] describing the plugin, arguments and host.
+    ///
+    /// # Returns
+    /// A `Result` wrapping a [`ServiceCheckTestResult`] with the resulting state, output and
+    /// performance data.
+    ///
+    /// # Errors
+    /// Returns an error if the HTTP request fails or if the response does not contain an exit
+    /// code.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use opsview::client::OpsviewClient;
+    /// use opsview::config::{Host, ServiceCheck};
+    /// use opsview::prelude::*;
+    /// use opsview::testcheck::ServiceCheckTest;
+    ///
+    /// async fn example(
+    ///     client: &OpsviewClient,
+    ///     check: &ServiceCheck,
+    ///     host: &Host,
+    /// ) -> Result<(), OpsviewError> {
+    ///     let test = ServiceCheckTest::builder()
+    ///         .service_check(check)
+    ///         .host(host)
+    ///         .build()?;
+    ///
+    ///     let result = client.test_service_check(&test).await?;
+    ///     println!("{}: {}", result.state, result.output.text);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn test_service_check(
+        &self,
+        test: &ServiceCheckTest,
+    ) -> Result<ServiceCheckTestResult, OpsviewClientError> {
+        let response = self.post("/testservicecheck", &test.request_body()).await?;
+        ServiceCheckTestResult::from_response(test.command_args(), &response)
+    }
+
     // Lookup operations -------------------------------------------------------------------------//
 
     /// Checks if a specific object exists in the Opsview system based on the object ID.
@@ -1652,7 +2272,7 @@ impl OpsviewClient {
     /// # Errors
     /// Returns an error if the HTTP request fails, or if the object is not found or the configuration
     /// is missing.
-    async fn get_object_config_by_key<T: ConfigObject>(
+    pub(crate) async fn get_object_config_by_key<T: ConfigObject>(
         &self,
         key: &str,
         value: &str,
@@ -1691,7 +2311,7 @@ impl OpsviewClient {
     /// # Errors
     /// Returns an error if the HTTP request fails, or if the object is not found or the configuration
     /// is missing.
-    async fn get_object_config_by_ref<T: ConfigObject>(
+    pub(crate) async fn get_object_config_by_ref<T: ConfigObject>(
         &self,
         ref_: &str,
         params: Option<Params>,
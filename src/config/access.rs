@@ -1,6 +1,7 @@
 use serde::de;
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
 
 /// Represents an Access entity in Opsview.
 ///
@@ -96,57 +97,134 @@ pub enum Access {
     ViewSome(Option<String>),
 }
 
+impl Access {
+    /// Returns the name of the access as used by the Opsview API, such as `"VIEWALL"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Access::ActionAll(_) => "ACTIONALL",
+            Access::ActionSome(_) => "ACTIONSOME",
+            Access::AdminAccess(_) => "ADMINACCESS",
+            Access::BSM(_) => "BSM",
+            Access::ConfigureBSM(_) => "CONFIGUREBSM",
+            Access::ConfigureBSMComponent(_) => "CONFIGUREBSMCOMPONENT",
+            Access::ConfigureContacts(_) => "CONFIGURECONTACTS",
+            Access::ConfigureHostGroups(_) => "CONFIGUREHOSTGROUPS",
+            Access::ConfigureHosts(_) => "CONFIGUREHOSTS",
+            Access::ConfigureKeywords(_) => "CONFIGUREKEYWORDS",
+            Access::ConfigureNetFlow(_) => "CONFIGURENETFLOW",
+            Access::ConfigureProfiles(_) => "CONFIGUREPROFILES",
+            Access::ConfigureRemoteCluster(_) => "CONFIGUREREMOTECLUSTER",
+            Access::ConfigureRoles(_) => "CONFIGUREROLES",
+            Access::ConfigureSave(_) => "CONFIGURESAVE",
+            Access::ConfigureTenancies(_) => "CONFIGURETENANCIES",
+            Access::ConfigureView(_) => "CONFIGUREVIEW",
+            Access::Dashboard(_) => "DASHBOARD",
+            Access::DashboardEdit(_) => "DASHBOARDEDIT",
+            Access::DashboardShare(_) => "DASHBOARDSHARE",
+            Access::DowntimeAll(_) => "DOWNTIMEALL",
+            Access::DowntimeSome(_) => "DOWNTIMESOME",
+            Access::NavOptions(_) => "NAVOPTIONS",
+            Access::NetAuditView(_) => "NETAUDITVIEW",
+            Access::NetFlow(_) => "NETFLOW",
+            Access::NotifySome(_) => "NOTIFYSOME",
+            Access::NTViewAll(_) => "NTVIEWALL",
+            Access::PasswordSave(_) => "PASSWORDSAVE",
+            Access::ReloadAccess(_) => "RELOADACCESS",
+            Access::ReloadView(_) => "RELOADVIEW",
+            Access::RemotelyManagedClusters(_) => "REMOTELYMANAGEDCLUSTERS",
+            Access::ReportAdmin(_) => "REPORTADMIN",
+            Access::ReportUser(_) => "REPORTUSER",
+            Access::RrdGraphs(_) => "RRDGRAPHS",
+            Access::TestAll(_) => "TESTALL",
+            Access::TestChange(_) => "TESTCHANGE",
+            Access::TestSome(_) => "TESTSOME",
+            Access::ViewAll(_) => "VIEWALL",
+            Access::ViewPortAccess(_) => "VIEWPORTACCESS",
+            Access::ViewSome(_) => "VIEWSOME",
+        }
+    }
+
+    /// Returns the reference string of the access, if any.
+    pub fn ref_(&self) -> Option<&str> {
+        match self {
+            Access::ActionAll(ref_)
+            | Access::ActionSome(ref_)
+            | Access::AdminAccess(ref_)
+            | Access::BSM(ref_)
+            | Access::ConfigureBSM(ref_)
+            | Access::ConfigureBSMComponent(ref_)
+            | Access::ConfigureContacts(ref_)
+            | Access::ConfigureHostGroups(ref_)
+            | Access::ConfigureHosts(ref_)
+            | Access::ConfigureKeywords(ref_)
+            | Access::ConfigureNetFlow(ref_)
+            | Access::ConfigureProfiles(ref_)
+            | Access::ConfigureRemoteCluster(ref_)
+            | Access::ConfigureRoles(ref_)
+            | Access::ConfigureSave(ref_)
+            | Access::ConfigureTenancies(ref_)
+            | Access::ConfigureView(ref_)
+            | Access::Dashboard(ref_)
+            | Access::DashboardEdit(ref_)
+            | Access::DashboardShare(ref_)
+            | Access::DowntimeAll(ref_)
+            | Access::DowntimeSome(ref_)
+            | Access::NavOptions(ref_)
+            | Access::NetAuditView(ref_)
+            | Access::NetFlow(ref_)
+            | Access::NotifySome(ref_)
+            | Access::NTViewAll(ref_)
+            | Access::PasswordSave(ref_)
+            | Access::ReloadAccess(ref_)
+            | Access::ReloadView(ref_)
+            | Access::RemotelyManagedClusters(ref_)
+            | Access::ReportAdmin(ref_)
+            | Access::ReportUser(ref_)
+            | Access::RrdGraphs(ref_)
+            | Access::TestAll(ref_)
+            | Access::TestChange(ref_)
+            | Access::TestSome(ref_)
+            | Access::ViewAll(ref_)
+            | Access::ViewPortAccess(ref_)
+            | Access::ViewSome(ref_) => ref_.as_deref(),
+        }
+    }
+
+    /// Returns `true` if both values are the same access, regardless of their reference strings.
+    pub fn is_same_access(&self, other: &Access) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Returns `true` if having this access also grants `other`.
+    ///
+    /// Every access grants itself, and the `*ALL` variants of `ACTION`, `DOWNTIME`, `TEST` and
+    /// `VIEW` also grant their `*SOME` counterparts.
+    pub fn grants(&self, other: &Access) -> bool {
+        self.is_same_access(other)
+            || matches!(
+                (self, other),
+                (Access::ActionAll(_), Access::ActionSome(_))
+                    | (Access::DowntimeAll(_), Access::DowntimeSome(_))
+                    | (Access::TestAll(_), Access::TestSome(_))
+                    | (Access::ViewAll(_), Access::ViewSome(_))
+            )
+    }
+}
+
+impl fmt::Display for Access {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 impl Serialize for Access {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let (name, ref_) = match self {
-            Access::ActionAll(ref_) => ("ACTIONALL", ref_),
-            Access::ActionSome(ref_) => ("ACTIONSOME", ref_),
-            Access::AdminAccess(ref_) => ("ADMINACCESS", ref_),
-            Access::BSM(ref_) => ("BSM", ref_),
-            Access::ConfigureBSM(ref_) => ("CONFIGUREBSM", ref_),
-            Access::ConfigureBSMComponent(ref_) => ("CONFIGUREBSMCOMPONENT", ref_),
-            Access::ConfigureContacts(ref_) => ("CONFIGURECONTACTS", ref_),
-            Access::ConfigureHostGroups(ref_) => ("CONFIGUREHOSTGROUPS", ref_),
-            Access::ConfigureHosts(ref_) => ("CONFIGUREHOSTS", ref_),
-            Access::ConfigureKeywords(ref_) => ("CONFIGUREKEYWORDS", ref_),
-            Access::ConfigureNetFlow(ref_) => ("CONFIGURENETFLOW", ref_),
-            Access::ConfigureProfiles(ref_) => ("CONFIGUREPROFILES", ref_),
-            Access::ConfigureRemoteCluster(ref_) => ("CONFIGUREREMOTECLUSTER", ref_),
-            Access::ConfigureRoles(ref_) => ("CONFIGUREROLES", ref_),
-            Access::ConfigureSave(ref_) => ("CONFIGURESAVE", ref_),
-            Access::ConfigureTenancies(ref_) => ("CONFIGURETENANCIES", ref_),
-            Access::ConfigureView(ref_) => ("CONFIGUREVIEW", ref_),
-            Access::Dashboard(ref_) => ("DASHBOARD", ref_),
-            Access::DashboardEdit(ref_) => ("DASHBOARDEDIT", ref_),
-            Access::DashboardShare(ref_) => ("DASHBOARDSHARE", ref_),
-            Access::DowntimeAll(ref_) => ("DOWNTIMEALL", ref_),
-            Access::DowntimeSome(ref_) => ("DOWNTIMESOME", ref_),
-            Access::NavOptions(ref_) => ("NAVOPTIONS", ref_),
-            Access::NetAuditView(ref_) => ("NETAUDITVIEW", ref_),
-            Access::NetFlow(ref_) => ("NETFLOW", ref_),
-            Access::NotifySome(ref_) => ("NOTIFYSOME", ref_),
-            Access::NTViewAll(ref_) => ("NTVIEWALL", ref_),
-            Access::PasswordSave(ref_) => ("PASSWORDSAVE", ref_),
-            Access::ReloadAccess(ref_) => ("RELOADACCESS", ref_),
-            Access::ReloadView(ref_) => ("RELOADVIEW", ref_),
-            Access::RemotelyManagedClusters(ref_) => ("REMOTELYMANAGEDCLUSTERS", ref_),
-            Access::ReportAdmin(ref_) => ("REPORTADMIN", ref_),
-            Access::ReportUser(ref_) => ("REPORTUSER", ref_),
-            Access::RrdGraphs(ref_) => ("RRDGRAPHS", ref_),
-            Access::TestAll(ref_) => ("TESTALL", ref_),
-            Access::TestChange(ref_) => ("TESTCHANGE", ref_),
-            Access::TestSome(ref_) => ("TESTSOME", ref_),
-            Access::ViewAll(ref_) => ("VIEWALL", ref_),
-            Access::ViewPortAccess(ref_) => ("VIEWPORTACCESS", ref_),
-            Access::ViewSome(ref_) => ("VIEWSOME", ref_),
-        };
-
         let mut state = serializer.serialize_struct("Access", 2)?;
-        state.serialize_field("name", name)?;
-        if let Some(ref ref_value) = ref_ {
+        state.serialize_field("name", self.name())?;
+        if let Some(ref_value) = self.ref_() {
             state.serialize_field("ref", ref_value)?;
         }
         state.end()
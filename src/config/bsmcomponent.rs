@@ -158,8 +158,28 @@ pub struct BSMComponent {
         default
     )]
     pub uncommitted: Option<bool>,
+
+    // Internal fields ---------------------------------------------------------------------------//
+    /// The quorum that `quorum_pct` was last resized from by `add_host` or `remove_host`.
+    #[serde(skip)]
+    resized_from: ResizedFrom,
+}
+
+/// The quorum a [`BSMComponent`] was last resized from, so that adding and removing hosts keeps
+/// rounding from the original ratio instead of tightening the quorum with every change.
+///
+/// This is not part of the component's configuration, so it is ignored when comparing components.
+#[derive(Clone, Copy, Debug, Default)]
+struct ResizedFrom(Option<Quorum>);
+
+impl PartialEq for ResizedFrom {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
 }
 
+impl Eq for ResizedFrom {}
+
 /// Implementation of the [`CreateFromJson`] trait for `BSMComponent`.
 ///
 /// Enables the creation of a `BSMComponent` instance from a JSON representation,
@@ -279,6 +299,248 @@ impl PersistentMap for ConfigObjectMap<BSMComponent> {
     }
 }
 
+impl BSMComponent {
+    /// Returns the quorum of the component as a number of required hosts out of the hosts
+    /// associated with the component.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError` if `quorum_pct` is not set or is not a valid ratio for the
+    /// number of hosts.
+    pub fn quorum(&self) -> Result<Quorum, OpsviewConfigError> {
+        let quorum_pct = require_field(&self.quorum_pct, "quorum_pct")?;
+        Quorum::from_pct(&quorum_pct, self.host_count())
+    }
+
+    /// Returns the number of hosts associated with the component.
+    pub fn host_count(&self) -> usize {
+        self.hosts.as_ref().map_or(0, |hosts| hosts.len())
+    }
+
+    /// Adds a host to the component and recomputes `quorum_pct` for the new number of hosts.
+    ///
+    /// See [`Quorum::resize`] for how the quorum is recomputed.
+    ///
+    /// # Arguments
+    /// * `host` - The [`Host`] to add.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError` if the current `quorum_pct` is not valid.
+    pub fn add_host(&mut self, host: &Host) -> Result<(), OpsviewConfigError> {
+        let quorum = self.quorum_for_resize()?;
+
+        self.hosts
+            .get_or_insert_with(ConfigRefMap::new)
+            .add(HostRef::from(host.clone()));
+
+        self.resize_quorum(quorum);
+        Ok(())
+    }
+
+    /// Removes a host from the component by name and recomputes `quorum_pct` for the new number of
+    /// hosts.
+    ///
+    /// See [`Quorum::resize`] for how the quorum is recomputed.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the host to remove.
+    ///
+    /// # Returns
+    /// The removed [`HostRef`], or `None` if the component did not have the host, in which case
+    /// `quorum_pct` is left unchanged.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError` if the current `quorum_pct` is not valid.
+    pub fn remove_host(&mut self, name: &str) -> Result<Option<Arc<HostRef>>, OpsviewConfigError> {
+        let quorum = self.quorum_for_resize()?;
+
+        let removed = self.hosts.as_mut().and_then(|hosts| hosts.remove(name));
+        if removed.is_some() {
+            self.resize_quorum(quorum);
+        }
+
+        Ok(removed)
+    }
+
+    /// Returns the quorum to resize from, treating an unset `quorum_pct` as requiring all hosts.
+    ///
+    /// This is the quorum the component was last resized from, as long as `quorum_pct` still
+    /// holds the result of that resize, and the current quorum otherwise.
+    fn quorum_for_resize(&self) -> Result<Quorum, OpsviewConfigError> {
+        let current = match &self.quorum_pct {
+            Some(_) => self.quorum()?,
+            None => Quorum::all(self.host_count()),
+        };
+
+        match self.resized_from.0 {
+            Some(base) if base.resize(self.host_count()) == current => Ok(base),
+            _ => Ok(current),
+        }
+    }
+
+    /// Sets `quorum_pct` to `quorum` resized for the current number of hosts.
+    fn resize_quorum(&mut self, quorum: Quorum) {
+        self.quorum_pct = Some(quorum.resize(self.host_count()).pct());
+        self.resized_from = ResizedFrom(Some(quorum));
+    }
+}
+
+/// The quorum of a [`BSMComponent`], expressed as the number of hosts that must be available out
+/// of the hosts associated with the component.
+///
+/// Opsview stores the quorum as a percentage with exactly 2 decimals, which must be one of the
+/// valid ratios for the number of hosts. `Quorum` converts between the two.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::Quorum;
+///
+/// let quorum = Quorum::from_pct("66.67", 3).unwrap();
+///
+/// assert_eq!(quorum.required_hosts(), 2);
+/// assert_eq!(quorum.to_string(), "2 of 3 hosts");
+/// assert_eq!(Quorum::new(1, 4).unwrap().pct(), "25.00");
+/// assert!(Quorum::from_pct("50.00", 3).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Quorum {
+    required_hosts: usize,
+    total_hosts: usize,
+    // Without any hosts there is no ratio, so remember whether all or none are required.
+    all_required: bool,
+}
+
+impl Quorum {
+    /// Creates a new `Quorum` requiring `required_hosts` of `total_hosts` hosts to be available.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError::InvalidQuorum` if `required_hosts` is greater than
+    /// `total_hosts`.
+    pub fn new(required_hosts: usize, total_hosts: usize) -> Result<Self, OpsviewConfigError> {
+        if required_hosts > total_hosts {
+            return Err(OpsviewConfigError::InvalidQuorum(format!(
+                "Cannot require {} of {} hosts",
+                required_hosts, total_hosts
+            )));
+        }
+
+        Ok(Quorum {
+            required_hosts,
+            total_hosts,
+            all_required: required_hosts == total_hosts,
+        })
+    }
+
+    /// Creates a new `Quorum` requiring all of `total_hosts` hosts to be available.
+    pub fn all(total_hosts: usize) -> Self {
+        Quorum {
+            required_hosts: total_hosts,
+            total_hosts,
+            all_required: true,
+        }
+    }
+
+    /// Creates a new `Quorum` from a quorum percentage such as `"66.67"`.
+    ///
+    /// # Arguments
+    /// * `percentage` - The quorum percentage with exactly 2 decimals.
+    /// * `total_hosts` - The number of hosts associated with the component.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError::InvalidQuorum` if the percentage is not a valid ratio for
+    /// the number of hosts.
+    pub fn from_pct(percentage: &str, total_hosts: usize) -> Result<Self, OpsviewConfigError> {
+        let percentage = validated_pct_and_ratio(percentage, total_hosts)?;
+
+        if total_hosts == 0 {
+            return Ok(Quorum {
+                required_hosts: 0,
+                total_hosts,
+                all_required: percentage != "0.00",
+            });
+        }
+
+        (0..=total_hosts)
+            .find(|required| ratio_pct(*required, total_hosts) == percentage)
+            .map(|required| Quorum {
+                required_hosts: required,
+                total_hosts,
+                all_required: required == total_hosts,
+            })
+            .ok_or_else(|| {
+                OpsviewConfigError::InvalidQuorum(format!(
+                    "The percentage '{}' is not a valid ratio for '{}' hosts",
+                    percentage, total_hosts
+                ))
+            })
+    }
+
+    /// Returns the number of hosts that must be available.
+    pub fn required_hosts(&self) -> usize {
+        self.required_hosts
+    }
+
+    /// Returns the number of hosts associated with the component.
+    pub fn total_hosts(&self) -> usize {
+        self.total_hosts
+    }
+
+    /// Returns the quorum as a percentage with exactly 2 decimals, as used by `quorum_pct`.
+    pub fn pct(&self) -> String {
+        match (self.total_hosts, self.all_required) {
+            (0, true) => "100.00".to_string(),
+            (0, false) => "0.00".to_string(),
+            _ => ratio_pct(self.required_hosts, self.total_hosts),
+        }
+    }
+
+    /// Returns the equivalent `Quorum` for a new number of hosts.
+    ///
+    /// Requiring all or none of the hosts is kept as is. Otherwise the ratio is kept, rounding the
+    /// number of required hosts up so that the quorum never becomes less strict. For example,
+    /// `2 of 3 hosts` becomes `3 of 4 hosts` when a host is added.
+    ///
+    /// A quorum that ends up requiring every host is equal to [`Quorum::all`], since `quorum_pct`
+    /// cannot tell the two apart. [`BSMComponent::add_host`] and [`BSMComponent::remove_host`]
+    /// therefore resize from the quorum they last resized from, so that `2 of 3 hosts` becomes
+    /// `2 of 2 hosts` when a host is removed and `2 of 3 hosts` again when it is added back.
+    ///
+    /// # Arguments
+    /// * `total_hosts` - The new number of hosts.
+    pub fn resize(&self, total_hosts: usize) -> Self {
+        let required_hosts = if self.all_required {
+            total_hosts
+        } else if self.total_hosts == 0 {
+            0
+        } else {
+            (self.required_hosts * total_hosts).div_ceil(self.total_hosts)
+        };
+
+        Quorum {
+            required_hosts,
+            total_hosts,
+            all_required: match total_hosts {
+                0 => self.all_required,
+                _ => required_hosts == total_hosts,
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Quorum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let noun = if self.total_hosts == 1 {
+            "host"
+        } else {
+            "hosts"
+        };
+        write!(
+            f,
+            "{} of {} {}",
+            self.required_hosts, self.total_hosts, noun
+        )
+    }
+}
+
 /// Builder for creating instances of [`BSMComponent`].
 ///
 /// This struct provides a fluent interface for constructing a `BSMComponent` object.
@@ -289,6 +551,7 @@ pub struct BSMComponentBuilder {
     host_template_id: Option<u64>,
     hosts: Option<ConfigRefMap<HostRef>>,
     quorum_pct: Option<String>,
+    quorum_hosts: Option<usize>,
     monitoring_cluster: Option<MonitoringClusterRef>,
 }
 
@@ -326,7 +589,10 @@ impl Builder for BSMComponentBuilder {
         let name = require_field(&self.name, "name")?;
         let host_template = require_field(&self.host_template, "host_template")?;
         let hosts = require_field(&self.hosts, "hosts")?;
-        let quorum_pct = require_field(&self.quorum_pct, "quorum_pct")?;
+        let quorum_pct = match self.quorum_hosts {
+            Some(required_hosts) => Quorum::new(required_hosts, hosts.len())?.pct(),
+            None => require_field(&self.quorum_pct, "quorum_pct")?,
+        };
 
         // TODO: Assert that self.host_template_id == host_template.id if both are present.
 
@@ -343,6 +609,7 @@ impl Builder for BSMComponentBuilder {
             id: None,
             ref_: None,
             uncommitted: None,
+            resized_from: ResizedFrom::default(),
         })
     }
 }
@@ -378,9 +645,10 @@ impl BSMComponentBuilder {
         self
     }
 
-    /// Clears the `quorum_pct` field.
+    /// Clears the `quorum_pct` field, along with any quorum set by `quorum_hosts`.
     pub fn clear_quorum_pct(mut self) -> Self {
         self.quorum_pct = None;
+        self.quorum_hosts = None;
         self
     }
 
@@ -445,6 +713,21 @@ impl BSMComponentBuilder {
     /// * `quorum_pct` - String representing the quorum percentage for the component.
     pub fn quorum_pct(mut self, quorum_pct: &str) -> Self {
         self.quorum_pct = Some(quorum_pct.to_string());
+        self.quorum_hosts = None;
+        self
+    }
+
+    /// A fluent method that sets the quorum as a number of hosts and returns Self, allowing for
+    /// method chaining.
+    ///
+    /// The `quorum_pct` is computed from the number of hosts when the component is built, so this
+    /// can be set before or after the hosts.
+    ///
+    /// # Arguments
+    /// * `required_hosts` - The number of hosts that must be available.
+    pub fn quorum_hosts(mut self, required_hosts: usize) -> Self {
+        self.quorum_hosts = Some(required_hosts);
+        self.quorum_pct = None;
         self
     }
 }
@@ -526,6 +809,11 @@ lazy_static! {
     static ref QUORUM_PCT_REGEX: Regex = regex::Regex::new(r"^\d{1,3}\.\d{2}$").unwrap();
 }
 
+/// Returns the percentage of `required` out of `total` with exactly 2 decimals.
+fn ratio_pct(required: usize, total: usize) -> String {
+    format!("{:.2}", 100.0 * required as f64 / total as f64)
+}
+
 /// Validates the format of the quorum percentage.
 ///
 /// # Arguments
@@ -561,8 +849,7 @@ fn validated_pct_and_ratio(
     // Generate valid percentages as strings
     let mut valid_percentages = Vec::new();
     for host_count in 0..=number_of_hosts {
-        let pct = 100.0 * host_count as f64 / number_of_hosts as f64;
-        valid_percentages.push(format!("{:.2}", pct));
+        valid_percentages.push(ratio_pct(host_count, number_of_hosts));
     }
 
     // Check if the provided percentage is in the list of valid percentages
@@ -677,6 +964,129 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_quorum() {
+        let quorum = Quorum::from_pct("66.67", 3).unwrap();
+        assert_eq!(quorum.required_hosts(), 2);
+        assert_eq!(quorum.total_hosts(), 3);
+        assert_eq!(quorum.to_string(), "2 of 3 hosts");
+        assert_eq!(quorum.resize(4).to_string(), "3 of 4 hosts");
+        assert_eq!(quorum.resize(2).pct(), "100.00");
+        assert_eq!(quorum.resize(2), Quorum::new(2, 2).unwrap());
+        assert_eq!(quorum.resize(2), Quorum::all(2));
+
+        assert_eq!(Quorum::new(1, 1).unwrap().to_string(), "1 of 1 host");
+        assert!(Quorum::new(4, 3).is_err());
+        assert!(Quorum::from_pct("50.00", 3).is_err());
+
+        let all = Quorum::from_pct("100.00", 0).unwrap();
+        assert_eq!(all.resize(3).to_string(), "3 of 3 hosts");
+        let none = Quorum::from_pct("0.00", 2).unwrap();
+        assert_eq!(none.resize(5).pct(), "0.00");
+    }
+
+    #[test]
+    fn test_quorum_recomputed_on_host_change() {
+        let mut component: BSMComponent = serde_json::from_value(serde_json::json!({
+            "name": "Web",
+            "host_template": {"name": "Web"},
+            "hosts": [{"name": "web01"}, {"name": "web02"}],
+            "quorum_pct": "50.00"
+        }))
+        .unwrap();
+
+        assert_eq!(component.quorum().unwrap().to_string(), "1 of 2 hosts");
+
+        component
+            .add_host(&Host::minimal("web03").unwrap())
+            .unwrap();
+        assert_eq!(component.quorum_pct.as_deref(), Some("66.67"));
+
+        assert!(component.remove_host("missing").unwrap().is_none());
+        assert_eq!(component.quorum_pct.as_deref(), Some("66.67"));
+
+        let removed = component.remove_host("web01").unwrap();
+        assert_eq!(removed.unwrap().name(), "web01");
+        assert_eq!(component.quorum().unwrap().to_string(), "1 of 2 hosts");
+    }
+
+    #[test]
+    fn test_quorum_remove_then_add_host() {
+        let mut component: BSMComponent = serde_json::from_value(serde_json::json!({
+            "name": "Web",
+            "host_template": {"name": "Web"},
+            "hosts": [{"name": "web01"}, {"name": "web02"}, {"name": "web03"}, {"name": "web04"}],
+            "quorum_pct": "50.00"
+        }))
+        .unwrap();
+        let original = component.clone();
+
+        component.remove_host("web04").unwrap();
+        assert_eq!(component.quorum().unwrap(), Quorum::new(2, 3).unwrap());
+        component
+            .add_host(&Host::minimal("web04").unwrap())
+            .unwrap();
+        assert_eq!(component.quorum().unwrap(), Quorum::new(2, 4).unwrap());
+        assert_eq!(component, original);
+
+        component.remove_host("web04").unwrap();
+        component.remove_host("web03").unwrap();
+        assert_eq!(component.quorum().unwrap(), Quorum::new(1, 2).unwrap());
+
+        // Setting quorum_pct directly starts over from the new ratio.
+        component.quorum_pct = Some("100.00".to_string());
+        component.remove_host("web02").unwrap();
+        assert_eq!(component.quorum().unwrap(), Quorum::all(1));
+        component
+            .add_host(&Host::minimal("web02").unwrap())
+            .unwrap();
+        assert_eq!(component.quorum().unwrap(), Quorum::all(2));
+
+        component
+            .add_host(&Host::minimal("web03").unwrap())
+            .unwrap();
+        component.quorum_pct = Some("66.67".to_string());
+        component.remove_host("web03").unwrap();
+        assert_eq!(component.quorum().unwrap(), Quorum::all(2));
+        component
+            .add_host(&Host::minimal("web03").unwrap())
+            .unwrap();
+        assert_eq!(component.quorum().unwrap(), Quorum::new(2, 3).unwrap());
+    }
+
+    #[test]
+    fn test_builder_quorum_hosts() {
+        let host_template = HostTemplate::minimal("Web").unwrap();
+        let mut host_templates = ConfigObjectMap::<HostTemplate>::new();
+        host_templates.add(host_template.clone());
+
+        let hosts: ConfigObjectMap<Host> = ["web01", "web02", "web03"]
+            .iter()
+            .map(|name| {
+                let mut host = Host::minimal(name).unwrap();
+                host.hosttemplates = Some((&host_templates).into());
+                host
+            })
+            .collect();
+
+        let component = BSMComponent::builder()
+            .name("Web")
+            .quorum_hosts(2)
+            .host_template(host_template.clone())
+            .hosts(&hosts)
+            .build()
+            .unwrap();
+        assert_eq!(component.quorum_pct.as_deref(), Some("66.67"));
+
+        assert!(BSMComponent::builder()
+            .name("Web")
+            .host_template(host_template)
+            .hosts(&hosts)
+            .quorum_hosts(4)
+            .build()
+            .is_err());
+    }
+
     #[test]
     fn test_default() {
         let bsm_component = BSMComponent::default();
@@ -0,0 +1,499 @@
+use crate::prelude::*;
+use serde_json::{json, Map, Value};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+/// The read-only fields of a [`ConfigObject`] as named in the API JSON, which are set by the
+/// Opsview server and differ between instances of the same object.
+pub const READONLY_FIELDS: [&str; 4] = ["id", "ref", "uncommitted", "last_updated"];
+
+/// Options for [`diff_config_objects`] and [`diff_config_object_maps`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DiffOptions {
+    /// Whether to ignore the [`READONLY_FIELDS`], at any depth.
+    pub ignore_readonly: bool,
+}
+
+/// A change to a single field of a [`ConfigObject`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Change {
+    /// The field, or the item of a ref map, was added with the value.
+    Added(Value),
+    /// The field, or the item of a ref map, was removed. Holds the old value.
+    Removed(Value),
+    /// The value of the field changed.
+    Changed {
+        /// The old value.
+        old: Value,
+        /// The new value.
+        new: Value,
+    },
+}
+
+/// A change to a field of a [`ConfigObject`] and where it was found.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FieldDiff {
+    /// The path of the field, such as `check_period.name` or `hosttemplates[Network - Base]` for
+    /// an item of a ref map.
+    pub path: String,
+    /// The JSON Pointer of the field in the serialized old object, such as `/hosttemplates/2`.
+    pub pointer: String,
+    /// The change.
+    pub change: Change,
+    /// The index of a removed item of a ref map in the serialized old object.
+    index: Option<usize>,
+}
+
+impl FieldDiff {
+    /// Returns `true` if the change adds or removes an item of a ref map, such as a host template
+    /// of a host.
+    pub fn is_ref_change(&self) -> bool {
+        self.path.ends_with(']') && !matches!(self.change, Change::Changed { .. })
+    }
+
+    fn to_json_patch(&self) -> Value {
+        match &self.change {
+            Change::Added(value) => json!({"op": "add", "path": self.pointer, "value": value}),
+            Change::Removed(_) => json!({"op": "remove", "path": self.pointer}),
+            Change::Changed { new, .. } => {
+                json!({"op": "replace", "path": self.pointer, "value": new})
+            }
+        }
+    }
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.change {
+            Change::Added(value) => write!(f, "+ {}: {}", self.path, value),
+            Change::Removed(value) => write!(f, "- {}: {}", self.path, value),
+            Change::Changed { old, new } => write!(f, "~ {}: {} -> {}", self.path, old, new),
+        }
+    }
+}
+
+/// The field-level differences between two versions of a [`ConfigObject`].
+///
+/// Displays as text, one line per change. See also [`ObjectDiff::to_json_patch`] and
+/// [`ObjectDiff::to_markdown`].
+///
+/// # Example
+/// ```rust
+/// use opsview::config::*;
+/// use opsview::prelude::*;
+///
+/// let old = Hashtag::builder().name("web").description("Web servers").build().unwrap();
+/// let mut new = old.clone();
+/// new.description = Some("Web and proxy servers".to_string());
+/// new.all_hosts = Some(true);
+///
+/// let diff = diff_config_objects(&old, &new, &DiffOptions::default());
+/// assert_eq!(diff.changes.len(), 2);
+/// assert_eq!(
+///     diff.to_string(),
+///     "web:\n  + all_hosts: \"1\"\n  ~ description: \"Web servers\" -> \"Web and proxy servers\"\n"
+/// );
+/// let patch = diff.to_json_patch();
+/// assert_eq!(patch[0]["op"], "add");
+/// assert_eq!(patch[1]["op"], "replace");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObjectDiff {
+    /// The unique name of the object.
+    pub name: String,
+    /// The changes, ordered by path.
+    pub changes: Vec<FieldDiff>,
+}
+
+impl ObjectDiff {
+    /// Returns `true` if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the changes that add or remove items of ref maps.
+    pub fn ref_changes(&self) -> Vec<&FieldDiff> {
+        self.changes.iter().filter(|c| c.is_ref_change()).collect()
+    }
+
+    /// Renders the changes as an RFC 6902 JSON Patch that turns the serialized old object into the
+    /// new one.
+    ///
+    /// Items of ref maps are addressed by their index in the serialized old object, where they are
+    /// sorted by unique name as for any serialized [`ConfigRefMap`], so the patch applies to any
+    /// copy of the old object. Removed items are removed last, highest index first, so that the
+    /// indexes of the other operations stay valid, and added items are appended.
+    pub fn to_json_patch(&self) -> Value {
+        let mut removals: Vec<&FieldDiff> =
+            self.changes.iter().filter(|c| c.index.is_some()).collect();
+        removals.sort_by_key(|c| Reverse(c.index));
+        let (appends, others): (Vec<&FieldDiff>, Vec<&FieldDiff>) = self
+            .changes
+            .iter()
+            .filter(|c| c.index.is_none())
+            .partition(|c| c.pointer.ends_with("/-"));
+
+        Value::Array(
+            others
+                .into_iter()
+                .chain(removals)
+                .chain(appends)
+                .map(FieldDiff::to_json_patch)
+                .collect(),
+        )
+    }
+
+    /// Renders the changes as a Markdown section with a table of fields and their old and new
+    /// values.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!("### {}\n\n", self.name);
+        if self.changes.is_empty() {
+            markdown.push_str("No changes.\n");
+            return markdown;
+        }
+
+        markdown.push_str("| Field | Old | New |\n| --- | --- | --- |\n");
+        for change in &self.changes {
+            let (old, new) = match &change.change {
+                Change::Added(value) => (None, Some(value)),
+                Change::Removed(value) => (Some(value), None),
+                Change::Changed { old, new } => (Some(old), Some(new)),
+            };
+            markdown.push_str(&format!(
+                "| `{}` | {} | {} |\n",
+                change.path.replace('|', "\\|"),
+                markdown_value(old),
+                markdown_value(new)
+            ));
+        }
+        markdown
+    }
+}
+
+impl fmt::Display for ObjectDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "{}: no changes", self.name);
+        }
+        writeln!(f, "{}:", self.name)?;
+        for change in &self.changes {
+            writeln!(f, "  {}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares two versions of a [`ConfigObject`] field by field, through their API JSON.
+///
+/// Nested objects are compared field by field. Lists of named objects, such as ref maps, are
+/// compared by name, so that added and removed items are reported on their own and changed items
+/// are compared field by field. Any other list is compared as a whole.
+///
+/// # Arguments
+/// * `old` - The old version of the object.
+/// * `new` - The new version of the object.
+/// * `options` - The [`DiffOptions`] to use.
+pub fn diff_config_objects<T: ConfigObject>(old: &T, new: &T, options: &DiffOptions) -> ObjectDiff {
+    let to_value = |object: &T| {
+        let mut value = serde_json::to_value(object).unwrap_or(Value::Null);
+        if options.ignore_readonly {
+            strip_readonly(&mut value);
+        }
+        value
+    };
+
+    let mut changes = Vec::new();
+    diff_values("", "", &to_value(old), &to_value(new), &mut changes);
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    ObjectDiff {
+        name: old.unique_name(),
+        changes,
+    }
+}
+
+/// Compares the objects common to two [`ConfigObjectMap`]s with [`diff_config_objects`].
+///
+/// # Returns
+/// The [`ObjectDiff`]s of the objects that differ, ordered by name. Objects that are only in one of
+/// the maps are not included, see [`compare_config_object_maps`](super::compare_config_object_maps).
+pub fn diff_config_object_maps<T: ConfigObject>(
+    old: &ConfigObjectMap<T>,
+    new: &ConfigObjectMap<T>,
+    options: &DiffOptions,
+) -> Vec<ObjectDiff> {
+    let mut names: Vec<&String> = old.keys().filter(|k| new.contains(k)).collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let diff =
+                diff_config_objects(old.get(name)?.as_ref(), new.get(name)?.as_ref(), options);
+            (!diff.is_empty()).then_some(diff)
+        })
+        .collect()
+}
+
+fn diff_values(path: &str, pointer: &str, old: &Value, new: &Value, changes: &mut Vec<FieldDiff>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => diff_objects(path, pointer, old, new, changes),
+        (Value::Array(old_items), Value::Array(new_items)) if old_items != new_items => {
+            match (named_items(old_items), named_items(new_items)) {
+                (Some(old_items), Some(new_items)) => {
+                    diff_named_items(path, pointer, &old_items, &new_items, changes)
+                }
+                _ => changes.push(changed(path, pointer, old, new)),
+            }
+        }
+        _ if old != new => changes.push(changed(path, pointer, old, new)),
+        _ => {}
+    }
+}
+
+fn diff_objects(
+    path: &str,
+    pointer: &str,
+    old: &Map<String, Value>,
+    new: &Map<String, Value>,
+    changes: &mut Vec<FieldDiff>,
+) {
+    let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    for key in keys {
+        let path = match path {
+            "" => key.clone(),
+            _ => format!("{}.{}", path, key),
+        };
+        let pointer = format!("{}/{}", pointer, escape_pointer(key));
+        match (old.get(key), new.get(key)) {
+            (Some(old), Some(new)) => diff_values(&path, &pointer, old, new, changes),
+            (Some(old), None) => changes.push(FieldDiff {
+                path,
+                pointer,
+                change: Change::Removed(old.clone()),
+                index: None,
+            }),
+            (None, Some(new)) => changes.push(FieldDiff {
+                path,
+                pointer,
+                change: Change::Added(new.clone()),
+                index: None,
+            }),
+            (None, None) => {}
+        }
+    }
+}
+
+fn diff_named_items(
+    path: &str,
+    pointer: &str,
+    old: &HashMap<&str, (usize, &Value)>,
+    new: &HashMap<&str, (usize, &Value)>,
+    changes: &mut Vec<FieldDiff>,
+) {
+    let names: BTreeSet<&&str> = old.keys().chain(new.keys()).collect();
+    for name in names {
+        let item_path = format!("{}[{}]", path, name);
+        match (old.get(*name), new.get(*name)) {
+            (Some((index, old)), Some((_, new))) => {
+                let item_pointer = format!("{}/{}", pointer, index);
+                diff_values(&item_path, &item_pointer, old, new, changes)
+            }
+            (Some((index, old)), None) => changes.push(FieldDiff {
+                path: item_path,
+                pointer: format!("{}/{}", pointer, index),
+                change: Change::Removed((*old).clone()),
+                index: Some(*index),
+            }),
+            (None, Some((_, new))) => changes.push(FieldDiff {
+                path: item_path,
+                pointer: format!("{}/-", pointer),
+                change: Change::Added((*new).clone()),
+                index: None,
+            }),
+            (None, None) => {}
+        }
+    }
+}
+
+/// Returns the items of a list of objects by their unique `name`, with their index, or `None` if
+/// any item has no name or a name is repeated.
+fn named_items(items: &[Value]) -> Option<HashMap<&str, (usize, &Value)>> {
+    let mut named = HashMap::new();
+    for (index, item) in items.iter().enumerate() {
+        let name = item.get("name")?.as_str()?;
+        if named.insert(name, (index, item)).is_some() {
+            return None;
+        }
+    }
+    Some(named)
+}
+
+fn changed(path: &str, pointer: &str, old: &Value, new: &Value) -> FieldDiff {
+    FieldDiff {
+        path: path.to_string(),
+        pointer: pointer.to_string(),
+        change: Change::Changed {
+            old: old.clone(),
+            new: new.clone(),
+        },
+        index: None,
+    }
+}
+
+fn strip_readonly(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|key, _| !READONLY_FIELDS.contains(&key.as_str()));
+            map.values_mut().for_each(strip_readonly);
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_readonly),
+        _ => {}
+    }
+}
+
+/// Escapes a key for use in a JSON Pointer, as described in RFC 6901.
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn markdown_value(value: Option<&Value>) -> String {
+    match value {
+        Some(value) => format!("`{}`", value.to_string().replace('|', "\\|")),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Host;
+
+    fn host(value: Value) -> Host {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_diff_ref_maps() {
+        let old = host(json!({
+            "name": "web01",
+            "id": "1",
+            "ref": "/rest/config/host/1",
+            "check_period": {"name": "24x7", "ref": "/rest/config/timeperiod/1"},
+            "hosttemplates": [
+                {"name": "Base", "ref": "/rest/config/hosttemplate/1"},
+                {"name": "Web", "ref": "/rest/config/hosttemplate/2"}
+            ]
+        }));
+        let new = host(json!({
+            "name": "web01",
+            "id": "7",
+            "ref": "/rest/config/host/7",
+            "alias": "Web server",
+            "check_period": {"name": "workhours", "ref": "/rest/config/timeperiod/2"},
+            "hosttemplates": [
+                {"name": "Base", "ref": "/rest/config/hosttemplate/1"},
+                {"name": "Proxy", "ref": "/rest/config/hosttemplate/3"}
+            ]
+        }));
+
+        let options = DiffOptions {
+            ignore_readonly: true,
+        };
+        let diff = diff_config_objects(&old, &new, &options);
+        let paths: Vec<&str> = diff.changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "alias",
+                "check_period.name",
+                "hosttemplates[Proxy]",
+                "hosttemplates[Web]"
+            ]
+        );
+        assert_eq!(diff.ref_changes().len(), 2);
+        assert_eq!(
+            diff.changes[3].change,
+            Change::Removed(json!({"name": "Web"}))
+        );
+
+        let all = diff_config_objects(&old, &new, &DiffOptions::default());
+        assert!(all.changes.iter().any(|c| c.path == "check_period.ref"));
+        assert!(all.changes.iter().any(|c| c.path == "id"));
+    }
+
+    /// Applies the operations of a JSON Patch made by [`ObjectDiff::to_json_patch`].
+    fn apply_patch(document: &mut Value, patch: &Value) {
+        for op in patch.as_array().unwrap() {
+            let path = op["path"].as_str().unwrap();
+            let (parent, key) = path.rsplit_once('/').unwrap();
+            let target = document.pointer_mut(parent).unwrap();
+            match (op["op"].as_str().unwrap(), target) {
+                ("remove", Value::Array(items)) => {
+                    items.remove(key.parse().unwrap());
+                }
+                ("remove", Value::Object(map)) => {
+                    map.remove(key);
+                }
+                ("add", Value::Array(items)) => items.push(op["value"].clone()),
+                ("add" | "replace", Value::Object(map)) => {
+                    map.insert(key.to_string(), op["value"].clone());
+                }
+                (op, _) => panic!("unexpected operation {}", op),
+            }
+        }
+    }
+
+    #[test]
+    fn test_json_patch_applies() {
+        let keywords = ["a", "b", "c", "e", "f", "g", "h", "i"];
+        let old_json = json!({
+            "name": "web01",
+            "keywords": keywords.map(|name| json!({"name": name})),
+            "notification_options": "d,u"
+        });
+        let old = host(old_json.clone());
+        let new = host(json!({
+            "name": "web01",
+            "keywords": [{"name": "d"}, {"name": "i"}, {"name": "b"}, {"name": "f"}],
+            "alias": "Web/Proxy"
+        }));
+
+        let diff = diff_config_objects(&old, &new, &DiffOptions::default());
+        let patch = diff.to_json_patch();
+
+        // Apply the patch to a copy deserialized separately, from the items in another order.
+        let mut copy_json = old_json;
+        copy_json["keywords"].as_array_mut().unwrap().reverse();
+        let mut document = serde_json::to_value(host(copy_json)).unwrap();
+        assert_eq!(document, serde_json::to_value(&old).unwrap());
+
+        apply_patch(&mut document, &patch);
+        assert_eq!(
+            serde_json::to_value(host(document)).unwrap(),
+            serde_json::to_value(&new).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let old = host(json!({"name": "web01", "alias": "a|b"}));
+        let new = host(json!({"name": "web01", "other_addresses": "10.0.0.1"}));
+        let diff = diff_config_objects(&old, &new, &DiffOptions::default());
+
+        assert_eq!(
+            diff.to_string(),
+            "web01:\n  - alias: \"a|b\"\n  + other_addresses: \"10.0.0.1\"\n"
+        );
+        assert_eq!(
+            diff.to_markdown(),
+            "### web01\n\n| Field | Old | New |\n| --- | --- | --- |\n\
+             | `alias` | `\"a\\|b\"` |  |\n| `other_addresses` |  | `\"10.0.0.1\"` |\n"
+        );
+
+        let same = diff_config_objects(&old, &old, &DiffOptions::default());
+        assert_eq!(same.to_string(), "web01: no changes\n");
+        assert_eq!(same.to_markdown(), "### web01\n\nNo changes.\n");
+    }
+}
@@ -149,10 +149,15 @@ pub struct Host {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notification_period: Option<TimePeriodRef>,
 
-    /// A string containing [additional addresses](https://docs.itrsgroup.com/docs/opsview/6.8.9/configuration/hosts-groups/host/index.html#other-hostnamesips) for the `Host`.
-    /// Must be a comma-separated list of valid addresses.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub other_addresses: Option<String>,
+    /// The [additional addresses](https://docs.itrsgroup.com/docs/opsview/6.8.9/configuration/hosts-groups/host/index.html#other-hostnamesips) of the `Host`.
+    /// Stored by the API as a comma-separated list of addresses.
+    #[serde(
+        default,
+        serialize_with = "serialize_host_addresses",
+        deserialize_with = "deserialize_host_addresses",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub other_addresses: Option<Vec<HostAddress>>,
 
     /// [`ConfigRefMap`] of parent `HostRef` objects.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -592,6 +597,34 @@ impl Host {
         }
     }
 
+    /// Returns the notification options of the `Host` as a typed [`HostNotificationOptions`] set,
+    /// or `None` if they are not set.
+    ///
+    /// # Returns
+    /// An error if the options are not a valid list of host notification options.
+    pub fn notification_options_set(
+        &self,
+    ) -> Result<Option<HostNotificationOptions>, OpsviewConfigError> {
+        self.notification_options
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+    }
+
+    /// Returns the [`SNMPV3Settings`] of the `Host`, or `None` if it does not use SNMPv3.
+    pub fn snmpv3(&self) -> Option<SNMPV3Settings> {
+        match self.snmp_version {
+            Some(SNMPVersion::V3) => Some(SNMPV3Settings::from_fields(
+                self.snmpv3_username.as_ref(),
+                self.snmpv3_authprotocol.as_ref(),
+                self.snmpv3_authpassword.as_ref(),
+                self.snmpv3_privprotocol.as_ref(),
+                self.snmpv3_privpassword.as_ref(),
+            )),
+            _ => None,
+        }
+    }
+
     /// Update a variable in the [`Host`].
     pub fn update_variable(&mut self, variable: Variable) {
         match self.hostattributes {
@@ -806,17 +839,12 @@ impl Builder for HostBuilder {
         let hostgroup = require_field(&self.hostgroup, "hostgroup")?;
         let monitored_by = require_field(&self.monitored_by, "monitored_by")?;
 
-        let mut validated_snmp_version: Option<SNMPVersion> = None;
-        if let Some(ref snmp_version) = self.snmp_version {
-            match snmp_version {
-                SNMPVersion::V1 | SNMPVersion::V2c => {
-                    self.validate_snmp_version1_or_2c()?;
-                }
-                SNMPVersion::V3(_) => {
-                    let new_security_level = self.determine_snmpv3_security_level();
-                    validated_snmp_version = Some(SNMPVersion::V3(new_security_level));
-                }
+        match self.snmp_version {
+            Some(SNMPVersion::V1) | Some(SNMPVersion::V2c) => {
+                self.validate_snmp_version1_or_2c()?
             }
+            Some(SNMPVersion::V3) => self.snmpv3_settings().validate()?,
+            None => {}
         }
 
         let validated_alias = validate_opt_string(self.alias, validate_and_trim_description)?;
@@ -829,8 +857,11 @@ impl Builder for HostBuilder {
             validate_and_trim_host_notification_options,
         )?;
 
-        let validated_other_addresses =
-            validate_opt_string(self.other_addresses, validate_and_trim_other_addresses)?;
+        let validated_other_addresses = self
+            .other_addresses
+            .as_deref()
+            .map(parse_host_addresses)
+            .transpose()?;
 
         let validated_rancid_password =
             validate_opt_string(self.rancid_password, validate_rancid_password)?;
@@ -859,7 +890,7 @@ impl Builder for HostBuilder {
         Ok(Host {
             name: validate_and_trim_host_name(&name)?,
             hostgroup: Some(hostgroup),
-            ip: Some(validate_and_trim_ip_or_hostname(&ip)?),
+            ip: Some(ip.parse::<HostAddress>()?.to_string()),
             monitored_by: Some(monitored_by),
             alias: validated_alias,
             business_components: self.business_components,
@@ -894,7 +925,7 @@ impl Builder for HostBuilder {
             snmp_port: validated_snmp_port,
             snmp_use_getnext: self.snmp_use_getnext,
             snmp_use_ifname: self.snmp_use_ifname,
-            snmp_version: validated_snmp_version,
+            snmp_version: self.snmp_version,
             snmpv3_authprotocol: self.snmpv3_authprotocol,
             snmpv3_authpassword: validated_snmpv3_authpassword,
             snmpv3_privprotocol: self.snmpv3_privprotocol,
@@ -1337,7 +1368,8 @@ impl HostBuilder {
     /// Sets the ip field.
     ///
     /// # Arguments
-    /// * `ip` - IP address of the `Host`.
+    /// * `ip` - IP address or hostname of the `Host`, validated as a [`HostAddress`] when the
+    ///   `Host` is built.
     pub fn ip(mut self, ip: &str) -> Self {
         self.ip = Some(ip.to_string());
         self
@@ -1383,17 +1415,19 @@ impl HostBuilder {
     ///
     /// # Arguments
     /// * `notification_options` - Notification options for the `Host`.
-    ///    Valid options are:
-    ///    * `d` - send notifications on DOWN state
-    ///    * `u` - send notifications on UNREACHABLE state
-    ///    * `r` - send notifications on recovery (UP state)
-    ///    * `f` - send notifications when the host starts and stops flapping
+    ///   Valid options are:
+    ///   * `d` - send notifications on DOWN state
+    ///   * `u` - send notifications on UNREACHABLE state
+    ///   * `r` - send notifications on recovery (UP state)
+    ///   * `f` - send notifications when the host starts and stops flapping
     ///
     ///  Multiple options can be specified, separated by commas.
     ///
     ///  # Example
     ///  "d,u,r"
-    pub fn notification_options(mut self, notification_options: &str) -> Self {
+    ///
+    /// Accepts a string or a typed [`HostNotificationOptions`] set.
+    pub fn notification_options(mut self, notification_options: impl ToString) -> Self {
         self.notification_options = Some(notification_options.to_string());
         self
     }
@@ -1551,6 +1585,20 @@ impl HostBuilder {
         self
     }
 
+    /// Sets the snmp_version field to [`SNMPVersion::V3`] and the snmpv3 fields to the settings.
+    ///
+    /// # Arguments
+    /// * `settings` - The [`SNMPV3Settings`] to use.
+    pub fn snmpv3(mut self, settings: SNMPV3Settings) -> Self {
+        self.snmp_version = Some(SNMPVersion::V3);
+        self.snmpv3_username = settings.username;
+        self.snmpv3_authprotocol = settings.auth.as_ref().map(|a| a.protocol.clone());
+        self.snmpv3_authpassword = settings.auth.and_then(|a| a.password);
+        self.snmpv3_privprotocol = settings.privacy.as_ref().map(|p| p.protocol.clone());
+        self.snmpv3_privpassword = settings.privacy.and_then(|p| p.password);
+        self
+    }
+
     /// Sets the snmpv3_authprotocol field.
     ///
     /// # Arguments
@@ -1672,22 +1720,15 @@ impl HostBuilder {
         Ok(())
     }
 
-    /// Helper method to determine the snmpv3 security level.
-    fn determine_snmpv3_security_level(&self) -> SNMPV3SecurityLevel {
-        match (
+    /// Helper method to gather the snmpv3 settings.
+    fn snmpv3_settings(&self) -> SNMPV3Settings {
+        SNMPV3Settings::from_fields(
+            self.snmpv3_username.as_ref(),
             self.snmpv3_authprotocol.as_ref(),
+            self.snmpv3_authpassword.as_ref(),
             self.snmpv3_privprotocol.as_ref(),
-        ) {
-            (None, None) => SNMPV3SecurityLevel::NoAuthNoPriv,
-            // Can't have priv without auth.
-            (None, Some(_)) => SNMPV3SecurityLevel::NoAuthNoPriv,
-            // If auth was empty, we don't care about priv.
-            (Some(SNMPV3AuthProtocol::Unspecified), _) => SNMPV3SecurityLevel::NoAuthNoPriv,
-            // If priv was empty, we don't care about auth.
-            (_, Some(SNMPV3PrivProtocol::Unspecified)) => SNMPV3SecurityLevel::NoAuthNoPriv,
-            (Some(a), None) => SNMPV3SecurityLevel::AuthNoPriv(a.clone()),
-            (Some(a), Some(p)) => SNMPV3SecurityLevel::AuthPriv(a.clone(), p.clone()),
-        }
+            self.snmpv3_privpassword.as_ref(),
+        )
     }
 }
 
@@ -1980,6 +2021,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_other_addresses_strict_in_builder_lenient_in_serde() {
+        let cluster = MonitoringCluster::minimal("foo").unwrap();
+        let hostgroup = HostGroup::minimal("Opsview").unwrap();
+
+        let host = Host::builder()
+            .name("foo")
+            .hostgroup(hostgroup)
+            .ip("10.0.0.1")
+            .monitored_by(cluster)
+            .other_addresses("foo_bar,10.0.0.2")
+            .build();
+        assert_eq!(
+            host.unwrap_err(),
+            OpsviewConfigError::InvalidIP("foo_bar".to_string())
+        );
+
+        let host: Host = serde_json::from_value(serde_json::json!({
+            "name": "foo",
+            "other_addresses": "foo_bar,10.0.0.2,"
+        }))
+        .unwrap();
+        assert_eq!(host.other_addresses.map(|a| a.len()), Some(2));
+    }
+
+    #[test]
+    fn test_ip_strict_in_builder_lenient_in_serde() {
+        let build = |ip: &str| {
+            Host::builder()
+                .name("foo")
+                .hostgroup(HostGroup::minimal("Opsview").unwrap())
+                .ip(ip)
+                .monitored_by(MonitoringCluster::minimal("foo").unwrap())
+                .build()
+        };
+
+        assert_eq!(
+            build("[fe80::1%eth0]").unwrap().ip,
+            Some("fe80::1%eth0".to_string())
+        );
+        assert_eq!(
+            build(" db-01.example.com ").unwrap().ip,
+            Some("db-01.example.com".to_string())
+        );
+        for ip in ["foo_bar", "10.1.2"] {
+            assert_eq!(
+                build(ip).unwrap_err(),
+                OpsviewConfigError::InvalidIP(ip.to_string())
+            );
+        }
+
+        let host: Host = serde_json::from_value(serde_json::json!({
+            "name": "foo",
+            "ip": "foo_bar"
+        }))
+        .unwrap();
+        assert_eq!(host.ip, Some("foo_bar".to_string()));
+    }
+
     fn setup_host_builder(name: &str, password: &str) -> Result<Host, OpsviewConfigError> {
         let cluster = MonitoringCluster::minimal("foo").unwrap();
         let hostgroup = HostGroup::minimal("Opsview").unwrap();
@@ -2011,4 +2111,39 @@ mod tests {
             assert_eq!(result.map(|_| ()), expected);
         }
     }
+
+    #[test]
+    fn test_snmpv3_round_trip() {
+        let builder = || {
+            Host::builder()
+                .name("foo")
+                .hostgroup(HostGroup::minimal("bar").unwrap())
+                .ip("192.168.1.100")
+                .monitored_by(MonitoringCluster::minimal("baz").unwrap())
+                .enable_snmp(true)
+        };
+        let settings = SNMPV3Settings::new("monitor")
+            .auth(SNMPV3AuthProtocol::SHA512, "auth-secret")
+            .privacy(SNMPV3PrivProtocol::AES192, "priv-secret");
+
+        let host = builder().snmpv3(settings.clone()).build().unwrap();
+        let json = serde_json::to_value(&host).unwrap();
+        assert_eq!(json["snmp_version"], "3");
+        assert_eq!(json["snmpv3_authprotocol"], "SHA512");
+        assert_eq!(json["snmpv3_privprotocol"], "AES192");
+
+        let host: Host = serde_json::from_value(json).unwrap();
+        assert_eq!(host.snmpv3(), Some(settings));
+
+        let priv_without_auth = builder()
+            .snmp_version(SNMPVersion::V3)
+            .snmpv3_privprotocol(SNMPV3PrivProtocol::AES256)
+            .snmpv3_privpassword("priv-secret");
+        assert!(priv_without_auth.build().is_err());
+
+        let auth_without_password = builder()
+            .snmp_version(SNMPVersion::V3)
+            .snmpv3_authprotocol(SNMPV3AuthProtocol::SHA256);
+        assert!(auth_without_password.build().is_err());
+    }
 }
@@ -0,0 +1,355 @@
+use crate::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// The longest hostname allowed by RFC 1123, excluding any trailing dot.
+const MAX_HOSTNAME_LENGTH: usize = 253;
+
+/// The longest label of a hostname allowed by RFC 1123.
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// An address of a [`Host`](super::Host): an IPv4 address, an IPv6 address with an optional zone
+/// ID, or an RFC 1123 hostname.
+///
+/// IPv6 addresses may be given in brackets, such as `[fe80::1%eth0]`, but are always written back
+/// without them, as the Opsview API expects.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::HostAddress;
+///
+/// let address: HostAddress = "[fe80::1%eth0]".parse().unwrap();
+/// assert!(address.is_ip());
+/// assert_eq!(address.zone(), Some("eth0"));
+/// assert_eq!(address.to_string(), "fe80::1%eth0");
+///
+/// let address: HostAddress = "db-01.example.com".parse().unwrap();
+/// assert_eq!(address, HostAddress::Hostname("db-01.example.com".to_string()));
+///
+/// assert!("db_01.example.com".parse::<HostAddress>().is_err());
+/// assert!("-db.example.com".parse::<HostAddress>().is_err());
+/// assert!("10.1.2".parse::<HostAddress>().is_err());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum HostAddress {
+    /// An IPv4 address.
+    Ipv4(Ipv4Addr),
+    /// An IPv6 address, with the zone ID of a scoped address such as `fe80::1%eth0`.
+    Ipv6(Ipv6Addr, Option<String>),
+    /// A hostname. Names parsed with [`FromStr`] are valid RFC 1123 hostnames, but names read
+    /// from the API are kept as they are, since Opsview itself does not validate them.
+    Hostname(String),
+}
+
+impl HostAddress {
+    /// Returns `true` if the address is an IP address.
+    pub fn is_ip(&self) -> bool {
+        self.ip().is_some()
+    }
+
+    /// Returns the IP address, or `None` for a hostname.
+    pub fn ip(&self) -> Option<IpAddr> {
+        match self {
+            HostAddress::Ipv4(addr) => Some(IpAddr::V4(*addr)),
+            HostAddress::Ipv6(addr, _) => Some(IpAddr::V6(*addr)),
+            HostAddress::Hostname(_) => None,
+        }
+    }
+
+    /// Returns the zone ID of a scoped IPv6 address.
+    pub fn zone(&self) -> Option<&str> {
+        match self {
+            HostAddress::Ipv6(_, zone) => zone.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for HostAddress {
+    type Err = OpsviewConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let invalid = || OpsviewConfigError::InvalidIP(trimmed.to_string());
+
+        let bracketed = trimmed
+            .strip_prefix('[')
+            .map(|rest| rest.strip_suffix(']').ok_or_else(invalid))
+            .transpose()?;
+        let address = bracketed.unwrap_or(trimmed);
+
+        let (ip, zone) = match address.split_once('%') {
+            Some((ip, zone)) if is_valid_zone(zone) => (ip, Some(zone.to_string())),
+            Some(_) => return Err(invalid()),
+            None => (address, None),
+        };
+
+        if let Ok(addr) = ip.parse::<Ipv6Addr>() {
+            return Ok(HostAddress::Ipv6(addr, zone));
+        }
+        if bracketed.is_some() || zone.is_some() {
+            return Err(invalid());
+        }
+        if let Ok(addr) = address.parse::<Ipv4Addr>() {
+            return Ok(HostAddress::Ipv4(addr));
+        }
+        if is_valid_hostname(address) {
+            return Ok(HostAddress::Hostname(address.to_string()));
+        }
+        Err(invalid())
+    }
+}
+
+impl fmt::Display for HostAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HostAddress::Ipv4(addr) => write!(f, "{}", addr),
+            HostAddress::Ipv6(addr, Some(zone)) => write!(f, "{}%{}", addr, zone),
+            HostAddress::Ipv6(addr, None) => write!(f, "{}", addr),
+            HostAddress::Hostname(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl Serialize for HostAddress {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for HostAddress {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Parses a comma separated list of addresses, as used in the `other_addresses` field of a
+/// [`Host`](super::Host).
+///
+/// # Returns
+/// An error if any of the addresses is empty or not valid.
+pub fn parse_host_addresses(addresses: &str) -> Result<Vec<HostAddress>, OpsviewConfigError> {
+    addresses.split(',').map(str::parse).collect()
+}
+
+/// Serializes a list of [`HostAddress`]es as the comma separated string used by the API.
+pub(crate) fn serialize_host_addresses<S>(
+    addresses: &Option<Vec<HostAddress>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match addresses {
+        Some(addresses) => {
+            let addresses: Vec<String> = addresses.iter().map(ToString::to_string).collect();
+            serializer.serialize_str(&addresses.join(","))
+        }
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes the comma separated string used by the API into a list of [`HostAddress`]es.
+///
+/// Unlike [`parse_host_addresses`], this never fails on the contents of the string: empty entries
+/// are skipped and entries that are not valid addresses are kept as [`HostAddress::Hostname`], so
+/// that hosts created outside this crate can still be read. An empty string is an empty list.
+pub(crate) fn deserialize_host_addresses<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<HostAddress>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let addresses = Option::<String>::deserialize(deserializer)?;
+    Ok(addresses.map(|s| {
+        s.split(',')
+            .map(str::trim)
+            .filter(|address| !address.is_empty())
+            .map(|address| {
+                address
+                    .parse()
+                    .unwrap_or_else(|_| HostAddress::Hostname(address.to_string()))
+            })
+            .collect()
+    }))
+}
+
+/// Returns `true` if a string is a valid RFC 1123 hostname, allowing a single trailing dot.
+///
+/// Labels are made up of ASCII letters, digits and hyphens, may not start or end with a hyphen,
+/// and the last label may not be all digits so that the name cannot be mistaken for an address.
+fn is_valid_hostname(name: &str) -> bool {
+    let name = name.strip_suffix('.').unwrap_or(name);
+    if name.is_empty() || name.len() > MAX_HOSTNAME_LENGTH {
+        return false;
+    }
+
+    let labels: Vec<&str> = name.split('.').collect();
+    let valid_label = |label: &&str| {
+        !label.is_empty()
+            && label.len() <= MAX_LABEL_LENGTH
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+
+    labels.iter().all(valid_label)
+        && !labels
+            .last()
+            .is_some_and(|label| label.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Returns `true` if a string is a valid IPv6 zone ID, such as an interface name or index.
+fn is_valid_zone(zone: &str) -> bool {
+    !zone.is_empty()
+        && zone
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ipv6() {
+        let valid = [
+            ("::1", "::1"),
+            ("[2001:db8::1]", "2001:db8::1"),
+            ("fe80::1%eth0", "fe80::1%eth0"),
+            ("[fe80::1%25]", "fe80::1%25"),
+            (" 2001:DB8:0:0:0:0:0:1 ", "2001:db8::1"),
+        ];
+        for (input, expected) in valid {
+            let address: HostAddress = input.parse().unwrap();
+            assert!(matches!(address, HostAddress::Ipv6(..)), "{}", input);
+            assert_eq!(address.to_string(), expected);
+        }
+
+        let invalid = [
+            "[::1",
+            "::1]",
+            "fe80::1%",
+            "fe80::1%eth 0",
+            "[192.168.1.1]",
+            "192.168.1.1%eth0",
+            "2001:db8:::1",
+        ];
+        for input in invalid {
+            assert!(input.parse::<HostAddress>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_hostname() {
+        let long_label = "a".repeat(63);
+        let long_name = vec!["a".repeat(49); 5].join(".");
+        for input in [
+            "localhost",
+            "foo-bar",
+            "1password.com",
+            "example.com.",
+            long_label.as_str(),
+            long_name.as_str(),
+        ] {
+            assert_eq!(
+                input.parse::<HostAddress>(),
+                Ok(HostAddress::Hostname(input.to_string())),
+                "{}",
+                input
+            );
+        }
+
+        let too_long_label = "a".repeat(64);
+        let too_long_name = vec!["a".repeat(50); 5].join(".");
+        for input in [
+            "",
+            "foo_bar",
+            "foo..bar",
+            "-foo",
+            "foo-.bar",
+            "foo bar",
+            "127.0.1..1",
+            "12.1.1.2.1",
+            too_long_label.as_str(),
+            too_long_name.as_str(),
+        ] {
+            assert!(input.parse::<HostAddress>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_host_addresses() {
+        for addresses in [
+            "192.168.1.1,127.0.1.1",
+            "localhost,192.168.1.1",
+            "foo.bar,localhost",
+            "foo-bar,foo.bar,127.0.0.1",
+            "10.0.0.1, [fe80::1%eth0]",
+        ] {
+            assert!(parse_host_addresses(addresses).is_ok(), "{}", addresses);
+        }
+
+        for addresses in [
+            "foo-bar,foo.bar,,",
+            "foo-bar,foo.bar, ",
+            ",foo-bar,foo.bar",
+            "foo_bar,foo-bar",
+            "192.168.1.1,https://example.com",
+            "127.0.0.1,foo?bar",
+            "localhost,foo@bar",
+            "",
+            " ,",
+        ] {
+            assert!(parse_host_addresses(addresses).is_err(), "{}", addresses);
+        }
+    }
+
+    #[test]
+    fn test_host_addresses_serde() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Addresses {
+            #[serde(
+                serialize_with = "serialize_host_addresses",
+                deserialize_with = "deserialize_host_addresses"
+            )]
+            other_addresses: Option<Vec<HostAddress>>,
+        }
+
+        let json = r#"{"other_addresses":"10.0.0.1,fe80::1%eth0,db.example.com"}"#;
+        let addresses: Addresses = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            addresses.other_addresses.as_ref().unwrap()[0],
+            HostAddress::Ipv4(Ipv4Addr::new(10, 0, 0, 1))
+        );
+        assert_eq!(serde_json::to_string(&addresses).unwrap(), json);
+
+        let empty: Addresses = serde_json::from_str(r#"{"other_addresses":""}"#).unwrap();
+        assert_eq!(empty.other_addresses, Some(Vec::new()));
+        assert_eq!(
+            serde_json::to_string(&empty).unwrap(),
+            r#"{"other_addresses":""}"#
+        );
+
+        let lenient: Addresses =
+            serde_json::from_str(r#"{"other_addresses":"foo_bar, ,10.1.2,::1,"}"#).unwrap();
+        assert_eq!(
+            lenient.other_addresses,
+            Some(vec![
+                HostAddress::Hostname("foo_bar".to_string()),
+                HostAddress::Hostname("10.1.2".to_string()),
+                HostAddress::Ipv6(Ipv6Addr::LOCALHOST, None),
+            ])
+        );
+        assert!(parse_host_addresses("foo_bar,10.1.2").is_err());
+    }
+}
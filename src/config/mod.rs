@@ -5,9 +5,11 @@ mod checktype;
 mod compare;
 mod contact;
 mod contactlink;
+mod diff;
 mod fontawesomeicon;
 mod hashtag;
 mod host;
+mod hostaddress;
 mod hostcheckcommand;
 mod hostgroup;
 mod hosticon;
@@ -19,7 +21,9 @@ mod netaudit;
 mod netflowcollector;
 mod netflowsource;
 mod notificationmethod;
+mod notificationoptions;
 mod notificationprofile;
+mod objecttype;
 mod plugin;
 mod role;
 mod servicecheck;
@@ -29,7 +33,9 @@ mod snmpinterfaces;
 mod snmptraprules;
 mod snmpversion;
 mod tenancy;
+mod threshold;
 mod timeperiod;
+mod timerange;
 mod timezone;
 mod variable;
 
@@ -40,9 +46,11 @@ pub use self::checktype::*;
 pub use self::compare::*;
 pub use self::contact::*;
 pub use self::contactlink::*;
+pub use self::diff::*;
 pub use self::fontawesomeicon::*;
 pub use self::hashtag::*;
 pub use self::host::*;
+pub use self::hostaddress::*;
 pub use self::hostcheckcommand::*;
 pub use self::hostgroup::*;
 pub use self::hosticon::*;
@@ -54,7 +62,9 @@ pub use self::netaudit::*;
 pub use self::netflowcollector::*;
 pub use self::netflowsource::*;
 pub use self::notificationmethod::*;
+pub use self::notificationoptions::*;
 pub use self::notificationprofile::*;
+pub use self::objecttype::*;
 pub use self::plugin::*;
 pub use self::role::*;
 pub use self::servicecheck::*;
@@ -64,6 +74,8 @@ pub use self::snmpinterfaces::*;
 pub use self::snmptraprules::*;
 pub use self::snmpversion::*;
 pub use self::tenancy::*;
+pub use self::threshold::*;
 pub use self::timeperiod::*;
+pub use self::timerange::*;
 pub use self::timezone::*;
 pub use self::variable::*;
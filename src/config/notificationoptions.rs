@@ -0,0 +1,523 @@
+use crate::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{BitAnd, BitOr, Sub};
+use std::str::FromStr;
+
+/// A single option in a comma separated list of notification or stalking options, such as the `d`
+/// in `"d,u,r"`.
+pub trait OptionFlag: Copy + Ord + Hash + fmt::Debug + 'static {
+    /// All options, in the order Opsview lists them.
+    const ALL: &'static [Self];
+
+    /// The letter used to explicitly set no options, if Opsview accepts one.
+    const NONE: Option<char>;
+
+    /// Returns the letter of the option.
+    fn letter(self) -> char;
+
+    /// Returns the option with the given letter, if any.
+    fn from_letter(letter: char) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|flag| flag.letter() == letter)
+    }
+}
+
+/// Host notification options, as used by [`Host`](super::Host) and the host notification options of
+/// [`NotificationProfile`](super::NotificationProfile) and [`SharedNotificationProfile`](super::SharedNotificationProfile).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum HostNotificationOption {
+    /// `d` - Notify on DOWN state.
+    Down,
+    /// `u` - Notify on UNREACHABLE state.
+    Unreachable,
+    /// `r` - Notify on recovery (UP state).
+    Recovery,
+    /// `f` - Notify when the host starts and stops flapping.
+    Flapping,
+    /// `s` - Notify when scheduled downtime starts and ends.
+    Downtime,
+}
+
+impl OptionFlag for HostNotificationOption {
+    const ALL: &'static [Self] = &[
+        HostNotificationOption::Down,
+        HostNotificationOption::Unreachable,
+        HostNotificationOption::Recovery,
+        HostNotificationOption::Flapping,
+        HostNotificationOption::Downtime,
+    ];
+    const NONE: Option<char> = Some('n');
+
+    fn letter(self) -> char {
+        match self {
+            HostNotificationOption::Down => 'd',
+            HostNotificationOption::Unreachable => 'u',
+            HostNotificationOption::Recovery => 'r',
+            HostNotificationOption::Flapping => 'f',
+            HostNotificationOption::Downtime => 's',
+        }
+    }
+}
+
+/// Service check notification options, as used by [`ServiceCheck`](super::ServiceCheck) and the service notification
+/// options of [`NotificationProfile`](super::NotificationProfile) and [`SharedNotificationProfile`](super::SharedNotificationProfile).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum ServiceCheckNotificationOption {
+    /// `w` - Notify on WARNING state.
+    Warning,
+    /// `c` - Notify on CRITICAL state.
+    Critical,
+    /// `r` - Notify on recovery (OK state).
+    Recovery,
+    /// `u` - Notify on UNKNOWN state.
+    Unknown,
+    /// `f` - Notify when the service check starts and stops flapping.
+    Flapping,
+    /// `s` - Notify when scheduled downtime starts and ends.
+    Downtime,
+}
+
+impl OptionFlag for ServiceCheckNotificationOption {
+    const ALL: &'static [Self] = &[
+        ServiceCheckNotificationOption::Warning,
+        ServiceCheckNotificationOption::Critical,
+        ServiceCheckNotificationOption::Recovery,
+        ServiceCheckNotificationOption::Unknown,
+        ServiceCheckNotificationOption::Flapping,
+        ServiceCheckNotificationOption::Downtime,
+    ];
+    const NONE: Option<char> = Some('n');
+
+    fn letter(self) -> char {
+        match self {
+            ServiceCheckNotificationOption::Warning => 'w',
+            ServiceCheckNotificationOption::Critical => 'c',
+            ServiceCheckNotificationOption::Recovery => 'r',
+            ServiceCheckNotificationOption::Unknown => 'u',
+            ServiceCheckNotificationOption::Flapping => 'f',
+            ServiceCheckNotificationOption::Downtime => 's',
+        }
+    }
+}
+
+/// Stalking options of a [`ServiceCheck`](super::ServiceCheck).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum StalkingOption {
+    /// `w` - Stalk on WARNING state.
+    Warning,
+    /// `c` - Stalk on CRITICAL state.
+    Critical,
+    /// `u` - Stalk on UNKNOWN state.
+    Unknown,
+    /// `o` - Stalk on OK state.
+    Ok,
+}
+
+impl OptionFlag for StalkingOption {
+    const ALL: &'static [Self] = &[
+        StalkingOption::Warning,
+        StalkingOption::Critical,
+        StalkingOption::Unknown,
+        StalkingOption::Ok,
+    ];
+    const NONE: Option<char> = None;
+
+    fn letter(self) -> char {
+        match self {
+            StalkingOption::Warning => 'w',
+            StalkingOption::Critical => 'c',
+            StalkingOption::Unknown => 'u',
+            StalkingOption::Ok => 'o',
+        }
+    }
+}
+
+/// [`BSMComponent`](super::BSMComponent) notification options of [`NotificationProfile`](super::NotificationProfile) and
+/// [`SharedNotificationProfile`](super::SharedNotificationProfile).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum BSMComponentNotificationOption {
+    /// `r` - Notify on recovery.
+    Recovery,
+    /// `f` - Notify when the component fails.
+    Failure,
+    /// `i` - Notify when the component is impacted.
+    Impacted,
+    /// `a` - Notify when the availability drops below the configured threshold.
+    Availability,
+}
+
+impl OptionFlag for BSMComponentNotificationOption {
+    const ALL: &'static [Self] = &[
+        BSMComponentNotificationOption::Recovery,
+        BSMComponentNotificationOption::Failure,
+        BSMComponentNotificationOption::Impacted,
+        BSMComponentNotificationOption::Availability,
+    ];
+    const NONE: Option<char> = Some('n');
+
+    fn letter(self) -> char {
+        match self {
+            BSMComponentNotificationOption::Recovery => 'r',
+            BSMComponentNotificationOption::Failure => 'f',
+            BSMComponentNotificationOption::Impacted => 'i',
+            BSMComponentNotificationOption::Availability => 'a',
+        }
+    }
+}
+
+/// [`BSMService`](super::BSMService) notification options of [`NotificationProfile`](super::NotificationProfile) and
+/// [`SharedNotificationProfile`](super::SharedNotificationProfile).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum BSMServiceNotificationOption {
+    /// `r` - Notify on recovery.
+    Recovery,
+    /// `o` - Notify when the service goes offline.
+    Offline,
+    /// `i` - Notify when the service is impacted.
+    Impacted,
+    /// `a` - Notify when the availability drops below the configured threshold.
+    Availability,
+}
+
+impl OptionFlag for BSMServiceNotificationOption {
+    const ALL: &'static [Self] = &[
+        BSMServiceNotificationOption::Recovery,
+        BSMServiceNotificationOption::Offline,
+        BSMServiceNotificationOption::Impacted,
+        BSMServiceNotificationOption::Availability,
+    ];
+    const NONE: Option<char> = Some('n');
+
+    fn letter(self) -> char {
+        match self {
+            BSMServiceNotificationOption::Recovery => 'r',
+            BSMServiceNotificationOption::Offline => 'o',
+            BSMServiceNotificationOption::Impacted => 'i',
+            BSMServiceNotificationOption::Availability => 'a',
+        }
+    }
+}
+
+/// A set of options, such as the `"d,u,r"` notification options of a [`Host`](super::Host).
+///
+/// The set is displayed and serialized as the comma separated list used by the Opsview API, in
+/// the order Opsview lists the options. An empty set is written as `n` where Opsview accepts it.
+///
+/// The string builder methods for options accept these sets as well as strings.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::{HostNotificationOption, HostNotificationOptions};
+///
+/// let options: HostNotificationOptions = "r,d".parse().unwrap();
+/// assert!(options.contains(HostNotificationOption::Down));
+/// assert_eq!(options.to_string(), "d,r");
+///
+/// let more = options | HostNotificationOption::Flapping.into();
+/// assert_eq!(more.to_string(), "d,r,f");
+/// assert_eq!(HostNotificationOptions::none().to_string(), "n");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct OptionFlags<F: OptionFlag> {
+    flags: BTreeSet<F>,
+}
+
+/// Notification options of a [`Host`](super::Host), e.g. `"d,u,r"`.
+pub type HostNotificationOptions = OptionFlags<HostNotificationOption>;
+
+/// Notification options of a [`ServiceCheck`](super::ServiceCheck), e.g. `"w,c,r"`.
+pub type ServiceCheckNotificationOptions = OptionFlags<ServiceCheckNotificationOption>;
+
+/// Stalking options of a [`ServiceCheck`](super::ServiceCheck), e.g. `"w,c"`.
+pub type StalkingOptions = OptionFlags<StalkingOption>;
+
+/// [`BSMComponent`](super::BSMComponent) notification options of a notification profile, e.g. `"f,i"`.
+pub type BSMComponentNotificationOptions = OptionFlags<BSMComponentNotificationOption>;
+
+/// [`BSMService`](super::BSMService) notification options of a notification profile, e.g. `"o,i"`.
+pub type BSMServiceNotificationOptions = OptionFlags<BSMServiceNotificationOption>;
+
+impl<F: OptionFlag> OptionFlags<F> {
+    /// Returns an empty set.
+    pub fn none() -> Self {
+        OptionFlags {
+            flags: BTreeSet::new(),
+        }
+    }
+
+    /// Returns a set with all options.
+    pub fn all() -> Self {
+        F::ALL.iter().copied().collect()
+    }
+
+    /// Returns `true` if the set contains the option.
+    pub fn contains(&self, flag: F) -> bool {
+        self.flags.contains(&flag)
+    }
+
+    /// Adds an option to the set, returning `true` if it was not already present.
+    pub fn insert(&mut self, flag: F) -> bool {
+        self.flags.insert(flag)
+    }
+
+    /// Removes an option from the set, returning `true` if it was present.
+    pub fn remove(&mut self, flag: F) -> bool {
+        self.flags.remove(&flag)
+    }
+
+    /// Returns the number of options in the set.
+    pub fn len(&self) -> usize {
+        self.flags.len()
+    }
+
+    /// Returns `true` if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.flags.is_empty()
+    }
+
+    /// Returns an iterator over the options, in the order Opsview lists them.
+    pub fn iter(&self) -> impl Iterator<Item = F> + '_ {
+        self.flags.iter().copied()
+    }
+
+    /// Returns the options in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        self.flags.union(&other.flags).copied().collect()
+    }
+
+    /// Returns the options in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.flags.intersection(&other.flags).copied().collect()
+    }
+
+    /// Returns the options in this set but not in the other.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.flags.difference(&other.flags).copied().collect()
+    }
+
+    /// Returns the options in either set but not in both.
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.flags
+            .symmetric_difference(&other.flags)
+            .copied()
+            .collect()
+    }
+
+    /// Returns the options not in the set.
+    pub fn complement(&self) -> Self {
+        Self::all().difference(self)
+    }
+
+    /// Returns `true` if every option in this set is also in the other.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.flags.is_subset(&other.flags)
+    }
+}
+
+impl<F: OptionFlag> Default for OptionFlags<F> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<F: OptionFlag> From<F> for OptionFlags<F> {
+    fn from(flag: F) -> Self {
+        std::iter::once(flag).collect()
+    }
+}
+
+impl<F: OptionFlag> FromIterator<F> for OptionFlags<F> {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        OptionFlags {
+            flags: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<F: OptionFlag> BitOr for OptionFlags<F> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(&rhs)
+    }
+}
+
+impl<F: OptionFlag> BitAnd for OptionFlags<F> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(&rhs)
+    }
+}
+
+impl<F: OptionFlag> Sub for OptionFlags<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(&rhs)
+    }
+}
+
+impl<F: OptionFlag> fmt::Display for OptionFlags<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.flags.is_empty() {
+            if let Some(none) = F::NONE {
+                return write!(f, "{}", none);
+            }
+        }
+
+        let letters: Vec<String> = self.iter().map(|flag| flag.letter().to_string()).collect();
+        write!(f, "{}", letters.join(","))
+    }
+}
+
+/// Parses a comma separated list of options. Surrounding whitespace is ignored, and an empty
+/// string or the letter for no options, where Opsview accepts one, gives an empty set.
+impl<F: OptionFlag> FromStr for OptionFlags<F> {
+    type Err = OpsviewConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Ok(Self::none());
+        }
+
+        let mut flags = Self::none();
+        let mut none = false;
+        for part in trimmed.split(',').map(str::trim) {
+            let mut chars = part.chars();
+            let letter = match (chars.next(), chars.next()) {
+                (Some(letter), None) => letter,
+                _ => {
+                    return Err(OpsviewConfigError::InvalidNotificationOptions(
+                        s.to_string(),
+                    ))
+                }
+            };
+
+            if F::NONE == Some(letter) {
+                if none {
+                    return Err(OpsviewConfigError::DuplicateOptions(s.to_string()));
+                }
+                none = true;
+                continue;
+            }
+
+            let flag = F::from_letter(letter)
+                .ok_or_else(|| OpsviewConfigError::InvalidNotificationOptions(s.to_string()))?;
+            if !flags.insert(flag) {
+                return Err(OpsviewConfigError::DuplicateOptions(s.to_string()));
+            }
+        }
+
+        if none && !flags.is_empty() {
+            return Err(OpsviewConfigError::ConflictingOptions(s.to_string()));
+        }
+
+        Ok(flags)
+    }
+}
+
+impl<F: OptionFlag> Serialize for OptionFlags<F> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de, F: OptionFlag> Deserialize<'de> for OptionFlags<F> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NotificationProfile, SharedNotificationProfile};
+
+    #[test]
+    fn test_parse_and_display() {
+        let options: ServiceCheckNotificationOptions = " u, w ,c".parse().unwrap();
+        assert_eq!(options.to_string(), "w,c,u");
+        assert_eq!(options.len(), 3);
+
+        let none: HostNotificationOptions = "n".parse().unwrap();
+        assert!(none.is_empty());
+        assert_eq!(none.to_string(), "n");
+        assert_eq!(StalkingOptions::none().to_string(), "");
+        assert_eq!(StalkingOptions::all().to_string(), "w,c,u,o");
+
+        assert_eq!(
+            "d,x".parse::<HostNotificationOptions>(),
+            Err(OpsviewConfigError::InvalidNotificationOptions(
+                "d,x".to_string()
+            ))
+        );
+        assert_eq!(
+            "d,d".parse::<HostNotificationOptions>(),
+            Err(OpsviewConfigError::DuplicateOptions("d,d".to_string()))
+        );
+        assert_eq!(
+            "n,d".parse::<HostNotificationOptions>(),
+            Err(OpsviewConfigError::ConflictingOptions("n,d".to_string()))
+        );
+        assert!("n".parse::<StalkingOptions>().is_err());
+        assert!("dr".parse::<HostNotificationOptions>().is_err());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: BSMServiceNotificationOptions = "o,i".parse().unwrap();
+        let b: BSMServiceNotificationOptions = "i,r".parse().unwrap();
+
+        assert_eq!((a.clone() | b.clone()).to_string(), "r,o,i");
+        assert_eq!((a.clone() & b.clone()).to_string(), "i");
+        assert_eq!((a.clone() - b.clone()).to_string(), "o");
+        assert_eq!(a.symmetric_difference(&b).to_string(), "r,o");
+        assert_eq!(a.complement().to_string(), "r,a");
+        assert!(a.intersection(&b).is_subset(&a));
+    }
+
+    #[test]
+    fn test_serde() {
+        let options: BSMComponentNotificationOptions = serde_json::from_str("\"i,f\"").unwrap();
+        assert!(options.contains(BSMComponentNotificationOption::Failure));
+        assert_eq!(serde_json::to_string(&options).unwrap(), "\"f,i\"");
+        assert!(serde_json::from_str::<BSMComponentNotificationOptions>("\"o\"").is_err());
+    }
+
+    #[test]
+    fn test_builders_accept_sets() {
+        let options = HostNotificationOptions::from(HostNotificationOption::Down)
+            | HostNotificationOption::Recovery.into();
+        let profile = NotificationProfile::builder()
+            .name("On call")
+            .host_notification_options(&options)
+            .business_service_options("n")
+            .build()
+            .unwrap();
+        assert_eq!(profile.host_notification_options, Some("d,r".to_string()));
+
+        let shared = SharedNotificationProfile::builder()
+            .name("Shared")
+            .service_notification_options(ServiceCheckNotificationOptions::all())
+            .build()
+            .unwrap();
+        assert_eq!(
+            shared.service_notification_options,
+            Some("w,c,r,u,f,s".to_string())
+        );
+    }
+}
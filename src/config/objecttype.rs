@@ -0,0 +1,273 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents the type of a configuration object that can be managed via the Opsview API.
+///
+/// Each variant corresponds to one of the `/rest/config/...` endpoints, and to one of the
+/// [`ConfigObject`] types in this crate.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum ConfigObjectType {
+    /// A [`BSMComponent`](crate::config::BSMComponent).
+    BSMComponent,
+    /// A [`BSMService`](crate::config::BSMService).
+    BSMService,
+    /// A [`Contact`](crate::config::Contact).
+    Contact,
+    /// A [`Hashtag`](crate::config::Hashtag).
+    Hashtag,
+    /// A [`Host`](crate::config::Host).
+    Host,
+    /// A [`HostCheckCommand`](crate::config::HostCheckCommand).
+    HostCheckCommand,
+    /// A [`HostGroup`](crate::config::HostGroup).
+    HostGroup,
+    /// A [`HostIcon`](crate::config::HostIcon).
+    HostIcon,
+    /// A [`HostTemplate`](crate::config::HostTemplate).
+    HostTemplate,
+    /// A [`MonitoringCluster`](crate::config::MonitoringCluster).
+    MonitoringCluster,
+    /// A [`NetflowCollector`](crate::config::NetflowCollector).
+    NetflowCollector,
+    /// A [`NetflowSource`](crate::config::NetflowSource).
+    NetflowSource,
+    /// A [`NotificationMethod`](crate::config::NotificationMethod).
+    NotificationMethod,
+    /// A [`Plugin`](crate::config::Plugin).
+    Plugin,
+    /// A [`Role`](crate::config::Role).
+    Role,
+    /// A [`ServiceCheck`](crate::config::ServiceCheck).
+    ServiceCheck,
+    /// A [`ServiceGroup`](crate::config::ServiceGroup).
+    ServiceGroup,
+    /// A [`SharedNotificationProfile`](crate::config::SharedNotificationProfile).
+    SharedNotificationProfile,
+    /// A [`Tenancy`](crate::config::Tenancy).
+    Tenancy,
+    /// A [`TimePeriod`](crate::config::TimePeriod).
+    TimePeriod,
+    /// A [`Variable`](crate::config::Variable).
+    Variable,
+}
+
+impl ConfigObjectType {
+    /// All configuration object types.
+    pub const ALL: [ConfigObjectType; 21] = [
+        ConfigObjectType::BSMComponent,
+        ConfigObjectType::BSMService,
+        ConfigObjectType::Contact,
+        ConfigObjectType::Hashtag,
+        ConfigObjectType::Host,
+        ConfigObjectType::HostCheckCommand,
+        ConfigObjectType::HostGroup,
+        ConfigObjectType::HostIcon,
+        ConfigObjectType::HostTemplate,
+        ConfigObjectType::MonitoringCluster,
+        ConfigObjectType::NetflowCollector,
+        ConfigObjectType::NetflowSource,
+        ConfigObjectType::NotificationMethod,
+        ConfigObjectType::Plugin,
+        ConfigObjectType::Role,
+        ConfigObjectType::ServiceCheck,
+        ConfigObjectType::ServiceGroup,
+        ConfigObjectType::SharedNotificationProfile,
+        ConfigObjectType::Tenancy,
+        ConfigObjectType::TimePeriod,
+        ConfigObjectType::Variable,
+    ];
+
+    /// Returns the name of the endpoint for this type, i.e. the last segment of its config path.
+    pub fn endpoint(&self) -> &'static str {
+        match self {
+            ConfigObjectType::BSMComponent => "bsmcomponent",
+            ConfigObjectType::BSMService => "bsmservice",
+            ConfigObjectType::Contact => "contact",
+            ConfigObjectType::Hashtag => "keyword",
+            ConfigObjectType::Host => "host",
+            ConfigObjectType::HostCheckCommand => "hostcheckcommand",
+            ConfigObjectType::HostGroup => "hostgroup",
+            ConfigObjectType::HostIcon => "hosticons",
+            ConfigObjectType::HostTemplate => "hosttemplate",
+            ConfigObjectType::MonitoringCluster => "monitoringcluster",
+            ConfigObjectType::NetflowCollector => "netflow_collector",
+            ConfigObjectType::NetflowSource => "netflow_source",
+            ConfigObjectType::NotificationMethod => "notificationmethod",
+            ConfigObjectType::Plugin => "plugin",
+            ConfigObjectType::Role => "role",
+            ConfigObjectType::ServiceCheck => "servicecheck",
+            ConfigObjectType::ServiceGroup => "servicegroup",
+            ConfigObjectType::SharedNotificationProfile => "sharednotificationprofile",
+            ConfigObjectType::Tenancy => "tenancy",
+            ConfigObjectType::TimePeriod => "timeperiod",
+            ConfigObjectType::Variable => "attribute",
+        }
+    }
+
+    /// Returns the config path for this type, as returned by [`ConfigObject::config_path`].
+    pub fn config_path(&self) -> String {
+        format!("/config/{}", self.endpoint())
+    }
+
+    /// Returns the `ref_` of the object of this type with the given ID.
+    pub fn ref_for_id(&self, id: u64) -> String {
+        format!("/rest/config/{}/{}", self.endpoint(), id)
+    }
+
+    /// Returns the `ConfigObjectType` of a [`ConfigObject`] type, if it has a config path.
+    pub fn of<T: ConfigObject>() -> Option<ConfigObjectType> {
+        T::config_path().and_then(|p| ConfigObjectType::from_config_path(&p))
+    }
+
+    /// Returns the `ConfigObjectType` matching a config path such as `/config/host`.
+    pub fn from_config_path(path: &str) -> Option<ConfigObjectType> {
+        let endpoint = path.trim_end_matches('/').strip_prefix("/config/")?;
+        ConfigObjectType::from_endpoint(endpoint)
+    }
+
+    /// Returns the `ConfigObjectType` matching an endpoint name such as `host` or `keyword`.
+    pub fn from_endpoint(endpoint: &str) -> Option<ConfigObjectType> {
+        ConfigObjectType::ALL
+            .into_iter()
+            .find(|t| t.endpoint() == endpoint)
+    }
+
+    /// Parses a `ref_` such as `/rest/config/host/12` into its type and ID.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewClientError::InvalidRef` if the ref is not a valid config object ref.
+    pub fn parse_ref(ref_: &str) -> Result<(ConfigObjectType, u64), OpsviewClientError> {
+        let invalid =
+            |reason: &str| OpsviewClientError::InvalidRef(reason.to_string(), ref_.to_string());
+
+        let rest = ref_
+            .strip_prefix("/rest/config/")
+            .ok_or_else(|| invalid("Ref must start with '/rest/config/'"))?;
+
+        let (endpoint, id) = rest
+            .split_once('/')
+            .ok_or_else(|| invalid("Ref must end with an ID"))?;
+
+        let object_type = ConfigObjectType::from_endpoint(endpoint)
+            .ok_or_else(|| invalid("Unknown object type"))?;
+
+        let id = id.parse::<u64>().map_err(|_| invalid("Invalid ID"))?;
+
+        Ok((object_type, id))
+    }
+}
+
+impl fmt::Display for ConfigObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ConfigObjectType::BSMComponent => "BSM Component",
+            ConfigObjectType::BSMService => "BSM Service",
+            ConfigObjectType::Contact => "Contact",
+            ConfigObjectType::Hashtag => "Hashtag",
+            ConfigObjectType::Host => "Host",
+            ConfigObjectType::HostCheckCommand => "Host Check Command",
+            ConfigObjectType::HostGroup => "Host Group",
+            ConfigObjectType::HostIcon => "Host Icon",
+            ConfigObjectType::HostTemplate => "Host Template",
+            ConfigObjectType::MonitoringCluster => "Monitoring Cluster",
+            ConfigObjectType::NetflowCollector => "Netflow Collector",
+            ConfigObjectType::NetflowSource => "Netflow Source",
+            ConfigObjectType::NotificationMethod => "Notification Method",
+            ConfigObjectType::Plugin => "Plugin",
+            ConfigObjectType::Role => "Role",
+            ConfigObjectType::ServiceCheck => "Service Check",
+            ConfigObjectType::ServiceGroup => "Service Group",
+            ConfigObjectType::SharedNotificationProfile => "Shared Notification Profile",
+            ConfigObjectType::Tenancy => "Tenancy",
+            ConfigObjectType::TimePeriod => "Time Period",
+            ConfigObjectType::Variable => "Variable",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for ConfigObjectType {
+    type Err = OpsviewConfigError;
+
+    /// Parses a `ConfigObjectType` from either its endpoint name (e.g. `keyword`) or its human
+    /// readable name in any case, with or without spaces (e.g. `Host Template` or `hashtag`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '_' && *c != '-')
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        let aliases = |t: &ConfigObjectType| -> bool {
+            let display: String = t
+                .to_string()
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+                .to_ascii_lowercase();
+            let endpoint = t.endpoint().replace('_', "");
+            normalized == display
+                || normalized == endpoint
+                || matches!(
+                    (t, normalized.as_str()),
+                    (ConfigObjectType::BSMComponent, "businesscomponent")
+                        | (ConfigObjectType::BSMService, "businessservice")
+                        | (ConfigObjectType::HostIcon, "hosticon")
+                )
+        };
+
+        ConfigObjectType::ALL
+            .into_iter()
+            .find(aliases)
+            .ok_or_else(|| OpsviewConfigError::UnknownObjectType(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::*;
+
+    #[test]
+    fn test_config_object_type_matches_config_path() {
+        assert_eq!(ConfigObjectType::of::<Host>(), Some(ConfigObjectType::Host));
+        assert_eq!(
+            ConfigObjectType::of::<Hashtag>(),
+            Some(ConfigObjectType::Hashtag)
+        );
+        assert_eq!(
+            ConfigObjectType::of::<Variable>(),
+            Some(ConfigObjectType::Variable)
+        );
+        assert_eq!(
+            ConfigObjectType::of::<NetflowSource>(),
+            Some(ConfigObjectType::NetflowSource)
+        );
+        assert_eq!(
+            ConfigObjectType::HostTemplate.config_path(),
+            HostTemplate::config_path().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_ref() {
+        assert_eq!(
+            ConfigObjectType::parse_ref("/rest/config/keyword/3"),
+            Ok((ConfigObjectType::Hashtag, 3))
+        );
+        assert!(ConfigObjectType::parse_ref("/rest/config/host/abc").is_err());
+        assert!(ConfigObjectType::parse_ref("/rest/config/unknown/1").is_err());
+        assert!(ConfigObjectType::parse_ref("/config/host/1").is_err());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("host template".parse(), Ok(ConfigObjectType::HostTemplate));
+        assert_eq!("Keyword".parse(), Ok(ConfigObjectType::Hashtag));
+        assert_eq!("attribute".parse(), Ok(ConfigObjectType::Variable));
+        assert_eq!("servicecheck".parse(), Ok(ConfigObjectType::ServiceCheck));
+        assert!("foo".parse::<ConfigObjectType>().is_err());
+    }
+}
@@ -223,10 +223,9 @@ impl ConfigObject for Role {
     /// # Returns
     /// A string representing the unique name of the `Role`.
     fn unique_name(&self) -> String {
-        if self.tenancy.is_some() {
-            format!("{}:{}", self.name, self.tenancy.as_ref().unwrap().name())
-        } else {
-            self.name.clone()
+        match self.tenancy {
+            Some(ref tenancy) => format!("{}:{}", self.name, tenancy.name()),
+            None => self.name.clone(),
         }
     }
 }
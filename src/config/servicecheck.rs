@@ -392,6 +392,49 @@ impl Default for ServiceCheck {
     }
 }
 
+impl ServiceCheck {
+    /// Returns the warning and critical [`Threshold`]s of the `ServiceCheck`, parsed from the
+    /// comparison and value fields. A threshold is `None` unless both of its fields are set.
+    ///
+    /// # Returns
+    /// An error if a comparison is unknown or its value is not of the right type.
+    pub fn thresholds(&self) -> Result<ServiceCheckThresholds, OpsviewConfigError> {
+        Ok(ServiceCheckThresholds {
+            warning: threshold_from_fields(
+                self.warning_comparison.as_deref(),
+                self.warning_value.as_deref(),
+            )?,
+            critical: threshold_from_fields(
+                self.critical_comparison.as_deref(),
+                self.critical_value.as_deref(),
+            )?,
+        })
+    }
+
+    /// Returns the notification options of the `ServiceCheck` as a typed
+    /// [`ServiceCheckNotificationOptions`] set, or `None` if they are not set.
+    ///
+    /// # Returns
+    /// An error if the options are not a valid list of service check notification options.
+    pub fn notification_options_set(
+        &self,
+    ) -> Result<Option<ServiceCheckNotificationOptions>, OpsviewConfigError> {
+        self.notification_options
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+    }
+
+    /// Returns the stalking options of the `ServiceCheck` as a typed [`StalkingOptions`] set, or
+    /// `None` if they are not set.
+    ///
+    /// # Returns
+    /// An error if the options are not a valid list of stalking options.
+    pub fn stalking_set(&self) -> Result<Option<StalkingOptions>, OpsviewConfigError> {
+        self.stalking.as_deref().map(str::parse).transpose()
+    }
+}
+
 /// Enables the creation of a [`ServiceCheck`] instance from a JSON representation.
 /// Typically used when parsing JSON data from the Opsview API.
 impl CreateFromJson for ServiceCheck {}
@@ -662,6 +705,15 @@ impl Builder for ServiceCheckBuilder {
         let validated_warning_value =
             validate_opt_string(self.warning_value, validate_and_trim_warning_value)?;
 
+        threshold_from_fields(
+            validated_warning_comparison.as_deref(),
+            validated_warning_value.as_deref(),
+        )?;
+        threshold_from_fields(
+            validated_critical_comparison.as_deref(),
+            validated_critical_value.as_deref(),
+        )?;
+
         Ok(ServiceCheck {
             name: validate_and_trim_servicecheck_name(&name)?,
             args: Some(validate_servicecheck_args(&args)?),
@@ -1060,6 +1112,16 @@ impl ServiceCheckBuilder {
         self
     }
 
+    /// Sets the critical_comparison and critical_value fields from a [`Threshold`].
+    ///
+    /// # Arguments
+    /// * `threshold` - The critical `Threshold` for the `ServiceCheck`.
+    pub fn critical_threshold(mut self, threshold: Threshold) -> Self {
+        self.critical_comparison = Some(threshold.comparison().to_string());
+        self.critical_value = Some(threshold.value());
+        self
+    }
+
     /// Sets the critical_value field.
     ///
     /// # Arguments
@@ -1197,7 +1259,9 @@ impl ServiceCheckBuilder {
     /// * `r` - Send notifications on recovery.
     /// * `u` - Send notifications on unknown state.
     /// * `f` - Send notifications on flap detection.
-    pub fn notification_options(mut self, notification_options: &str) -> Self {
+    ///
+    /// Accepts a string or a typed [`ServiceCheckNotificationOptions`] set.
+    pub fn notification_options(mut self, notification_options: impl ToString) -> Self {
         self.notification_options = Some(notification_options.to_string());
         self
     }
@@ -1302,7 +1366,10 @@ impl ServiceCheckBuilder {
     /// * `w` - Stalk on warning state.
     /// * `c` - Stalk on critical state.
     /// * `u` - Stalk on unknown state.
-    pub fn stalking(mut self, stalking: &str) -> Self {
+    ///
+    /// Accepts a string or a typed [`StalkingOptions`] set. An empty string or
+    /// [`StalkingOptions::none()`] turns stalking off.
+    pub fn stalking(mut self, stalking: impl ToString) -> Self {
         self.stalking = Some(stalking.to_string());
         self
     }
@@ -1325,6 +1392,18 @@ impl ServiceCheckBuilder {
         self
     }
 
+    /// Sets the warning_comparison and warning_value fields from a [`Threshold`].
+    ///
+    /// Only the numeric comparisons `==`, `<` and `>` are valid for warnings.
+    ///
+    /// # Arguments
+    /// * `threshold` - The warning `Threshold` for the `ServiceCheck`.
+    pub fn warning_threshold(mut self, threshold: Threshold) -> Self {
+        self.warning_comparison = Some(threshold.comparison().to_string());
+        self.warning_value = Some(threshold.value());
+        self
+    }
+
     /// Sets the warning_value field.
     ///
     /// # Arguments
@@ -1585,6 +1664,7 @@ fn validate_calculate_rate(s: &str) -> Result<String, OpsviewConfigError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::ServiceCheckState;
 
     #[test]
     fn test_default() {
@@ -1597,4 +1677,79 @@ mod tests {
         let servicecheck = ServiceCheck::minimal("test");
         assert_eq!(servicecheck.unwrap().name, "test");
     }
+
+    #[test]
+    fn test_thresholds() {
+        let builder = ServiceCheck::builder()
+            .name("ifOperStatus")
+            .args("")
+            .plugin(Plugin::minimal("check_snmp").unwrap())
+            .servicegroup(ServiceGroup::minimal("Network").unwrap());
+
+        let check = builder
+            .clone()
+            .warning_threshold(Threshold::GreaterThan(80.0))
+            .critical_threshold(Threshold::new("regex", "^down$").unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(check.warning_comparison, Some(">".to_string()));
+        assert_eq!(check.warning_value, Some("80".to_string()));
+
+        let thresholds = check.thresholds().unwrap();
+        assert_eq!(
+            thresholds.critical,
+            Some(Threshold::new("regex", "^down$").unwrap())
+        );
+        assert_eq!(thresholds.evaluate("90"), ServiceCheckState::Warning);
+
+        let invalid = builder
+            .clone()
+            .warning_comparison("<")
+            .warning_value("ten")
+            .build();
+        assert!(matches!(
+            invalid,
+            Err(OpsviewConfigError::InvalidThreshold(_))
+        ));
+
+        let string_warning = builder
+            .warning_threshold(Threshold::StringEqual("down".to_string()))
+            .build();
+        assert!(string_warning.is_err());
+    }
+
+    #[test]
+    fn test_stalking() {
+        let builder = ServiceCheck::builder()
+            .name("ifOperStatus")
+            .args("")
+            .plugin(Plugin::minimal("check_snmp").unwrap())
+            .servicegroup(ServiceGroup::minimal("Network").unwrap());
+
+        let check = builder
+            .clone()
+            .stalking(
+                [StalkingOption::Warning, StalkingOption::Critical]
+                    .into_iter()
+                    .collect::<StalkingOptions>(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(check.stalking, Some("w,c".to_string()));
+        assert!(check
+            .stalking_set()
+            .unwrap()
+            .unwrap()
+            .contains(StalkingOption::Critical));
+
+        let check = builder
+            .clone()
+            .stalking(StalkingOptions::none())
+            .build()
+            .unwrap();
+        assert_eq!(check.stalking, Some(String::new()));
+        assert_eq!(check.stalking_set().unwrap(), Some(StalkingOptions::none()));
+
+        assert!(builder.stalking("w,x").build().is_err());
+    }
 }
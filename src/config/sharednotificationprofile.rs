@@ -292,6 +292,36 @@ impl Default for SharedNotificationProfile {
     }
 }
 
+impl SharedNotificationProfile {
+    /// Returns the host notification options of the `SharedNotificationProfile` as a typed
+    /// [`HostNotificationOptions`] set, or `None` if they are not set.
+    ///
+    /// # Returns
+    /// An error if the options are not a valid list of host notification options.
+    pub fn host_notification_options_set(
+        &self,
+    ) -> Result<Option<HostNotificationOptions>, OpsviewConfigError> {
+        self.host_notification_options
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+    }
+
+    /// Returns the service notification options of the `SharedNotificationProfile` as a typed
+    /// [`ServiceCheckNotificationOptions`] set, or `None` if they are not set.
+    ///
+    /// # Returns
+    /// An error if the options are not a valid list of service check notification options.
+    pub fn service_notification_options_set(
+        &self,
+    ) -> Result<Option<ServiceCheckNotificationOptions>, OpsviewConfigError> {
+        self.service_notification_options
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+    }
+}
+
 /// Enables the creation of a [`SharedNotificationProfile`] instance from a JSON representation.
 /// Typically used when parsing JSON data from the Opsview API.
 impl CreateFromJson for SharedNotificationProfile {}
@@ -633,7 +663,9 @@ impl SharedNotificationProfileBuilder {
     ///
     /// # Arguments
     /// * `business_component_options` - String indicating the options for [`BSMComponent`]s in the `SharedNotificationProfile`.
-    pub fn business_component_options(mut self, business_component_options: &str) -> Self {
+    ///
+    /// Accepts a string or a typed [`BSMComponentNotificationOptions`] set.
+    pub fn business_component_options(mut self, business_component_options: impl ToString) -> Self {
         self.business_component_options = Some(business_component_options.to_string());
         self
     }
@@ -700,7 +732,9 @@ impl SharedNotificationProfileBuilder {
     ///
     /// # Arguments
     /// * `business_service_options` - String indicating the options for [`BSMService`]s in the `SharedNotificationProfile`.
-    pub fn business_service_options(mut self, business_service_options: &str) -> Self {
+    ///
+    /// Accepts a string or a typed [`BSMServiceNotificationOptions`] set.
+    pub fn business_service_options(mut self, business_service_options: impl ToString) -> Self {
         self.business_service_options = Some(business_service_options.to_string());
         self
     }
@@ -911,7 +945,9 @@ impl SharedNotificationProfileBuilder {
     ///
     /// # Arguments
     /// * `host_notification_options` - String indicating the host notification options for the `SharedNotificationProfile`.
-    pub fn host_notification_options(mut self, host_notification_options: &str) -> Self {
+    ///
+    /// Accepts a string or a typed [`HostNotificationOptions`] set.
+    pub fn host_notification_options(mut self, host_notification_options: impl ToString) -> Self {
         self.host_notification_options = Some(host_notification_options.to_string());
         self
     }
@@ -1013,7 +1049,12 @@ impl SharedNotificationProfileBuilder {
     ///
     /// # Arguments
     /// * `service_notification_options` - String indicating the service notification options for the `SharedNotificationProfile`.
-    pub fn service_notification_options(mut self, service_notification_options: &str) -> Self {
+    ///
+    /// Accepts a string or a typed [`ServiceCheckNotificationOptions`] set.
+    pub fn service_notification_options(
+        mut self,
+        service_notification_options: impl ToString,
+    ) -> Self {
         self.service_notification_options = Some(service_notification_options.to_string());
         self
     }
@@ -114,14 +114,13 @@ mod tests {
 
     #[test]
     fn test_invalid_name_for_index_0() {
-        let mut i: SNMPInterface = Default::default();
-        i.interfacename = Some("eth0".to_string());
-        i.indexid = Some(0);
-        let i = i;
-
-        let mut interfaces = SNMPInterfaces::new();
-        interfaces.push(i);
-        let interfaces = interfaces;
+        let i = SNMPInterface {
+            interfacename: Some("eth0".to_string()),
+            indexid: Some(0),
+            ..Default::default()
+        };
+
+        let interfaces: SNMPInterfaces = vec![i];
 
         let result = interfaces.validate();
 
@@ -1,55 +1,294 @@
-#![allow(missing_docs)]
-use serde::{Deserialize, Deserializer, Serialize};
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
 
+/// The SNMP version used to poll a [`Host`](super::Host).
+///
+/// The API stores the version as `"1"`, `"2c"` or `"3"`. The SNMPv3 credentials are stored in
+/// separate fields of the host and are available as [`SNMPV3Settings`] through
+/// [`Host::snmpv3`](super::Host::snmpv3).
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
-#[serde(rename_all = "lowercase")]
 pub enum SNMPVersion {
+    /// SNMP version 1.
     #[serde(rename = "1")]
     V1,
+    /// SNMP version 2c.
     #[serde(rename = "2c")]
     V2c,
-    #[serde(rename = "3", deserialize_with = "deserialize_snmpv3")]
-    V3(SNMPV3SecurityLevel),
-}
-
-/// Custom deserialization for SNMPv3 security levels.
-/// Defaults to `NoAuthNoPriv`.
-fn deserialize_snmpv3<'de, D>(deserializer: D) -> Result<SNMPV3SecurityLevel, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let version_str: String = Deserialize::deserialize(deserializer)?;
-
-    match version_str.as_str() {
-        "3" => Ok(SNMPV3SecurityLevel::NoAuthNoPriv),
-        _ => Err(serde::de::Error::custom("Invalid SNMP version for V3")),
-    }
+    /// SNMP version 3.
+    #[serde(rename = "3")]
+    V3,
 }
 
+/// The authentication protocol of SNMPv3.
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum SNMPV3AuthProtocol {
+    /// HMAC-MD5-96.
+    #[serde(alias = "md5")]
     MD5,
+    /// HMAC-SHA-96.
+    #[serde(alias = "sha")]
     SHA,
+    /// HMAC-SHA-256.
+    #[serde(rename = "SHA256", alias = "sha256", alias = "SHA-256")]
+    SHA256,
+    /// HMAC-SHA-512.
+    #[serde(rename = "SHA512", alias = "sha512", alias = "SHA-512")]
+    SHA512,
+    /// No protocol is set.
     #[serde(rename = "")]
     Unspecified,
 }
 
+/// The privacy protocol of SNMPv3.
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum SNMPV3PrivProtocol {
+    /// CBC-DES.
+    #[serde(alias = "des")]
     DES,
+    /// CFB-AES-128.
+    #[serde(alias = "aes", alias = "AES128", alias = "aes128")]
     AES,
+    /// CFB-AES-192.
+    #[serde(rename = "AES192", alias = "aes192", alias = "AES-192")]
+    AES192,
+    /// CFB-AES-256.
+    #[serde(rename = "AES256", alias = "aes256", alias = "AES-256")]
+    AES256,
+    /// No protocol is set.
     #[serde(rename = "")]
     Unspecified,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+/// The security level of SNMPv3, with the protocols in use.
+///
+/// The API has no field for the security level, so it is only derived from the settings by
+/// [`SNMPV3Settings::security_level`] and is not serialized.
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SNMPV3SecurityLevel {
-    #[serde(rename = "noAuthNoPriv")]
+    /// Neither authentication nor privacy.
     NoAuthNoPriv,
-    #[serde(rename = "authNoPriv")]
+    /// Authentication without privacy.
     AuthNoPriv(SNMPV3AuthProtocol),
-    #[serde(rename = "authPriv")]
+    /// Authentication and privacy.
     AuthPriv(SNMPV3AuthProtocol, SNMPV3PrivProtocol),
 }
+
+/// The authentication settings of SNMPv3.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SNMPV3Auth {
+    /// The authentication protocol.
+    pub protocol: SNMPV3AuthProtocol,
+    /// The authentication password. The API does not always return it, so it may be `None` on an
+    /// existing host.
+    pub password: Option<String>,
+}
+
+/// The privacy settings of SNMPv3.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SNMPV3Privacy {
+    /// The privacy protocol.
+    pub protocol: SNMPV3PrivProtocol,
+    /// The privacy password. The API does not always return it, so it may be `None` on an existing
+    /// host.
+    pub password: Option<String>,
+}
+
+/// The SNMPv3 settings of a [`Host`](super::Host), gathered from its `snmpv3_*` fields.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::*;
+///
+/// let settings = SNMPV3Settings::new("monitor")
+///     .auth(SNMPV3AuthProtocol::SHA256, "auth-secret")
+///     .privacy(SNMPV3PrivProtocol::AES256, "priv-secret");
+///
+/// assert!(settings.validate().is_ok());
+/// assert_eq!(
+///     settings.security_level(),
+///     SNMPV3SecurityLevel::AuthPriv(SNMPV3AuthProtocol::SHA256, SNMPV3PrivProtocol::AES256)
+/// );
+///
+/// let no_auth = SNMPV3Settings::new("monitor").privacy(SNMPV3PrivProtocol::AES, "priv-secret");
+/// assert!(no_auth.validate().is_err());
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SNMPV3Settings {
+    /// The username.
+    pub username: Option<String>,
+    /// The authentication settings, if authentication is used.
+    pub auth: Option<SNMPV3Auth>,
+    /// The privacy settings, if privacy is used.
+    pub privacy: Option<SNMPV3Privacy>,
+}
+
+impl SNMPV3Settings {
+    /// Creates new `SNMPV3Settings` with a username and neither authentication nor privacy.
+    pub fn new(username: &str) -> Self {
+        SNMPV3Settings {
+            username: Some(username.to_string()),
+            auth: None,
+            privacy: None,
+        }
+    }
+
+    /// Sets the authentication protocol and password.
+    pub fn auth(mut self, protocol: SNMPV3AuthProtocol, password: &str) -> Self {
+        self.auth = Some(SNMPV3Auth {
+            protocol,
+            password: Some(password.to_string()),
+        });
+        self
+    }
+
+    /// Sets the privacy protocol and password.
+    pub fn privacy(mut self, protocol: SNMPV3PrivProtocol, password: &str) -> Self {
+        self.privacy = Some(SNMPV3Privacy {
+            protocol,
+            password: Some(password.to_string()),
+        });
+        self
+    }
+
+    /// Returns the security level of the settings.
+    pub fn security_level(&self) -> SNMPV3SecurityLevel {
+        match (&self.auth, &self.privacy) {
+            (Some(auth), Some(privacy)) => {
+                SNMPV3SecurityLevel::AuthPriv(auth.protocol.clone(), privacy.protocol.clone())
+            }
+            (Some(auth), None) => SNMPV3SecurityLevel::AuthNoPriv(auth.protocol.clone()),
+            (None, _) => SNMPV3SecurityLevel::NoAuthNoPriv,
+        }
+    }
+
+    /// Checks that the settings are consistent: a username is required, authentication requires a
+    /// password, and privacy requires both a password and authentication.
+    pub fn validate(&self) -> Result<(), OpsviewConfigError> {
+        let invalid = |reason: &str| Err(OpsviewConfigError::InvalidSNMPConfig(reason.to_string()));
+
+        if self.username.as_deref().unwrap_or_default().is_empty() {
+            return invalid("snmpv3_username must be set for SNMP version 3");
+        }
+
+        if self
+            .auth
+            .as_ref()
+            .is_some_and(|auth| auth.password.is_none())
+        {
+            return invalid("snmpv3_authpassword must be set when snmpv3_authprotocol is set");
+        }
+
+        if let Some(ref privacy) = self.privacy {
+            if self.auth.is_none() {
+                return invalid("snmpv3_authprotocol must be set when snmpv3_privprotocol is set");
+            }
+            if privacy.password.is_none() {
+                return invalid("snmpv3_privpassword must be set when snmpv3_privprotocol is set");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gathers the settings from the `snmpv3_*` fields of a host, treating unspecified protocols
+    /// and empty usernames as not set.
+    pub(crate) fn from_fields(
+        username: Option<&String>,
+        auth_protocol: Option<&SNMPV3AuthProtocol>,
+        auth_password: Option<&String>,
+        priv_protocol: Option<&SNMPV3PrivProtocol>,
+        priv_password: Option<&String>,
+    ) -> Self {
+        SNMPV3Settings {
+            username: username.filter(|u| !u.is_empty()).cloned(),
+            auth: auth_protocol
+                .filter(|p| **p != SNMPV3AuthProtocol::Unspecified)
+                .map(|protocol| SNMPV3Auth {
+                    protocol: protocol.clone(),
+                    password: auth_password.cloned(),
+                }),
+            privacy: priv_protocol
+                .filter(|p| **p != SNMPV3PrivProtocol::Unspecified)
+                .map(|protocol| SNMPV3Privacy {
+                    protocol: protocol.clone(),
+                    password: priv_password.cloned(),
+                }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snmp_version_serde() {
+        for (version, json) in [
+            (SNMPVersion::V1, "\"1\""),
+            (SNMPVersion::V2c, "\"2c\""),
+            (SNMPVersion::V3, "\"3\""),
+        ] {
+            assert_eq!(serde_json::to_string(&version).unwrap(), json);
+            assert_eq!(serde_json::from_str::<SNMPVersion>(json).unwrap(), version);
+        }
+    }
+
+    #[test]
+    fn test_protocol_serde() {
+        assert_eq!(
+            serde_json::from_str::<SNMPV3AuthProtocol>("\"sha256\"").unwrap(),
+            SNMPV3AuthProtocol::SHA256
+        );
+        assert_eq!(
+            serde_json::to_string(&SNMPV3AuthProtocol::SHA512).unwrap(),
+            "\"SHA512\""
+        );
+        assert_eq!(
+            serde_json::from_str::<SNMPV3PrivProtocol>("\"AES-192\"").unwrap(),
+            SNMPV3PrivProtocol::AES192
+        );
+        assert_eq!(
+            serde_json::from_str::<SNMPV3PrivProtocol>("\"\"").unwrap(),
+            SNMPV3PrivProtocol::Unspecified
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(SNMPV3Settings::new("user").validate().is_ok());
+        assert!(SNMPV3Settings::new("").validate().is_err());
+        assert!(SNMPV3Settings::default()
+            .auth(SNMPV3AuthProtocol::MD5, "password")
+            .validate()
+            .is_err());
+
+        let mut settings = SNMPV3Settings::new("user").auth(SNMPV3AuthProtocol::MD5, "password");
+        assert!(settings.validate().is_ok());
+        settings.auth.as_mut().unwrap().password = None;
+        assert!(settings.validate().is_err());
+
+        let mut settings = SNMPV3Settings::new("user")
+            .auth(SNMPV3AuthProtocol::SHA, "password")
+            .privacy(SNMPV3PrivProtocol::DES, "password");
+        assert!(settings.validate().is_ok());
+        settings.privacy.as_mut().unwrap().password = None;
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_from_fields() {
+        let settings = SNMPV3Settings::from_fields(
+            Some(&String::new()),
+            Some(&SNMPV3AuthProtocol::Unspecified),
+            None,
+            Some(&SNMPV3PrivProtocol::AES),
+            None,
+        );
+        assert_eq!(settings.username, None);
+        assert_eq!(settings.auth, None);
+        assert_eq!(settings.security_level(), SNMPV3SecurityLevel::NoAuthNoPriv);
+        assert!(settings.validate().is_err());
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{prelude::*, state::ServiceCheckState};
+use regex::Regex;
+use std::fmt;
+
+/// A threshold of an SNMP polling [`ServiceCheck`](super::ServiceCheck), made up of a comparison
+/// and a value of the type the comparison works on.
+///
+/// A polled value that matches the threshold raises the state of the service check, see
+/// [`ServiceCheckThresholds`].
+///
+/// # Example
+/// ```rust
+/// use opsview::config::Threshold;
+///
+/// let threshold = Threshold::new(">", "90").unwrap();
+/// assert_eq!(threshold, Threshold::GreaterThan(90.0));
+/// assert_eq!(threshold.matches("95.5"), Some(true));
+/// assert_eq!(threshold.matches("up"), None);
+///
+/// let threshold = Threshold::new("regex", "^(down|dormant)$").unwrap();
+/// assert_eq!(threshold.matches("down"), Some(true));
+/// assert_eq!(threshold.to_string(), "regex ^(down|dormant)$");
+///
+/// assert!(Threshold::new("<", "ten").is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub enum Threshold {
+    /// `==` - The polled value is numerically equal to the value.
+    Equal(f64),
+    /// `<` - The polled value is less than the value.
+    LessThan(f64),
+    /// `>` - The polled value is greater than the value.
+    GreaterThan(f64),
+    /// `eq` - The polled value is the string.
+    StringEqual(String),
+    /// `ne` - The polled value is not the string.
+    StringNotEqual(String),
+    /// `regex` - The polled value matches the regular expression.
+    Regex(Regex),
+}
+
+impl Threshold {
+    /// Creates a new `Threshold` from the comparison and value fields of a `ServiceCheck`.
+    ///
+    /// # Arguments
+    /// * `comparison` - One of `==`, `<`, `>`, `eq`, `ne` and `regex`.
+    /// * `value` - The value to compare with, which must be a number for `==`, `<` and `>`, and a
+    ///   valid regular expression for `regex`.
+    pub fn new(comparison: &str, value: &str) -> Result<Self, OpsviewConfigError> {
+        let invalid = |reason: &str| {
+            OpsviewConfigError::InvalidThreshold(format!("{} {}: {}", comparison, value, reason))
+        };
+        let number = || {
+            value
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|n| n.is_finite())
+                .ok_or_else(|| invalid("the value is not a number"))
+        };
+
+        match comparison.trim() {
+            "==" => Ok(Threshold::Equal(number()?)),
+            "<" => Ok(Threshold::LessThan(number()?)),
+            ">" => Ok(Threshold::GreaterThan(number()?)),
+            "eq" => Ok(Threshold::StringEqual(value.to_string())),
+            "ne" => Ok(Threshold::StringNotEqual(value.to_string())),
+            "regex" => Regex::new(value)
+                .map(Threshold::Regex)
+                .map_err(|_| invalid("the value is not a valid regular expression")),
+            _ => Err(invalid("unknown comparison")),
+        }
+    }
+
+    /// Returns the comparison as used in the `ServiceCheck` fields.
+    pub fn comparison(&self) -> &'static str {
+        match self {
+            Threshold::Equal(_) => "==",
+            Threshold::LessThan(_) => "<",
+            Threshold::GreaterThan(_) => ">",
+            Threshold::StringEqual(_) => "eq",
+            Threshold::StringNotEqual(_) => "ne",
+            Threshold::Regex(_) => "regex",
+        }
+    }
+
+    /// Returns the value as used in the `ServiceCheck` fields.
+    pub fn value(&self) -> String {
+        match self {
+            Threshold::Equal(n) | Threshold::LessThan(n) | Threshold::GreaterThan(n) => {
+                n.to_string()
+            }
+            Threshold::StringEqual(s) | Threshold::StringNotEqual(s) => s.clone(),
+            Threshold::Regex(re) => re.as_str().to_string(),
+        }
+    }
+
+    /// Returns `true` if the comparison is numeric.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            Threshold::Equal(_) | Threshold::LessThan(_) | Threshold::GreaterThan(_)
+        )
+    }
+
+    /// Returns whether a polled value matches the threshold, or `None` if a numeric threshold is
+    /// given a value that is not a number, including `NaN`.
+    pub fn matches(&self, polled: &str) -> Option<bool> {
+        let number = || polled.trim().parse::<f64>().ok().filter(|p| !p.is_nan());
+        match self {
+            Threshold::Equal(n) => number().map(|p| p == *n),
+            Threshold::LessThan(n) => number().map(|p| p < *n),
+            Threshold::GreaterThan(n) => number().map(|p| p > *n),
+            Threshold::StringEqual(s) => Some(polled == s),
+            Threshold::StringNotEqual(s) => Some(polled != s),
+            Threshold::Regex(re) => Some(re.is_match(polled)),
+        }
+    }
+}
+
+impl PartialEq for Threshold {
+    fn eq(&self, other: &Self) -> bool {
+        self.comparison() == other.comparison() && self.value() == other.value()
+    }
+}
+
+impl fmt::Display for Threshold {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.comparison(), self.value())
+    }
+}
+
+/// The warning and critical [`Threshold`]s of a [`ServiceCheck`](super::ServiceCheck).
+///
+/// # Example
+/// ```rust
+/// use opsview::config::{ServiceCheckThresholds, Threshold};
+/// use opsview::state::ServiceCheckState;
+///
+/// let thresholds = ServiceCheckThresholds {
+///     warning: Some(Threshold::GreaterThan(80.0)),
+///     critical: Some(Threshold::GreaterThan(95.0)),
+/// };
+///
+/// assert_eq!(thresholds.evaluate("50"), ServiceCheckState::Ok);
+/// assert_eq!(thresholds.evaluate("85"), ServiceCheckState::Warning);
+/// assert_eq!(thresholds.evaluate("99"), ServiceCheckState::Critical);
+/// assert_eq!(thresholds.evaluate("n/a"), ServiceCheckState::Unknown);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServiceCheckThresholds {
+    /// The threshold at which the service check is in a WARNING state.
+    pub warning: Option<Threshold>,
+    /// The threshold at which the service check is in a CRITICAL state.
+    pub critical: Option<Threshold>,
+}
+
+impl ServiceCheckThresholds {
+    /// Evaluates a polled value to the state of the service check.
+    ///
+    /// The critical threshold is checked first, then the warning threshold. A value that cannot be
+    /// compared with a threshold, such as text or `NaN` compared with a number, gives `Unknown`. A
+    /// value that matches neither threshold gives `Ok`.
+    pub fn evaluate(&self, polled: &str) -> ServiceCheckState {
+        for (threshold, state) in [
+            (&self.critical, ServiceCheckState::Critical),
+            (&self.warning, ServiceCheckState::Warning),
+        ] {
+            if let Some(threshold) = threshold {
+                match threshold.matches(polled) {
+                    Some(true) => return state,
+                    Some(false) => {}
+                    None => return ServiceCheckState::Unknown,
+                }
+            }
+        }
+        ServiceCheckState::Ok
+    }
+}
+
+/// Parses a comparison and value field pair into a `Threshold`, if both are set and not empty.
+pub(crate) fn threshold_from_fields(
+    comparison: Option<&str>,
+    value: Option<&str>,
+) -> Result<Option<Threshold>, OpsviewConfigError> {
+    match (comparison, value) {
+        (Some(comparison), Some(value)) if !comparison.is_empty() => {
+            Threshold::new(comparison, value).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ServiceCheck;
+    use crate::perfdata::evaluate_service_check;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Threshold::new("==", " 1 ").unwrap(), Threshold::Equal(1.0));
+        assert_eq!(
+            Threshold::new("ne", "up").unwrap(),
+            Threshold::StringNotEqual("up".to_string())
+        );
+        assert!(Threshold::new("regex", "(").is_err());
+        assert!(Threshold::new(">=", "1").is_err());
+        assert!(Threshold::new(">", "NaN").is_err());
+        assert_eq!(Threshold::new("<", "2.5").unwrap().value(), "2.5");
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let thresholds = ServiceCheckThresholds {
+            warning: Some(Threshold::StringNotEqual("up".to_string())),
+            critical: Some(Threshold::new("regex", "^down").unwrap()),
+        };
+        assert_eq!(thresholds.evaluate("up"), ServiceCheckState::Ok);
+        assert_eq!(thresholds.evaluate("dormant"), ServiceCheckState::Warning);
+        assert_eq!(thresholds.evaluate("down"), ServiceCheckState::Critical);
+
+        assert_eq!(
+            ServiceCheckThresholds::default().evaluate("anything"),
+            ServiceCheckState::Ok
+        );
+    }
+
+    #[test]
+    fn test_evaluate_non_numeric_and_nan() {
+        let mut check = ServiceCheck::minimal("Interface Utilisation").unwrap();
+        check.critical_comparison = Some(">".to_string());
+        check.critical_value = Some("NaN".to_string());
+        assert!(check.thresholds().is_err());
+        assert!(evaluate_service_check(&check, "1").is_err());
+
+        check.critical_value = Some("90".to_string());
+        for polled in ["n/a", "NaN", ""] {
+            assert_eq!(
+                check.thresholds().unwrap().evaluate(polled),
+                ServiceCheckState::Unknown,
+                "{}",
+                polled
+            );
+            assert_eq!(
+                evaluate_service_check(&check, polled),
+                Ok(ServiceCheckState::Unknown),
+                "{}",
+                polled
+            );
+        }
+    }
+}
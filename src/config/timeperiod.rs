@@ -1,5 +1,7 @@
-use super::{HostRef, ServiceCheckRef, TimeZone};
+use super::{parse_day_ranges, HostRef, ServiceCheckRef, TimePeriodRanges, TimeZone};
 use crate::{prelude::*, util::*};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -196,6 +198,164 @@ impl Default for TimePeriod {
     }
 }
 
+impl TimePeriod {
+    /// Returns the ranges of each day of the week, sorted and merged.
+    ///
+    /// # Returns
+    /// An error if a day contains an invalid range.
+    pub fn ranges(&self) -> Result<TimePeriodRanges, OpsviewConfigError> {
+        TimePeriodRanges::from_time_period(self)
+    }
+
+    /// Returns the time zone in which the `TimePeriod` is defined.
+    ///
+    /// The `SYSTEM` zone, which is the zone of the Opsview server, and a missing zone are both
+    /// treated as UTC.
+    ///
+    /// # Returns
+    /// An error if the zone is not a known IANA time zone name.
+    pub fn time_zone(&self) -> Result<Tz, OpsviewConfigError> {
+        match self.zone.as_ref().map(|zone| zone.name.as_str()) {
+            None | Some("") | Some("SYSTEM") => Ok(Tz::UTC),
+            Some(name) => name
+                .parse()
+                .map_err(|_| OpsviewConfigError::InvalidTimeZone(name.to_string())),
+        }
+    }
+
+    /// Returns `true` if the `TimePeriod` is active at a time, evaluated in its time zone.
+    ///
+    /// # Example
+    /// ```rust
+    /// use chrono::{TimeZone as _, Utc};
+    /// use opsview::config::{TimePeriod, TimeZone};
+    /// use opsview::prelude::*;
+    ///
+    /// let time_period = TimePeriod::builder()
+    ///     .name("workhours")
+    ///     .monday("09:00-17:00")
+    ///     .zone(TimeZone::minimal("America/New_York").unwrap())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // 15:00 UTC is 10:00 in New York on Monday 2024-03-04.
+    /// let at = Utc.with_ymd_and_hms(2024, 3, 4, 15, 0, 0).unwrap();
+    /// assert!(time_period.is_active_at(at).unwrap());
+    ///
+    /// let next = time_period.next_transition(at).unwrap().unwrap();
+    /// assert_eq!(next, Utc.with_ymd_and_hms(2024, 3, 4, 22, 0, 0).unwrap());
+    /// ```
+    pub fn is_active_at(&self, at: DateTime<Utc>) -> Result<bool, OpsviewConfigError> {
+        Ok(self.ranges()?.is_active_at(at, self.time_zone()?))
+    }
+
+    /// Returns the first time after `at` at which the `TimePeriod` becomes active or inactive,
+    /// evaluated in its time zone, or `None` if it is always or never active.
+    pub fn next_transition(
+        &self,
+        at: DateTime<Utc>,
+    ) -> Result<Option<DateTime<Utc>>, OpsviewConfigError> {
+        Ok(self.ranges()?.next_transition(at, self.time_zone()?))
+    }
+
+    /// Returns the percentage of the week covered by the `TimePeriod`.
+    pub fn coverage_pct(&self) -> Result<f64, OpsviewConfigError> {
+        Ok(self.ranges()?.coverage_pct())
+    }
+
+    /// Creates a new `TimePeriod` from a template such as `"weekdays 08:00-18:00"`.
+    ///
+    /// See [`TimePeriodRanges::from_template`] for the template format.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the new `TimePeriod`.
+    /// * `template` - The template describing the days and ranges.
+    pub fn from_template(name: &str, template: &str) -> Result<Self, OpsviewConfigError> {
+        TimePeriodRanges::from_template(template)?.to_time_period(name, None)
+    }
+
+    /// Returns a new `TimePeriod` covering the times covered by either `TimePeriod`.
+    ///
+    /// # Arguments
+    /// * `other` - The other `TimePeriod`, which must be in the same time zone.
+    /// * `name` - The name of the new `TimePeriod`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use opsview::config::TimePeriod;
+    ///
+    /// let work = TimePeriod::from_template("workhours", "weekdays 08:00-18:00").unwrap();
+    /// let lunch = TimePeriod::from_template("lunch", "weekdays 12:00-13:00").unwrap();
+    ///
+    /// let working = work.difference(&lunch, "working").unwrap();
+    /// assert_eq!(working.monday, Some("08:00-12:00,13:00-18:00".to_string()));
+    ///
+    /// let off = work.complement("nonworkhours").unwrap();
+    /// assert_eq!(off.monday, Some("00:00-08:00,18:00-24:00".to_string()));
+    /// assert_eq!(off.sunday, Some("00:00-24:00".to_string()));
+    ///
+    /// let all = work.union(&off, "24x7").unwrap();
+    /// assert_eq!(all.coverage_pct().unwrap(), 100.0);
+    /// ```
+    pub fn union(&self, other: &TimePeriod, name: &str) -> Result<TimePeriod, OpsviewConfigError> {
+        self.combine(other, name, TimePeriodRanges::union)
+    }
+
+    /// Returns a new `TimePeriod` covering the times covered by both `TimePeriod`s.
+    ///
+    /// # Arguments
+    /// * `other` - The other `TimePeriod`, which must be in the same time zone.
+    /// * `name` - The name of the new `TimePeriod`.
+    pub fn intersection(
+        &self,
+        other: &TimePeriod,
+        name: &str,
+    ) -> Result<TimePeriod, OpsviewConfigError> {
+        self.combine(other, name, TimePeriodRanges::intersection)
+    }
+
+    /// Returns a new `TimePeriod` covering the times covered by this `TimePeriod` but not by the
+    /// other.
+    ///
+    /// # Arguments
+    /// * `other` - The other `TimePeriod`, which must be in the same time zone.
+    /// * `name` - The name of the new `TimePeriod`.
+    pub fn difference(
+        &self,
+        other: &TimePeriod,
+        name: &str,
+    ) -> Result<TimePeriod, OpsviewConfigError> {
+        self.combine(other, name, TimePeriodRanges::difference)
+    }
+
+    /// Returns a new `TimePeriod` covering the times of the week not covered by this one.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the new `TimePeriod`.
+    pub fn complement(&self, name: &str) -> Result<TimePeriod, OpsviewConfigError> {
+        self.ranges()?
+            .complement()
+            .to_time_period(name, self.zone.clone())
+    }
+
+    fn combine(
+        &self,
+        other: &TimePeriod,
+        name: &str,
+        f: impl Fn(&TimePeriodRanges, &TimePeriodRanges) -> TimePeriodRanges,
+    ) -> Result<TimePeriod, OpsviewConfigError> {
+        let (zone, other_zone) = (self.time_zone()?, other.time_zone()?);
+        if zone != other_zone {
+            return Err(OpsviewConfigError::ConflictingTimeZones(
+                zone.to_string(),
+                other_zone.to_string(),
+            ));
+        }
+
+        f(&self.ranges()?, &other.ranges()?).to_time_period(name, self.zone.clone())
+    }
+}
+
 /// Enables the creation of a [`TimePeriod`] instance from a JSON representation.
 /// Typically used when parsing JSON data from the Opsview API.
 impl CreateFromJson for TimePeriod {}
@@ -286,6 +446,13 @@ impl Persistent for TimePeriod {
     }
 }
 
+/// Validates the ranges of a weekday, which must be well-formed and must not cross midnight.
+fn validate_weekday_ranges(s: &str) -> Result<String, OpsviewConfigError> {
+    let validated = validate_and_trim_timeperiod_weekday(s)?;
+    parse_day_ranges(&validated)?;
+    Ok(validated)
+}
+
 impl PersistentMap for ConfigObjectMap<TimePeriod> {
     fn config_path() -> Option<String> {
         Some("/config/timeperiod".to_string())
@@ -339,20 +506,13 @@ impl Builder for TimePeriodBuilder {
     fn build(self) -> Result<Self::ConfigObject, OpsviewConfigError> {
         let name = require_field(&self.name, "name")?;
         let validated_alias = validate_opt_string(self.alias, validate_and_trim_timeperiod_alias)?;
-        let validated_monday =
-            validate_opt_string(self.monday, validate_and_trim_timeperiod_weekday)?;
-        let validated_tuesday =
-            validate_opt_string(self.tuesday, validate_and_trim_timeperiod_weekday)?;
-        let validated_wednesday =
-            validate_opt_string(self.wednesday, validate_and_trim_timeperiod_weekday)?;
-        let validated_thursday =
-            validate_opt_string(self.thursday, validate_and_trim_timeperiod_weekday)?;
-        let validated_friday =
-            validate_opt_string(self.friday, validate_and_trim_timeperiod_weekday)?;
-        let validated_saturday =
-            validate_opt_string(self.saturday, validate_and_trim_timeperiod_weekday)?;
-        let validated_sunday =
-            validate_opt_string(self.sunday, validate_and_trim_timeperiod_weekday)?;
+        let validated_monday = validate_opt_string(self.monday, validate_weekday_ranges)?;
+        let validated_tuesday = validate_opt_string(self.tuesday, validate_weekday_ranges)?;
+        let validated_wednesday = validate_opt_string(self.wednesday, validate_weekday_ranges)?;
+        let validated_thursday = validate_opt_string(self.thursday, validate_weekday_ranges)?;
+        let validated_friday = validate_opt_string(self.friday, validate_weekday_ranges)?;
+        let validated_saturday = validate_opt_string(self.saturday, validate_weekday_ranges)?;
+        let validated_sunday = validate_opt_string(self.sunday, validate_weekday_ranges)?;
 
         Ok(TimePeriod {
             name: validate_and_trim_timeperiod_name(&name)?,
@@ -689,7 +849,14 @@ mod tests {
 
     #[test]
     fn test_invalid_timeperiods() {
-        let invalid_strings = ["foo", "00:00-22:00 23:00-24:00", "10:00-11:00Z", ""];
+        let invalid_strings = [
+            "foo",
+            "00:00-22:00 23:00-24:00",
+            "10:00-11:00Z",
+            "",
+            "22:00-02:00",
+            "09:00-17:00,25:00-26:00",
+        ];
 
         for s in invalid_strings {
             println!("Testing timeperiod string '{}'", s);
@@ -707,4 +874,29 @@ mod tests {
             assert!(tp.is_err());
         }
     }
+
+    #[test]
+    fn test_timeperiod_algebra_time_zones() {
+        let work = TimePeriod::from_template("workhours", "weekdays 09:00-17:00").unwrap();
+        let mut night = TimePeriod::from_template("night", "daily 00:00-06:00").unwrap();
+
+        let both = work.union(&night, "both").unwrap();
+        assert_eq!(both.monday, Some("00:00-06:00,09:00-17:00".to_string()));
+        assert_eq!(both.saturday, Some("00:00-06:00".to_string()));
+        assert!(work
+            .intersection(&night, "none")
+            .unwrap()
+            .ranges()
+            .unwrap()
+            .is_empty());
+
+        night.zone = Some(TimeZone::minimal("Europe/Stockholm").unwrap());
+        assert_eq!(
+            work.union(&night, "both"),
+            Err(OpsviewConfigError::ConflictingTimeZones(
+                "UTC".to_string(),
+                "Europe/Stockholm".to_string()
+            ))
+        );
+    }
 }
@@ -0,0 +1,674 @@
+use super::TimePeriod;
+use crate::prelude::*;
+use chrono::{DateTime, Datelike, Duration, TimeZone as _, Timelike, Utc, Weekday};
+use chrono_tz::Tz;
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Not, Sub};
+use std::str::FromStr;
+
+/// The number of minutes in a day.
+pub const MINUTES_PER_DAY: u16 = 24 * 60;
+
+/// The days of the week in the order they are stored in [`TimePeriodRanges`].
+pub const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// A range of time within a single day, such as `09:00-17:00`.
+///
+/// The start is inclusive and the end exclusive, both counted in minutes since midnight. The end
+/// may be `24:00`, but a range cannot cross midnight: `22:00-02:00` must be written as
+/// `22:00-24:00` on one day and `00:00-02:00` on the next.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::TimeRange;
+///
+/// let range: TimeRange = "09:00-17:30".parse().unwrap();
+/// assert_eq!(range.start(), 9 * 60);
+/// assert_eq!(range.minutes(), 8 * 60 + 30);
+/// assert_eq!(range.to_string(), "09:00-17:30");
+///
+/// assert!("22:00-02:00".parse::<TimeRange>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct TimeRange {
+    start: u16,
+    end: u16,
+}
+
+impl TimeRange {
+    /// Creates a new `TimeRange` from minutes since midnight.
+    ///
+    /// # Arguments
+    /// * `start` - The inclusive start of the range.
+    /// * `end` - The exclusive end of the range, at most 1440 (`24:00`).
+    ///
+    /// # Returns
+    /// An error if the range is empty, ends after midnight or crosses midnight.
+    pub fn new(start: u16, end: u16) -> Result<Self, OpsviewConfigError> {
+        if end > MINUTES_PER_DAY {
+            return Err(OpsviewConfigError::InvalidTimeRange(format!(
+                "{} ends after 24:00",
+                format_range(start, end)
+            )));
+        }
+        if end <= start {
+            return Err(OpsviewConfigError::InvalidTimeRange(format!(
+                "{} does not end after it starts, and ranges cannot cross midnight",
+                format_range(start, end)
+            )));
+        }
+
+        Ok(TimeRange { start, end })
+    }
+
+    /// Returns a range covering the whole day, `00:00-24:00`.
+    pub fn all_day() -> Self {
+        TimeRange {
+            start: 0,
+            end: MINUTES_PER_DAY,
+        }
+    }
+
+    /// Returns the inclusive start of the range in minutes since midnight.
+    pub fn start(&self) -> u16 {
+        self.start
+    }
+
+    /// Returns the exclusive end of the range in minutes since midnight.
+    pub fn end(&self) -> u16 {
+        self.end
+    }
+
+    /// Returns the length of the range in minutes.
+    pub fn minutes(&self) -> u16 {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the minute since midnight is within the range.
+    pub fn contains(&self, minute: u16) -> bool {
+        self.start <= minute && minute < self.end
+    }
+
+    /// Returns `true` if the ranges overlap or touch, so that they can be merged into one.
+    pub fn touches(&self, other: &TimeRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+impl fmt::Display for TimeRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format_range(self.start, self.end))
+    }
+}
+
+impl FromStr for TimeRange {
+    type Err = OpsviewConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || OpsviewConfigError::InvalidTimeRange(s.to_string());
+        let (start, end) = s.trim().split_once('-').ok_or_else(invalid)?;
+        let start = parse_time(start).ok_or_else(invalid)?;
+        let end = parse_time(end).ok_or_else(invalid)?;
+
+        TimeRange::new(start, end)
+    }
+}
+
+/// Sorts ranges and merges those that overlap or touch.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::{normalize_ranges, TimeRange};
+///
+/// let ranges: Vec<TimeRange> = ["12:00-13:00", "09:00-12:00", "11:00-11:30"]
+///     .iter()
+///     .map(|r| r.parse().unwrap())
+///     .collect();
+///
+/// assert_eq!(normalize_ranges(ranges), vec!["09:00-13:00".parse().unwrap()]);
+/// ```
+pub fn normalize_ranges(ranges: impl IntoIterator<Item = TimeRange>) -> Vec<TimeRange> {
+    let mut ranges: Vec<TimeRange> = ranges.into_iter().collect();
+    ranges.sort();
+
+    let mut merged: Vec<TimeRange> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if last.touches(&range) => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Parses the ranges of a day in a [`TimePeriod`], such as `"00:00-09:00,17:00-24:00"`, into
+/// sorted and merged ranges.
+pub fn parse_day_ranges(s: &str) -> Result<Vec<TimeRange>, OpsviewConfigError> {
+    let ranges = s
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<Vec<TimeRange>, _>>()?;
+    Ok(normalize_ranges(ranges))
+}
+
+/// The ranges of each day of the week of a [`TimePeriod`], sorted and merged.
+///
+/// # Example
+/// ```rust
+/// use chrono::Weekday;
+/// use opsview::config::{TimePeriod, TimePeriodRanges};
+/// use opsview::prelude::*;
+///
+/// let time_period = TimePeriod::builder()
+///     .name("workhours")
+///     .monday("13:00-17:00,09:00-13:00")
+///     .build()
+///     .unwrap();
+///
+/// let ranges = TimePeriodRanges::from_time_period(&time_period).unwrap();
+/// assert_eq!(ranges.day_string(Weekday::Mon), Some("09:00-17:00".to_string()));
+/// assert_eq!(ranges.day_string(Weekday::Tue), None);
+/// assert!(ranges.is_active(Weekday::Mon, 12 * 60));
+/// assert_eq!(ranges.active_minutes(), 8 * 60);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct TimePeriodRanges {
+    days: [Vec<TimeRange>; 7],
+}
+
+impl TimePeriodRanges {
+    /// Creates a new `TimePeriodRanges` without any ranges.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the weekday fields of a `TimePeriod`. Days that are not set have no ranges.
+    pub fn from_time_period(time_period: &TimePeriod) -> Result<Self, OpsviewConfigError> {
+        let mut ranges = Self::new();
+        for day in WEEKDAYS {
+            if let Some(s) = day_field(time_period, day) {
+                ranges.days[day.num_days_from_monday() as usize] = parse_day_ranges(s)?;
+            }
+        }
+        Ok(ranges)
+    }
+
+    /// Returns the ranges of a day.
+    pub fn day(&self, day: Weekday) -> &[TimeRange] {
+        &self.days[day.num_days_from_monday() as usize]
+    }
+
+    /// Replaces the ranges of a day, sorting and merging them.
+    pub fn set_day(&mut self, day: Weekday, ranges: impl IntoIterator<Item = TimeRange>) {
+        self.days[day.num_days_from_monday() as usize] = normalize_ranges(ranges);
+    }
+
+    /// Returns the ranges of a day in the format of the `TimePeriod` fields, or `None` if the day
+    /// has no ranges.
+    pub fn day_string(&self, day: Weekday) -> Option<String> {
+        let ranges = self.day(day);
+        if ranges.is_empty() {
+            return None;
+        }
+        let ranges: Vec<String> = ranges.iter().map(ToString::to_string).collect();
+        Some(ranges.join(","))
+    }
+
+    /// Returns `true` if no day has any ranges.
+    pub fn is_empty(&self) -> bool {
+        self.days.iter().all(Vec::is_empty)
+    }
+
+    /// Returns `true` if a minute since midnight on a day is within one of its ranges.
+    pub fn is_active(&self, day: Weekday, minute: u16) -> bool {
+        self.day(day).iter().any(|range| range.contains(minute))
+    }
+
+    /// Returns `true` if the ranges are active at a time, evaluated in a time zone.
+    pub fn is_active_at(&self, at: DateTime<Utc>, tz: Tz) -> bool {
+        let local = at.with_timezone(&tz);
+        let minute = (local.hour() * 60 + local.minute()) as u16;
+        self.is_active(local.weekday(), minute)
+    }
+
+    /// Returns the first time after `at` at which the ranges become active or inactive, evaluated
+    /// in a time zone, or `None` if they are always or never active.
+    pub fn next_transition(&self, at: DateTime<Utc>, tz: Tz) -> Option<DateTime<Utc>> {
+        let active = self.is_active_at(at, tz);
+        let today = at.with_timezone(&tz).date_naive();
+
+        for offset in 0..=7 {
+            let date = today + Duration::days(offset);
+            let midnight = date.and_hms_opt(0, 0, 0)?;
+
+            let mut boundaries: Vec<u16> = self
+                .day(date.weekday())
+                .iter()
+                .flat_map(|range| [range.start, range.end])
+                .chain([0])
+                .collect();
+            boundaries.sort_unstable();
+            boundaries.dedup();
+
+            for boundary in boundaries {
+                let local = midnight + Duration::minutes(boundary as i64);
+                // Local times skipped by a daylight saving change do not exist.
+                let Some(local) = tz.from_local_datetime(&local).earliest() else {
+                    continue;
+                };
+                let utc = local.with_timezone(&Utc);
+                if utc > at && self.is_active_at(utc, tz) != active {
+                    return Some(utc);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the number of minutes in the week covered by the ranges.
+    pub fn active_minutes(&self) -> u32 {
+        self.days
+            .iter()
+            .flatten()
+            .map(|range| range.minutes() as u32)
+            .sum()
+    }
+
+    /// Returns the percentage of the week covered by the ranges.
+    pub fn coverage_pct(&self) -> f64 {
+        self.active_minutes() as f64 * 100.0 / (7.0 * MINUTES_PER_DAY as f64)
+    }
+
+    /// Returns the times covered by either set of ranges.
+    pub fn union(&self, other: &TimePeriodRanges) -> TimePeriodRanges {
+        self.combine(other, |a, b| {
+            normalize_ranges(a.iter().chain(b.iter()).copied())
+        })
+    }
+
+    /// Returns the times covered by both sets of ranges.
+    pub fn intersection(&self, other: &TimePeriodRanges) -> TimePeriodRanges {
+        self.combine(other, intersect_day)
+    }
+
+    /// Returns the times covered by these ranges but not by the other.
+    pub fn difference(&self, other: &TimePeriodRanges) -> TimePeriodRanges {
+        self.combine(other, |a, b| intersect_day(a, &complement_day(b)))
+    }
+
+    /// Returns the times of the week not covered by the ranges.
+    pub fn complement(&self) -> TimePeriodRanges {
+        TimePeriodRanges {
+            days: std::array::from_fn(|i| complement_day(&self.days[i])),
+        }
+    }
+
+    /// Parses a template describing one or more days and their ranges into `TimePeriodRanges`.
+    ///
+    /// A template is a list of clauses separated by `;`. Each clause is a set of days followed by
+    /// optional comma separated ranges, and covers the whole day when no ranges are given. The
+    /// set of days is one of:
+    /// * `daily` or `everyday` - every day of the week.
+    /// * `weekdays` - Monday to Friday.
+    /// * `weekends` - Saturday and Sunday.
+    /// * A day, such as `monday` or `mon`.
+    /// * A span of days, such as `mon-thu`, which may wrap around the end of the week.
+    /// * A comma separated list of the above, such as `mon,wed,fri`.
+    ///
+    /// The template `24x7` covers the whole week.
+    ///
+    /// # Example
+    /// ```rust
+    /// use chrono::Weekday;
+    /// use opsview::config::TimePeriodRanges;
+    ///
+    /// let ranges =
+    ///     TimePeriodRanges::from_template("weekdays 08:00-18:00; sat 10:00-14:00").unwrap();
+    /// assert_eq!(ranges.day_string(Weekday::Fri), Some("08:00-18:00".to_string()));
+    /// assert_eq!(ranges.day_string(Weekday::Sat), Some("10:00-14:00".to_string()));
+    /// assert_eq!(ranges.day_string(Weekday::Sun), None);
+    ///
+    /// let lunch = TimePeriodRanges::from_template("weekdays 12:00-13:00").unwrap();
+    /// let without_lunch = ranges.difference(&lunch);
+    /// assert_eq!(
+    ///     without_lunch.day_string(Weekday::Mon),
+    ///     Some("08:00-12:00,13:00-18:00".to_string())
+    /// );
+    /// ```
+    pub fn from_template(template: &str) -> Result<Self, OpsviewConfigError> {
+        let invalid = || OpsviewConfigError::InvalidTimePeriodTemplate(template.to_string());
+        let mut ranges = TimePeriodRanges::new();
+
+        if template.trim().eq_ignore_ascii_case("24x7") {
+            return Ok(ranges.complement());
+        }
+
+        for clause in template.split(';').map(str::trim) {
+            let (days, times) = match clause.split_once(char::is_whitespace) {
+                Some((days, times)) => (days, times.trim()),
+                None => (clause, ""),
+            };
+            let days = parse_days(days).ok_or_else(invalid)?;
+            let times = if times.is_empty() {
+                vec![TimeRange::all_day()]
+            } else {
+                times
+                    .split(',')
+                    .map(str::parse)
+                    .collect::<Result<Vec<TimeRange>, _>>()?
+            };
+
+            for day in days {
+                let existing = ranges.day(day).to_vec();
+                ranges.set_day(day, existing.into_iter().chain(times.iter().copied()));
+            }
+        }
+
+        Ok(ranges)
+    }
+
+    /// Creates a new `TimePeriod` with these ranges.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the new `TimePeriod`.
+    /// * `zone` - The optional [`TimeZone`](super::TimeZone) in which the ranges are defined.
+    pub fn to_time_period(
+        &self,
+        name: &str,
+        zone: Option<super::TimeZone>,
+    ) -> Result<TimePeriod, OpsviewConfigError> {
+        let mut builder = TimePeriod::builder().name(name);
+        for day in WEEKDAYS {
+            if let Some(ranges) = self.day_string(day) {
+                builder = match day {
+                    Weekday::Mon => builder.monday(&ranges),
+                    Weekday::Tue => builder.tuesday(&ranges),
+                    Weekday::Wed => builder.wednesday(&ranges),
+                    Weekday::Thu => builder.thursday(&ranges),
+                    Weekday::Fri => builder.friday(&ranges),
+                    Weekday::Sat => builder.saturday(&ranges),
+                    Weekday::Sun => builder.sunday(&ranges),
+                };
+            }
+        }
+        if let Some(zone) = zone {
+            builder = builder.zone(zone);
+        }
+        builder.build()
+    }
+
+    fn combine(
+        &self,
+        other: &TimePeriodRanges,
+        f: impl Fn(&[TimeRange], &[TimeRange]) -> Vec<TimeRange>,
+    ) -> TimePeriodRanges {
+        TimePeriodRanges {
+            days: std::array::from_fn(|i| f(&self.days[i], &other.days[i])),
+        }
+    }
+}
+
+impl BitOr for TimePeriodRanges {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(&rhs)
+    }
+}
+
+impl BitAnd for TimePeriodRanges {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(&rhs)
+    }
+}
+
+impl Sub for TimePeriodRanges {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(&rhs)
+    }
+}
+
+impl Not for TimePeriodRanges {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+impl fmt::Display for TimePeriodRanges {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let days: Vec<String> = WEEKDAYS
+            .iter()
+            .filter_map(|day| {
+                self.day_string(*day)
+                    .map(|ranges| format!("{}: {}", day, ranges))
+            })
+            .collect();
+        write!(f, "{}", days.join("; "))
+    }
+}
+
+/// Returns the field of a `TimePeriod` for a day.
+pub(crate) fn day_field(time_period: &TimePeriod, day: Weekday) -> Option<&str> {
+    match day {
+        Weekday::Mon => time_period.monday.as_deref(),
+        Weekday::Tue => time_period.tuesday.as_deref(),
+        Weekday::Wed => time_period.wednesday.as_deref(),
+        Weekday::Thu => time_period.thursday.as_deref(),
+        Weekday::Fri => time_period.friday.as_deref(),
+        Weekday::Sat => time_period.saturday.as_deref(),
+        Weekday::Sun => time_period.sunday.as_deref(),
+    }
+}
+
+/// Returns the times covered by both sorted and merged lists of ranges.
+fn intersect_day(a: &[TimeRange], b: &[TimeRange]) -> Vec<TimeRange> {
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let start = a[i].start.max(b[j].start);
+        let end = a[i].end.min(b[j].end);
+        if start < end {
+            result.push(TimeRange { start, end });
+        }
+        if a[i].end < b[j].end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
+
+/// Returns the times of the day not covered by a sorted and merged list of ranges.
+fn complement_day(ranges: &[TimeRange]) -> Vec<TimeRange> {
+    let mut result = Vec::new();
+    let mut start = 0;
+    for range in ranges {
+        if range.start > start {
+            result.push(TimeRange {
+                start,
+                end: range.start,
+            });
+        }
+        start = range.end;
+    }
+    if start < MINUTES_PER_DAY {
+        result.push(TimeRange {
+            start,
+            end: MINUTES_PER_DAY,
+        });
+    }
+    result
+}
+
+/// Parses a set of days in a template, such as `weekdays`, `mon-fri` or `sat,sun`.
+fn parse_days(days: &str) -> Option<Vec<Weekday>> {
+    let mut result = Vec::new();
+    for part in days.split(',').map(|d| d.trim().to_lowercase()) {
+        match part.as_str() {
+            "daily" | "everyday" => result.extend(WEEKDAYS),
+            "weekdays" => result.extend(&WEEKDAYS[..5]),
+            "weekends" => result.extend(&WEEKDAYS[5..]),
+            _ => match part.split_once('-') {
+                Some((first, last)) => {
+                    let mut day: Weekday = first.parse().ok()?;
+                    let last: Weekday = last.parse().ok()?;
+                    result.push(day);
+                    while day != last {
+                        day = day.succ();
+                        result.push(day);
+                    }
+                }
+                None => result.push(part.parse().ok()?),
+            },
+        }
+    }
+    Some(result)
+}
+
+/// Parses a time such as `09:30` into minutes since midnight, allowing `24:00`.
+fn parse_time(time: &str) -> Option<u16> {
+    let (hours, minutes) = time.trim().split_once(':')?;
+    if hours.len() != 2 || minutes.len() != 2 {
+        return None;
+    }
+    let (hours, minutes): (u16, u16) = (hours.parse().ok()?, minutes.parse().ok()?);
+    (hours < 24 && minutes < 60 || hours == 24 && minutes == 0).then_some(hours * 60 + minutes)
+}
+
+fn format_range(start: u16, end: u16) -> String {
+    format!(
+        "{:02}:{:02}-{:02}:{:02}",
+        start / 60,
+        start % 60,
+        end / 60,
+        end % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(s: &str) -> TimeRange {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_time_range() {
+        assert_eq!(range("00:00-24:00"), TimeRange::all_day());
+        assert_eq!(range(" 09:15-10:00 ").start(), 555);
+        assert!("22:00-02:00".parse::<TimeRange>().is_err());
+        assert!("10:00-10:00".parse::<TimeRange>().is_err());
+        assert!("24:30-25:00".parse::<TimeRange>().is_err());
+        assert!("9:00-10:00".parse::<TimeRange>().is_err());
+        assert!("09:00".parse::<TimeRange>().is_err());
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            parse_day_ranges("17:00-24:00,00:00-09:00,08:00-10:00,10:00-11:00").unwrap(),
+            vec![range("00:00-11:00"), range("17:00-24:00")]
+        );
+    }
+
+    #[test]
+    fn test_next_transition() {
+        let mut ranges = TimePeriodRanges::new();
+        ranges.set_day(Weekday::Mon, [range("22:00-24:00")]);
+        ranges.set_day(Weekday::Tue, [range("00:00-02:00")]);
+
+        // Monday 2024-03-04.
+        let at = Utc.with_ymd_and_hms(2024, 3, 4, 12, 0, 0).unwrap();
+        let start = ranges.next_transition(at, Tz::UTC).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2024, 3, 4, 22, 0, 0).unwrap());
+        assert!(ranges.is_active_at(start, Tz::UTC));
+
+        // The range continues over midnight.
+        let end = ranges.next_transition(start, Tz::UTC).unwrap();
+        assert_eq!(end, Utc.with_ymd_and_hms(2024, 3, 5, 2, 0, 0).unwrap());
+
+        // In Stockholm (UTC+1 in March) everything happens an hour earlier in UTC.
+        let start = ranges.next_transition(at, Tz::Europe__Stockholm).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2024, 3, 4, 21, 0, 0).unwrap());
+
+        assert_eq!(TimePeriodRanges::new().next_transition(at, Tz::UTC), None);
+        let mut always = TimePeriodRanges::new();
+        for day in WEEKDAYS {
+            always.set_day(day, [TimeRange::all_day()]);
+        }
+        assert_eq!(always.next_transition(at, Tz::UTC), None);
+        assert_eq!(always.coverage_pct(), 100.0);
+    }
+
+    #[test]
+    fn test_algebra() {
+        let work = TimePeriodRanges::from_template("mon-fri 08:00-18:00").unwrap();
+        let lunch = TimePeriodRanges::from_template("weekdays 12:00-13:00").unwrap();
+        let evening = TimePeriodRanges::from_template("fri 17:00-22:00").unwrap();
+
+        let ranges = work.clone() - lunch.clone();
+        assert_eq!(
+            ranges.day_string(Weekday::Wed),
+            Some("08:00-12:00,13:00-18:00".to_string())
+        );
+        assert_eq!(ranges.clone() | lunch.clone(), work);
+        assert_eq!(
+            (work.clone() | evening.clone()).day_string(Weekday::Fri),
+            Some("08:00-22:00".to_string())
+        );
+        assert_eq!(
+            (work.clone() & evening).day_string(Weekday::Fri),
+            Some("17:00-18:00".to_string())
+        );
+
+        let off = !work.clone();
+        assert_eq!(
+            off.day_string(Weekday::Mon),
+            Some("00:00-08:00,18:00-24:00".to_string())
+        );
+        assert_eq!(
+            off.day_string(Weekday::Sun),
+            Some("00:00-24:00".to_string())
+        );
+        assert_eq!(off.active_minutes() + work.active_minutes(), 7 * 1440);
+        assert_eq!(!off, work);
+        assert!(work.intersection(&work.complement()).is_empty());
+    }
+
+    #[test]
+    fn test_templates() {
+        let week = TimePeriodRanges::from_template("24x7").unwrap();
+        assert_eq!(week.coverage_pct(), 100.0);
+        assert_eq!(TimePeriodRanges::from_template("daily").unwrap(), week);
+
+        let wrap = TimePeriodRanges::from_template("sat-mon 10:00-11:00").unwrap();
+        assert_eq!(wrap.active_minutes(), 3 * 60);
+        assert!(wrap.day(Weekday::Tue).is_empty());
+
+        let list = TimePeriodRanges::from_template("mon,wed 09:00-10:00,09:30-11:00").unwrap();
+        assert_eq!(
+            list.day_string(Weekday::Wed),
+            Some("09:00-11:00".to_string())
+        );
+
+        assert!(TimePeriodRanges::from_template("someday 09:00-10:00").is_err());
+        assert!(TimePeriodRanges::from_template("weekdays 18:00-08:00").is_err());
+    }
+}
@@ -945,6 +945,24 @@ impl HostVariableRef {
     pub fn id(&self) -> Option<u64> {
         self.id
     }
+
+    /// Returns the argument at the given index (1-4), if set, and whether it is encrypted.
+    ///
+    /// Secured arguments are held in the `encrypted_arg` fields and are returned if the plain
+    /// argument is not set. As returned by the API these hold ciphertext, not the value of the
+    /// argument.
+    pub fn arg(&self, index: u8) -> Option<(&str, bool)> {
+        let (arg, encrypted) = match index {
+            1 => (&self.arg1, &self.encrypted_arg1),
+            2 => (&self.arg2, &self.encrypted_arg2),
+            3 => (&self.arg3, &self.encrypted_arg3),
+            4 => (&self.arg4, &self.encrypted_arg4),
+            _ => return None,
+        };
+        let arg = arg.as_deref().filter(|a| !a.is_empty());
+        let encrypted = encrypted.as_deref().filter(|a| !a.is_empty());
+        arg.map(|a| (a, false)).or(encrypted.map(|a| (a, true)))
+    }
 }
 
 impl ConfigRefMap<HostVariableRef> {
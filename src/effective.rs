@@ -0,0 +1,486 @@
+use crate::expansion::{Expansion, MacroExpander};
+use crate::{config::*, instance::OpsviewInstance, prelude::*};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Where a service check on a host comes from.
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum CheckSource {
+    /// The service check is assigned directly to the host.
+    Direct,
+    /// The service check is inherited from the named host template.
+    Template(String),
+}
+
+impl fmt::Display for CheckSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckSource::Direct => write!(f, "assigned directly"),
+            CheckSource::Template(name) => write!(f, "host template '{}'", name),
+        }
+    }
+}
+
+/// A single instance of a service check that runs on a host.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EffectiveServiceCheck {
+    /// The name of the service check.
+    pub service_check: String,
+    /// The name of the service on the host. For multi-valued checks this is
+    /// `<check name>: <value>`.
+    pub service_name: String,
+    /// Everything that puts the service check on the host, direct assignment first followed by
+    /// host templates in the order in which they are attached to the host.
+    pub sources: Vec<CheckSource>,
+    /// The name and value of the multi-valued variable this instance was created for, if any.
+    pub variable: Option<(String, String)>,
+    /// The exception overriding the arguments of the service check and where it is set, if any.
+    pub exception: Option<(CheckSource, String)>,
+    /// The arguments of this instance with macros expanded.
+    pub expansion: Expansion,
+}
+
+impl EffectiveServiceCheck {
+    /// Returns a human readable explanation of why this service check runs on the host.
+    pub fn explain(&self) -> String {
+        let sources: Vec<String> = self.sources.iter().map(|s| s.to_string()).collect();
+        let mut explanation = format!(
+            "'{}' runs because it is {}",
+            self.service_name,
+            sources.join(" and ")
+        );
+
+        if let Some((name, value)) = &self.variable {
+            explanation.push_str(&format!(
+                ", once for each value of variable '{}' (this is '{}')",
+                name, value
+            ));
+        }
+
+        if let Some((source, _)) = &self.exception {
+            let source = match source {
+                CheckSource::Direct => "the host".to_string(),
+                other => other.to_string(),
+            };
+            explanation.push_str(&format!(", with arguments overridden by {}", source));
+        }
+
+        explanation
+    }
+}
+
+/// A service check inherited from a host template that is removed from the host.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemovedServiceCheck {
+    /// The name of the service check.
+    pub service_check: String,
+    /// The host templates that would otherwise have put the service check on the host.
+    pub sources: Vec<CheckSource>,
+}
+
+/// The effective service checks of a single host, as returned by [`ServiceCheckResolver::resolve`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HostServiceChecks {
+    /// The name of the host.
+    pub host: String,
+    /// The service check instances that run on the host, ordered by service name.
+    pub checks: Vec<EffectiveServiceCheck>,
+    /// Service checks inherited from host templates but removed on the host.
+    pub removed: Vec<RemovedServiceCheck>,
+    /// Multi-valued service checks that are assigned to the host but not instantiated, because
+    /// the host has no values for their variable. Holds the check and variable names.
+    pub without_values: Vec<(String, String)>,
+    /// Host templates referenced by the host that are not in the instance.
+    pub missing_templates: Vec<String>,
+    /// Service checks referenced by the host or its templates that are not in the instance.
+    pub missing_service_checks: Vec<String>,
+}
+
+impl HostServiceChecks {
+    /// Returns all instances of the named service check on the host.
+    pub fn instances_of<'a>(
+        &'a self,
+        service_check: &'a str,
+    ) -> impl Iterator<Item = &'a EffectiveServiceCheck> + 'a {
+        self.checks
+            .iter()
+            .filter(move |c| c.service_check == service_check)
+    }
+
+    /// Returns a human readable explanation of whether and why the named service check runs on
+    /// the host.
+    pub fn explain(&self, service_check: &str) -> String {
+        let instances: Vec<String> = self
+            .instances_of(service_check)
+            .map(|c| c.explain())
+            .collect();
+        if !instances.is_empty() {
+            return instances.join("\n");
+        }
+
+        if let Some(removed) = self
+            .removed
+            .iter()
+            .find(|r| r.service_check == service_check)
+        {
+            let sources: Vec<String> = removed.sources.iter().map(|s| s.to_string()).collect();
+            return format!(
+                "'{}' is inherited from {} but removed on host '{}'",
+                service_check,
+                sources.join(" and "),
+                self.host
+            );
+        }
+
+        if let Some((_, variable)) = self.without_values.iter().find(|(c, _)| c == service_check) {
+            return format!(
+                "'{}' is assigned but host '{}' has no values for variable '{}'",
+                service_check, self.host, variable
+            );
+        }
+
+        format!(
+            "'{}' is neither assigned to host '{}' nor to any of its host templates",
+            service_check, self.host
+        )
+    }
+}
+
+/// Resolves the effective service checks of hosts offline over an [`OpsviewInstance`].
+///
+/// A service check runs on a host if it is assigned directly to the host or to one of its host
+/// templates, unless it is inherited from a template and removed on the host with
+/// `remove_servicecheck`. An `exception` on the host, or else on the first template that has one
+/// in the order in which the templates are attached to the host, overrides the arguments of the
+/// service check. A service check with a multi-valued variable runs once for each value of that
+/// variable on the host.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::*;
+/// use opsview::effective::{CheckSource, ServiceCheckResolver};
+/// use opsview::instance::OpsviewInstance;
+/// use opsview::prelude::*;
+///
+/// let check = ServiceCheck::minimal("Ping").unwrap();
+///
+/// let mut template = HostTemplate::minimal("Network - Base").unwrap();
+/// let mut checks = ConfigRefMap::new();
+/// checks.add(ServiceCheckHostRef::from(check.clone()));
+/// template.servicechecks = Some(checks);
+///
+/// let mut host = Host::minimal("router01").unwrap();
+/// let mut templates = ConfigRefMap::new();
+/// templates.add(HostTemplateRef::from(template.clone()));
+/// host.hosttemplates = Some(templates);
+///
+/// let mut instance = OpsviewInstance::default();
+/// instance.service_checks.add(check);
+/// instance.host_templates.add(template);
+///
+/// let resolved = ServiceCheckResolver::new(&instance).resolve(&host);
+///
+/// assert_eq!(
+///     resolved.checks[0].sources,
+///     vec![CheckSource::Template("Network - Base".to_string())]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct ServiceCheckResolver<'a> {
+    instance: &'a OpsviewInstance,
+    expander: MacroExpander,
+}
+
+/// The assignment of a service check to a host, before exceptions and removals are applied.
+#[derive(Default)]
+struct Assignment {
+    sources: Vec<CheckSource>,
+    exception: Option<(CheckSource, String)>,
+    removed: bool,
+}
+
+impl<'a> ServiceCheckResolver<'a> {
+    /// Creates a new `ServiceCheckResolver` over an [`OpsviewInstance`].
+    pub fn new(instance: &'a OpsviewInstance) -> Self {
+        ServiceCheckResolver {
+            instance,
+            expander: MacroExpander::from_instance(instance),
+        }
+    }
+
+    /// Resolves the effective service checks of a host.
+    ///
+    /// # Arguments
+    /// * `host` - The host to resolve the service checks for. It does not need to be part of the
+    ///   instance.
+    pub fn resolve(&self, host: &Host) -> HostServiceChecks {
+        let mut result = HostServiceChecks {
+            host: host.name.clone(),
+            ..Default::default()
+        };
+        let mut assignments: BTreeMap<String, Assignment> = BTreeMap::new();
+
+        let template_names: Vec<String> = host
+            .hosttemplates
+            .as_ref()
+            .map(|t| t.ordered_values().map(|t| t.name()).collect())
+            .unwrap_or_default();
+
+        for template_name in template_names {
+            let Some(template) = self.instance.host_templates.get(&template_name) else {
+                result.missing_templates.push(template_name);
+                continue;
+            };
+
+            for check_ref in template.servicechecks.iter().flat_map(|c| c.values()) {
+                let source = CheckSource::Template(template_name.clone());
+                let assignment = assignments.entry(check_ref.name()).or_default();
+                if let Some(exception) = check_ref.get_exception().filter(|e| !e.is_empty()) {
+                    assignment
+                        .exception
+                        .get_or_insert((source.clone(), exception));
+                }
+                assignment.sources.push(source);
+            }
+        }
+
+        for check_ref in host.servicechecks.iter().flat_map(|c| c.values()) {
+            let assignment = assignments.entry(check_ref.name()).or_default();
+
+            if check_ref.get_remove_servicecheck() == Some(true) {
+                assignment.removed = true;
+                continue;
+            }
+
+            if let Some(exception) = check_ref.get_exception().filter(|e| !e.is_empty()) {
+                assignment.exception = Some((CheckSource::Direct, exception));
+            }
+            assignment.sources.insert(0, CheckSource::Direct);
+        }
+
+        for (name, assignment) in assignments {
+            if assignment.removed {
+                if !assignment.sources.is_empty() {
+                    result.removed.push(RemovedServiceCheck {
+                        service_check: name,
+                        sources: assignment.sources,
+                    });
+                }
+                continue;
+            }
+
+            let Some(check) = self.instance.service_checks.get(&name) else {
+                result.missing_service_checks.push(name);
+                continue;
+            };
+
+            let mut check = (*check).clone();
+            if let Some((_, exception)) = &assignment.exception {
+                check.args = Some(exception.clone());
+            }
+
+            let variable = check.attribute.as_ref().map(|a| a.name());
+            let expansions = self.expander.expand_service_check(&check, host);
+
+            if expansions.is_empty() {
+                if let Some(variable) = variable {
+                    result.without_values.push((name, variable));
+                }
+                continue;
+            }
+
+            for expansion in expansions {
+                result.checks.push(EffectiveServiceCheck {
+                    service_check: name.clone(),
+                    service_name: expansion.service_name.clone(),
+                    sources: assignment.sources.clone(),
+                    variable: variable.clone().zip(expansion.attribute_value.clone()),
+                    exception: assignment.exception.clone(),
+                    expansion,
+                });
+            }
+        }
+
+        result
+            .checks
+            .sort_by(|a, b| a.service_name.cmp(&b.service_name));
+        result
+    }
+
+    /// Resolves the effective service checks of all hosts in the instance.
+    ///
+    /// # Returns
+    /// A map from host name to the [`HostServiceChecks`] of that host.
+    pub fn resolve_all(&self) -> BTreeMap<String, HostServiceChecks> {
+        self.instance
+            .hosts
+            .values()
+            .map(|host| (host.name.clone(), self.resolve(host)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn check_ref(name: &str) -> ServiceCheckHostRef {
+        ServiceCheckHostRef::from(ServiceCheck::minimal(name).unwrap())
+    }
+
+    fn setup() -> (OpsviewInstance, Host) {
+        let mut instance = OpsviewInstance::default();
+
+        let mut disk = ServiceCheck::minimal("Disk").unwrap();
+        disk.args = Some("-p %DISK%".to_string());
+        disk.attribute = Some(VariableRef::from(Variable::minimal("DISK").unwrap()));
+
+        let mut http = ServiceCheck::minimal("HTTP").unwrap();
+        http.args = Some("-H %HOSTADDRESS%".to_string());
+
+        for check in [
+            disk,
+            http,
+            ServiceCheck::minimal("Ping").unwrap(),
+            ServiceCheck::minimal("SSH").unwrap(),
+        ] {
+            instance.service_checks.add(check);
+        }
+
+        let mut base = HostTemplate::minimal("Base").unwrap();
+        base.servicechecks = Some(ConfigRefMap::from_iter(vec![
+            check_ref("Ping"),
+            check_ref("SSH"),
+            check_ref("Disk"),
+        ]));
+
+        let mut web = HostTemplate::minimal("Web").unwrap();
+        web.servicechecks = Some(ConfigRefMap::from_iter(vec![
+            check_ref("Ping"),
+            check_ref("HTTP").set_exception("-H %HOSTADDRESS% -p 8080"),
+        ]));
+
+        let mut host = Host::minimal("web01").unwrap();
+        host.ip = Some("10.0.0.1".to_string());
+        host.hosttemplates = Some(ConfigRefMap::from_iter(vec![
+            HostTemplateRef::from(base.clone()),
+            HostTemplateRef::from(web.clone()),
+            HostTemplateRef::from(HostTemplate::minimal("Missing").unwrap()),
+        ]));
+        host.servicechecks = Some(ConfigRefMap::from_iter(vec![
+            check_ref("SSH").set_remove_servicecheck(true),
+            check_ref("HTTP").set_exception("-H %HOSTADDRESS% -S"),
+            check_ref("Unknown"),
+        ]));
+
+        let disk_var: HostVariableRef = serde_json::from_value(json!({
+            "name": "DISK", "id": 1, "value": "/var",
+            "arg1": null, "arg2": null, "arg3": null, "arg4": null
+        }))
+        .unwrap();
+        host.hostattributes = Some(ConfigRefMap::from_iter(vec![disk_var]));
+
+        instance.host_templates.add(base);
+        instance.host_templates.add(web);
+        instance.hosts.add(host.clone());
+
+        (instance, host)
+    }
+
+    #[test]
+    fn test_resolve() {
+        let (instance, host) = setup();
+        let resolved = ServiceCheckResolver::new(&instance).resolve(&host);
+
+        let names: Vec<&str> = resolved
+            .checks
+            .iter()
+            .map(|c| c.service_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Disk: /var", "HTTP", "Ping"]);
+
+        let ping = resolved.instances_of("Ping").next().unwrap();
+        assert_eq!(
+            ping.sources,
+            vec![
+                CheckSource::Template("Base".to_string()),
+                CheckSource::Template("Web".to_string())
+            ]
+        );
+
+        let http = resolved.instances_of("HTTP").next().unwrap();
+        assert_eq!(http.sources[0], CheckSource::Direct);
+        assert_eq!(http.expansion.args, "-H 10.0.0.1 -S");
+
+        let disk = resolved.instances_of("Disk").next().unwrap();
+        assert_eq!(
+            disk.variable,
+            Some(("DISK".to_string(), "/var".to_string()))
+        );
+
+        assert_eq!(resolved.removed[0].service_check, "SSH");
+        assert_eq!(resolved.missing_templates, vec!["Missing"]);
+        assert_eq!(resolved.missing_service_checks, vec!["Unknown"]);
+        assert!(resolved.explain("SSH").contains("removed"));
+        assert!(resolved.explain("HTTP").contains("overridden by the host"));
+    }
+
+    #[test]
+    fn test_resolve_all() {
+        let (instance, _) = setup();
+        let all = ServiceCheckResolver::new(&instance).resolve_all();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all["web01"].checks.len(), 3);
+    }
+
+    #[test]
+    fn test_template_exception_precedence_by_attachment_order() {
+        let (mut instance, mut host) = setup();
+
+        let mut alpha = HostTemplate::minimal("Alpha").unwrap();
+        alpha.servicechecks = Some(ConfigRefMap::from_iter(vec![
+            check_ref("Ping").set_exception("-w 1 -c 2")
+        ]));
+        let mut zulu = HostTemplate::minimal("Zulu").unwrap();
+        zulu.servicechecks = Some(ConfigRefMap::from_iter(vec![
+            check_ref("Ping").set_exception("-w 3 -c 4")
+        ]));
+
+        instance.host_templates.add(zulu);
+        instance.host_templates.add(alpha);
+
+        host.hosttemplates =
+            Some(serde_json::from_value(json!([{"name": "Zulu"}, {"name": "Alpha"}])).unwrap());
+        let resolved = ServiceCheckResolver::new(&instance).resolve(&host);
+        let ping = resolved.instances_of("Ping").next().unwrap();
+        assert_eq!(
+            ping.exception,
+            Some((
+                CheckSource::Template("Zulu".to_string()),
+                "-w 3 -c 4".to_string()
+            ))
+        );
+
+        host.hosttemplates = Some(ConfigRefMap::from_iter(vec![
+            HostTemplateRef::from(HostTemplate::minimal("Alpha").unwrap()),
+            HostTemplateRef::from(HostTemplate::minimal("Zulu").unwrap()),
+        ]));
+        let resolved = ServiceCheckResolver::new(&instance).resolve(&host);
+        let ping = resolved.instances_of("Ping").next().unwrap();
+        assert_eq!(
+            ping.sources,
+            vec![
+                CheckSource::Template("Alpha".to_string()),
+                CheckSource::Template("Zulu".to_string())
+            ]
+        );
+        assert_eq!(
+            ping.exception,
+            Some((
+                CheckSource::Template("Alpha".to_string()),
+                "-w 1 -c 2".to_string()
+            ))
+        );
+    }
+}
@@ -1,4 +1,5 @@
 #![allow(missing_docs)]
+use std::time::Duration;
 use thiserror::Error;
 
 #[non_exhaustive] // More error types are likely to be added in the future.
@@ -71,6 +72,9 @@ pub enum OpsviewClientError {
     #[error("Unable to parse JSON: {0}")]
     JsonParseError(String),
 
+    #[error("No time series returned for metric: {0}")]
+    MetricNotReturned(String),
+
     #[error("Missing argument: {0}")]
     MissingArgument(String),
 
@@ -89,6 +93,18 @@ pub enum OpsviewClientError {
     #[error("Object not found: {0}")]
     ObjectNotFound(String),
 
+    #[error("Opspack '{0}' is already installed")]
+    OpspackAlreadyInstalled(String),
+
+    #[error("Failed to import Opspack '{name}': {reason}")]
+    OpspackImportFailed { name: String, reason: String },
+
+    #[error("Opspack not found: {0}")]
+    OpspackNotFound(String),
+
+    #[error("Reload did not finish within {0:?}")]
+    ReloadTimedOut(Duration),
+
     #[error("Resource not found: {0}")]
     ResourceNotFound(String),
 
@@ -126,36 +142,72 @@ pub enum OpsviewConfigError {
     #[error("Conflicting options found: {0}")]
     ConflictingOptions(String),
 
+    #[error("Conflicting time zones: {0} and {1}")]
+    ConflictingTimeZones(String, String),
+
     #[error("Forbidden character found: {0}")]
     ForbiddenCharacter(char),
 
     #[error("Invalid calculate_rate: Must be one of 'no', 'per_second', 'per_minute', 'per_hour'")]
     InvalidCalculateRate,
 
+    #[error("Invalid check arguments: {0}")]
+    InvalidCheckArgs(String),
+
+    #[error("Invalid comparison: {0}")]
+    InvalidComparison(String),
+
     #[error("Invalid HashtagStyle: '{0}'")]
     InvalidHashtagStyle(String),
 
+    #[error("Invalid host group path: {0}")]
+    InvalidHostGroupPath(String),
+
     #[error("Invalid IP: {0}")]
     InvalidIP(String),
 
-    #[error("Invalid notification_options: {0} does not conform to the format 'u,d,r,f'")]
+    #[error("Invalid options: {0} is not a comma separated list of valid options")]
     InvalidNotificationOptions(String),
 
+    #[error("Invalid Opspack: {0}")]
+    InvalidOpspack(String),
+
     #[error("Failed to parse percentage: '{0}' with error '{1}'")]
     InvalidPercentage(String, String),
 
+    #[error("Invalid perfdata: {0}")]
+    InvalidPerfData(String),
+
     #[error("Invalid Plugin origin_id: must be either '0' or '1'")]
     InvalidPluginOriginID,
 
     #[error("Invalid quorum: {0}")]
     InvalidQuorum(String),
 
+    #[error("Invalid range: {0}")]
+    InvalidRange(String),
+
+    #[error("Invalid resolution: {0}")]
+    InvalidResolution(String),
+
     #[error("Invalid SNMP configuration: {0}")]
     InvalidSNMPConfig(String),
 
+    #[error("Invalid threshold: {0}")]
+    InvalidThreshold(String),
+
+    #[error("Invalid time period template: {0}")]
+    InvalidTimePeriodTemplate(String),
+
+    #[error("Invalid time range: {0}")]
+    InvalidTimeRange(String),
+
     #[error("Invalid timestamp: {0}")]
     InvalidTimestamp(String),
 
+    #[error("Invalid time zone: {0}")]
+    InvalidTimeZone(String),
+
     #[error("UTF-8 error, contains a character that is forbidden in this field: {0}")]
     InvalidUtf8(char),
 
@@ -195,6 +247,9 @@ pub enum OpsviewConfigError {
     #[error("Unknown FontAwesomeIcon: {0}")]
     UnknownFontAwesomeIcon(String),
 
+    #[error("Unknown object type: {0}")]
+    UnknownObjectType(String),
+
     #[error("Unable to parse URL: {0}")]
     UrlParseError(url::ParseError),
 }
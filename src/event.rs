@@ -0,0 +1,502 @@
+use crate::client::Params;
+use crate::prelude::*;
+use crate::util::{parse_api_timestamp, API_TIME_FORMAT};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The type of object that an [`Event`] refers to.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EventObjectType {
+    /// The event is a state change of a [`Host`](crate::config::Host).
+    Host,
+    /// The event is a state change of a [`ServiceCheck`](crate::config::ServiceCheck) on a host.
+    Service,
+}
+
+impl fmt::Display for EventObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EventObjectType::Host => write!(f, "host"),
+            EventObjectType::Service => write!(f, "service"),
+        }
+    }
+}
+
+/// The state type of an [`Event`], i.e. whether the state change was confirmed or not.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StateType {
+    /// A state change that has not yet been confirmed by the configured number of retries.
+    Soft,
+    /// A confirmed state change.
+    Hard,
+}
+
+impl fmt::Display for StateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StateType::Soft => write!(f, "soft"),
+            StateType::Hard => write!(f, "hard"),
+        }
+    }
+}
+
+/// The state transition recorded by an [`Event`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StateChange {
+    /// A host state change.
+    Host {
+        /// The state of the host before the event, if known.
+        previous: Option<HostState>,
+        /// The state of the host after the event.
+        new: HostState,
+    },
+    /// A service check state change.
+    Service {
+        /// The state of the service check before the event, if known.
+        previous: Option<ServiceCheckState>,
+        /// The state of the service check after the event.
+        new: ServiceCheckState,
+    },
+}
+
+impl StateChange {
+    /// Returns the [`EventObjectType`] that this state change refers to.
+    pub fn object_type(&self) -> EventObjectType {
+        match self {
+            StateChange::Host { .. } => EventObjectType::Host,
+            StateChange::Service { .. } => EventObjectType::Service,
+        }
+    }
+
+    /// Returns true if the state before the event is known and differs from the state after it.
+    pub fn is_transition(&self) -> bool {
+        match self {
+            StateChange::Host { previous, new } => previous.as_ref().is_some_and(|p| p != new),
+            StateChange::Service { previous, new } => previous.as_ref().is_some_and(|p| p != new),
+        }
+    }
+}
+
+/// Represents a single state change record returned by the Opsview `/rest/event` endpoint.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(try_from = "RawEvent")]
+pub struct Event {
+    /// The ID of the event, if returned by the API.
+    pub id: Option<u64>,
+    /// The time of the state change.
+    pub time: DateTime<Utc>,
+    /// The name of the [`Host`](crate::config::Host) the event refers to.
+    pub hostname: String,
+    /// The name of the service check, for service events.
+    pub servicename: Option<String>,
+    /// The name of the [`HostGroup`](crate::config::HostGroup) the host belongs to, if returned.
+    pub hostgroup: Option<String>,
+    /// Whether the state change is soft or hard.
+    pub state_type: StateType,
+    /// The previous and new state.
+    pub state_change: StateChange,
+    /// The plugin output at the time of the state change.
+    pub output: String,
+    /// Whether the problem had been acknowledged at the time of the state change.
+    pub acknowledged: bool,
+    /// Whether the object was in scheduled downtime at the time of the state change.
+    pub downtime: bool,
+}
+
+impl Event {
+    /// Returns the [`EventObjectType`] of the event.
+    pub fn object_type(&self) -> EventObjectType {
+        self.state_change.object_type()
+    }
+}
+
+/// The event as returned by the API, before any type conversion.
+#[derive(Deserialize)]
+struct RawEvent {
+    #[serde(
+        default,
+        alias = "eventid",
+        deserialize_with = "deserialize_string_or_number_to_u64"
+    )]
+    id: Option<u64>,
+    time: serde_json::Value,
+    hostname: String,
+    #[serde(default)]
+    servicename: Option<String>,
+    #[serde(default, alias = "host_group")]
+    hostgroup: Option<String>,
+    #[serde(default)]
+    objecttype: Option<EventObjectType>,
+    #[serde(alias = "statetype")]
+    state_type: StateType,
+    state: String,
+    #[serde(default, alias = "prior_state", alias = "last_state")]
+    previous_state: Option<String>,
+    #[serde(default)]
+    output: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_string_or_number_to_option_bool"
+    )]
+    acknowledged: Option<bool>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_string_or_number_to_option_bool"
+    )]
+    downtime: Option<bool>,
+}
+
+impl TryFrom<RawEvent> for Event {
+    type Error = OpsviewClientError;
+
+    fn try_from(raw: RawEvent) -> Result<Self, Self::Error> {
+        let servicename = raw.servicename.filter(|s| !s.is_empty());
+
+        let object_type = raw.objecttype.unwrap_or(match servicename {
+            Some(_) => EventObjectType::Service,
+            None => EventObjectType::Host,
+        });
+
+        let previous_state = raw.previous_state.filter(|s| !s.is_empty());
+
+        let state_change = match object_type {
+            EventObjectType::Host => StateChange::Host {
+                previous: previous_state.map(|s| s.parse()).transpose()?,
+                new: raw.state.parse()?,
+            },
+            EventObjectType::Service => StateChange::Service {
+                previous: previous_state.map(|s| s.parse()).transpose()?,
+                new: raw.state.parse()?,
+            },
+        };
+
+        Ok(Event {
+            id: raw.id,
+            time: parse_api_timestamp(&raw.time)?,
+            hostname: raw.hostname,
+            servicename,
+            hostgroup: raw.hostgroup,
+            state_type: raw.state_type,
+            state_change,
+            output: raw.output.unwrap_or_default(),
+            acknowledged: raw.acknowledged.unwrap_or(false),
+            downtime: raw.downtime.unwrap_or(false),
+        })
+    }
+}
+
+/// A query against the Opsview event history.
+///
+/// Use [`EventQuery::builder`] to construct a new query and pass it to
+/// [`OpsviewClient::get_events`](crate::client::OpsviewClient::get_events).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventQuery {
+    /// Only include events at or after this time.
+    pub start_time: Option<DateTime<Utc>>,
+    /// Only include events at or before this time.
+    pub end_time: Option<DateTime<Utc>>,
+    /// Only include events for these hosts.
+    pub hostnames: Vec<String>,
+    /// Only include events for these service checks.
+    pub servicenames: Vec<String>,
+    /// Only include events for hosts in these host groups.
+    pub hostgroup_ids: Vec<u64>,
+    /// Only include events of this state type.
+    pub state_type: Option<StateType>,
+    /// Only include events for this object type.
+    pub object_type: Option<EventObjectType>,
+    /// The number of events to fetch per page.
+    pub rows_per_page: Option<u64>,
+}
+
+impl EventQuery {
+    /// Returns a builder for constructing an `EventQuery`.
+    pub fn builder() -> EventQueryBuilder {
+        EventQueryBuilder::default()
+    }
+
+    /// Returns the query as a list of parameters to be used with the `/rest/event` endpoint.
+    pub fn params(&self) -> Params {
+        let mut params: Params = Vec::new();
+
+        if let Some(start_time) = self.start_time {
+            params.push((
+                "startTime".to_string(),
+                start_time.format(API_TIME_FORMAT).to_string(),
+            ));
+        }
+
+        if let Some(end_time) = self.end_time {
+            params.push((
+                "endTime".to_string(),
+                end_time.format(API_TIME_FORMAT).to_string(),
+            ));
+        }
+
+        for hostname in &self.hostnames {
+            params.push(("hostname".to_string(), hostname.clone()));
+        }
+
+        for servicename in &self.servicenames {
+            params.push(("servicename".to_string(), servicename.clone()));
+        }
+
+        for id in &self.hostgroup_ids {
+            params.push(("hostgroupid".to_string(), id.to_string()));
+        }
+
+        if let Some(state_type) = self.state_type {
+            params.push(("statetype".to_string(), state_type.to_string()));
+        }
+
+        if let Some(object_type) = self.object_type {
+            params.push(("type".to_string(), object_type.to_string()));
+        }
+
+        if let Some(rows) = self.rows_per_page {
+            params.push(("rows".to_string(), rows.to_string()));
+        }
+
+        params
+    }
+}
+
+/// Builder for [`EventQuery`].
+#[derive(Clone, Debug, Default)]
+pub struct EventQueryBuilder {
+    start_time: Option<DateTime<Utc>>,
+    end_time: Option<DateTime<Utc>>,
+    hostnames: Vec<String>,
+    servicenames: Vec<String>,
+    hostgroup_ids: Vec<u64>,
+    state_type: Option<StateType>,
+    object_type: Option<EventObjectType>,
+    rows_per_page: Option<u64>,
+}
+
+impl EventQueryBuilder {
+    /// Sets the start of the time range.
+    ///
+    /// # Arguments
+    /// * `start_time` - Only events at or after this time will be included.
+    pub fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Sets the end of the time range.
+    ///
+    /// # Arguments
+    /// * `end_time` - Only events at or before this time will be included.
+    pub fn end_time(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Adds a host to filter on. May be called multiple times to include several hosts.
+    ///
+    /// # Arguments
+    /// * `hostname` - The name of the host.
+    pub fn hostname(mut self, hostname: &str) -> Self {
+        self.hostnames.push(hostname.to_string());
+        self
+    }
+
+    /// Adds a service check to filter on. May be called multiple times to include several service
+    /// checks.
+    ///
+    /// # Arguments
+    /// * `servicename` - The name of the service check.
+    pub fn servicename(mut self, servicename: &str) -> Self {
+        self.servicenames.push(servicename.to_string());
+        self
+    }
+
+    /// Adds a host group to filter on. May be called multiple times to include several host
+    /// groups.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the [`HostGroup`](crate::config::HostGroup).
+    pub fn hostgroup_id(mut self, id: u64) -> Self {
+        self.hostgroup_ids.push(id);
+        self
+    }
+
+    /// Sets the state type to filter on.
+    ///
+    /// # Arguments
+    /// * `state_type` - Only events of this [`StateType`] will be included.
+    pub fn state_type(mut self, state_type: StateType) -> Self {
+        self.state_type = Some(state_type);
+        self
+    }
+
+    /// Sets the object type to filter on.
+    ///
+    /// # Arguments
+    /// * `object_type` - Only events of this [`EventObjectType`] will be included.
+    pub fn object_type(mut self, object_type: EventObjectType) -> Self {
+        self.object_type = Some(object_type);
+        self
+    }
+
+    /// Sets the number of events to fetch per page.
+    ///
+    /// # Arguments
+    /// * `rows` - The number of events per page. Must be greater than 0.
+    pub fn rows_per_page(mut self, rows: u64) -> Self {
+        self.rows_per_page = Some(rows);
+        self
+    }
+
+    /// Builds a new `EventQuery`.
+    ///
+    /// # Errors
+    /// Returns an error if the start time is after the end time or if `rows_per_page` is 0.
+    pub fn build(self) -> Result<EventQuery, OpsviewConfigError> {
+        if let (Some(start), Some(end)) = (self.start_time, self.end_time) {
+            if start > end {
+                return Err(OpsviewConfigError::InvalidTimestamp(format!(
+                    "start time {} is after end time {}",
+                    start, end
+                )));
+            }
+        }
+
+        if self.rows_per_page == Some(0) {
+            return Err(OpsviewConfigError::RequiredFieldEmpty(
+                "rows_per_page".to_string(),
+            ));
+        }
+
+        Ok(EventQuery {
+            start_time: self.start_time,
+            end_time: self.end_time,
+            hostnames: self.hostnames,
+            servicenames: self.servicenames,
+            hostgroup_ids: self.hostgroup_ids,
+            state_type: self.state_type,
+            object_type: self.object_type,
+            rows_per_page: self.rows_per_page,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_deserialize_service_event() {
+        let json = r#"{
+            "time": "2024-03-01 12:30:00",
+            "hostname": "web01",
+            "servicename": "HTTP",
+            "host_group": "Web",
+            "objecttype": "service",
+            "state_type": "hard",
+            "state": "CRITICAL",
+            "prior_state": "OK",
+            "output": "Connection refused",
+            "acknowledged": "1",
+            "downtime": "0"
+        }"#;
+
+        let event: Event = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            event.time,
+            Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 0).unwrap()
+        );
+        assert_eq!(event.object_type(), EventObjectType::Service);
+        assert_eq!(
+            event.state_change,
+            StateChange::Service {
+                previous: Some(ServiceCheckState::Ok),
+                new: ServiceCheckState::Critical,
+            }
+        );
+        assert!(event.state_change.is_transition());
+        assert!(event.acknowledged);
+        assert!(!event.downtime);
+        assert_eq!(event.hostgroup, Some("Web".to_string()));
+    }
+
+    #[test]
+    fn test_deserialize_host_event_without_objecttype() {
+        let json = r#"{
+            "time": 1709296200,
+            "hostname": "web01",
+            "servicename": null,
+            "statetype": "soft",
+            "state": "down",
+            "output": "PING CRITICAL"
+        }"#;
+
+        let event: Event = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.object_type(), EventObjectType::Host);
+        assert_eq!(
+            event.state_change,
+            StateChange::Host {
+                previous: None,
+                new: HostState::Down,
+            }
+        );
+        assert!(!event.state_change.is_transition());
+        assert_eq!(event.state_type, StateType::Soft);
+    }
+
+    #[test]
+    fn test_deserialize_event_with_invalid_state() {
+        let json = r#"{
+            "time": "2024-03-01T12:30:00Z",
+            "hostname": "web01",
+            "servicename": "HTTP",
+            "state_type": "hard",
+            "state": "up"
+        }"#;
+
+        assert!(serde_json::from_str::<Event>(json).is_err());
+    }
+
+    #[test]
+    fn test_event_query_params() {
+        let query = EventQuery::builder()
+            .start_time(Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap())
+            .end_time(Utc.with_ymd_and_hms(2024, 3, 2, 0, 0, 0).unwrap())
+            .hostname("web01")
+            .hostname("web02")
+            .state_type(StateType::Hard)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            query.params(),
+            vec![
+                ("startTime".to_string(), "2024-03-01 00:00:00".to_string()),
+                ("endTime".to_string(), "2024-03-02 00:00:00".to_string()),
+                ("hostname".to_string(), "web01".to_string()),
+                ("hostname".to_string(), "web02".to_string()),
+                ("statetype".to_string(), "hard".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_event_query_rejects_inverted_range() {
+        let result = EventQuery::builder()
+            .start_time(Utc.with_ymd_and_hms(2024, 3, 2, 0, 0, 0).unwrap())
+            .end_time(Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap())
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(OpsviewConfigError::InvalidTimestamp(_))
+        ));
+    }
+}
@@ -0,0 +1,407 @@
+use crate::{config::*, instance::OpsviewInstance, prelude::*};
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+lazy_static! {
+    static ref MACRO_REGEX: Regex = Regex::new(r"%([A-Z0-9_]+)(?::ARG([1-4]))?%").unwrap();
+}
+
+/// The string shown in place of the value of a secured argument.
+pub const MASK: &str = "********";
+
+/// The result of expanding the macros in the arguments of a service check for a host.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Expansion {
+    /// The name of the service as it appears on the host. For checks with a multi-valued
+    /// variable this is `<check name>: <value>`.
+    pub service_name: String,
+    /// The name of the plugin, if known.
+    pub plugin: Option<String>,
+    /// The value of the multi-valued variable this expansion was made for, if any.
+    pub attribute_value: Option<String>,
+    /// The arguments with all resolvable macros substituted.
+    ///
+    /// This may contain the values of secured arguments and must not be logged. Use
+    /// [`Expansion::masked_args`] for display purposes.
+    pub args: String,
+    /// The arguments with all resolvable macros substituted and the values of secured arguments
+    /// replaced by [`MASK`].
+    pub masked_args: String,
+    /// The macros that could not be resolved, in order of appearance and without duplicates,
+    /// e.g. `%SNMP_COMMUNITY%` or `%URL:ARG2%`. They are left untouched in the arguments. This
+    /// includes the macros of encrypted arguments, which are masked in `masked_args`.
+    pub unresolved: Vec<String>,
+}
+
+impl Expansion {
+    /// Returns the full command line, i.e. the plugin followed by the arguments.
+    ///
+    /// This may contain the values of secured arguments and must not be logged.
+    pub fn command_line(&self) -> String {
+        join_command(self.plugin.as_deref(), &self.args)
+    }
+
+    /// Returns the full command line with the values of secured arguments masked.
+    pub fn masked_command_line(&self) -> String {
+        join_command(self.plugin.as_deref(), &self.masked_args)
+    }
+
+    /// Returns true if all macros were resolved.
+    pub fn is_fully_resolved(&self) -> bool {
+        self.unresolved.is_empty()
+    }
+}
+
+fn join_command(plugin: Option<&str>, args: &str) -> String {
+    match (plugin, args.is_empty()) {
+        (Some(plugin), true) => plugin.to_string(),
+        (Some(plugin), false) => format!("{} {}", plugin, args),
+        (None, _) => args.to_string(),
+    }
+}
+
+/// Expands the Opsview macros in service check arguments for a given host.
+///
+/// The following macros are supported:
+/// * `%HOSTADDRESS%`, `%HOSTNAME%` and `%HOSTALIAS%` - the address, name and alias of the host.
+///   The address falls back to the name if the host has no IP set.
+/// * `%VARNAME%` - the value of the host's variable `VARNAME`.
+/// * `%VARNAME:ARG1%` to `%VARNAME:ARG4%` - the arguments of the host's variable `VARNAME`.
+///
+/// If the host does not have a variable, or has it without a value for an argument, the default
+/// from the [`Variable`] itself is used. Arguments marked as `secured1` to `secured4` on the
+/// `Variable` are masked in [`Expansion::masked_args`]. The `encrypted_arg` fields of the host's
+/// variable hold ciphertext rather than the value, so their macros are left unresolved in
+/// [`Expansion::args`] and only shown as [`MASK`] in [`Expansion::masked_args`].
+///
+/// A service check with a multi-valued variable (its `attribute` field) is expanded once for
+/// each value of that variable on the host, in which case the macros of that variable resolve to
+/// the specific value being expanded. Such a check is not applied to hosts lacking the variable.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::{Host, ServiceCheck, Variable};
+/// use opsview::expansion::MacroExpander;
+/// use opsview::prelude::*;
+///
+/// let mut port = Variable::minimal("HTTP_PORT").unwrap();
+/// port.value = Some("80".to_string());
+///
+/// let variables = ConfigObjectMap::from_iter(vec![port]);
+/// let expander = MacroExpander::new(&variables);
+///
+/// let mut host = Host::minimal("web01").unwrap();
+/// host.ip = Some("10.0.0.1".to_string());
+///
+/// let expansion = expander.expand_args("-H %HOSTADDRESS% -p %HTTP_PORT% -u %URL%", &host);
+///
+/// assert_eq!(expansion.args, "-H 10.0.0.1 -p 80 -u %URL%");
+/// assert_eq!(expansion.unresolved, vec!["%URL%"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MacroExpander {
+    variables: HashMap<String, Arc<Variable>>,
+}
+
+impl MacroExpander {
+    /// Creates a new `MacroExpander` using the given variables for defaults and secured flags.
+    pub fn new(variables: &ConfigObjectMap<Variable>) -> Self {
+        MacroExpander {
+            variables: variables
+                .values()
+                .map(|v| (v.name.clone(), v.clone()))
+                .collect(),
+        }
+    }
+
+    /// Creates a new `MacroExpander` using the variables of an [`OpsviewInstance`].
+    pub fn from_instance(instance: &OpsviewInstance) -> Self {
+        MacroExpander::new(&instance.variables)
+    }
+
+    /// Expands the macros in a string of arguments for a host.
+    ///
+    /// Multi-valued semantics do not apply; if the host has several values for a variable, the
+    /// first one ordered by value is used.
+    ///
+    /// # Arguments
+    /// * `args` - The arguments containing the macros to expand.
+    /// * `host` - The host to expand the macros for.
+    pub fn expand_args(&self, args: &str, host: &Host) -> Expansion {
+        self.expand(args, host, None)
+    }
+
+    /// Expands the arguments of a service check for a host, applying the multi-valued variable
+    /// semantics.
+    ///
+    /// # Arguments
+    /// * `check` - The service check to expand.
+    /// * `host` - The host to expand the service check for.
+    ///
+    /// # Returns
+    /// One [`Expansion`] per instance of the service check on the host. This is a single expansion
+    /// for regular checks, one per variable value for checks with a multi-valued variable, and
+    /// none if the host lacks the multi-valued variable.
+    pub fn expand_service_check(&self, check: &ServiceCheck, host: &Host) -> Vec<Expansion> {
+        let args = check.args.as_deref().unwrap_or_default();
+        let plugin = check.plugin.as_ref().map(|p| p.name.clone());
+
+        let Some(attribute) = &check.attribute else {
+            let mut expansion = self.expand(args, host, None);
+            expansion.service_name = check.name.clone();
+            expansion.plugin = plugin;
+            return vec![expansion];
+        };
+
+        host_variables(host, &attribute.name())
+            .into_iter()
+            .map(|variable| {
+                let value = variable.value.clone().unwrap_or_default();
+                let mut expansion = self.expand(args, host, Some(&variable));
+                expansion.service_name = format!("{}: {}", check.name, value);
+                expansion.plugin = plugin.clone();
+                expansion.attribute_value = Some(value);
+                expansion
+            })
+            .collect()
+    }
+
+    fn expand(&self, args: &str, host: &Host, attribute: Option<&HostVariableRef>) -> Expansion {
+        let mut unresolved: Vec<String> = Vec::new();
+        let mut masked = Vec::new();
+
+        let args_out = MACRO_REGEX
+            .replace_all(args, |caps: &Captures| {
+                let name = &caps[1];
+                let index = caps.get(2).and_then(|m| m.as_str().parse::<u8>().ok());
+
+                let resolved = self.resolve(name, index, host, attribute);
+                if let Some(Resolved::Value(value, secured)) = resolved {
+                    masked.push(if secured {
+                        MASK.to_string()
+                    } else {
+                        value.clone()
+                    });
+                    return value;
+                }
+
+                if !unresolved.iter().any(|u| u == &caps[0]) {
+                    unresolved.push(caps[0].to_string());
+                }
+                masked.push(match resolved {
+                    Some(Resolved::Encrypted) => MASK.to_string(),
+                    _ => caps[0].to_string(),
+                });
+                caps[0].to_string()
+            })
+            .into_owned();
+
+        let mut masked = masked.into_iter();
+        let masked_args = MACRO_REGEX
+            .replace_all(args, |_: &Captures| masked.next().unwrap_or_default())
+            .into_owned();
+
+        Expansion {
+            args: args_out,
+            masked_args,
+            unresolved,
+            ..Default::default()
+        }
+    }
+
+    /// Resolves a single macro.
+    fn resolve(
+        &self,
+        name: &str,
+        index: Option<u8>,
+        host: &Host,
+        attribute: Option<&HostVariableRef>,
+    ) -> Option<Resolved> {
+        match (name, index) {
+            ("HOSTADDRESS", None) => {
+                return Some(Resolved::Value(
+                    host.ip.clone().unwrap_or(host.name.clone()),
+                    false,
+                ))
+            }
+            ("HOSTNAME", None) => return Some(Resolved::Value(host.name.clone(), false)),
+            ("HOSTALIAS", None) => return host.alias.clone().map(|a| Resolved::Value(a, false)),
+            _ => {}
+        }
+
+        let default = self.variables.get(name);
+        let host_variable = match attribute {
+            Some(attribute) if attribute.name == name => Some(attribute.clone()),
+            _ => host_variables(host, name).into_iter().next(),
+        };
+
+        if host_variable.is_none() && default.is_none() {
+            return None;
+        }
+
+        let non_empty = |s: Option<&str>| s.filter(|s| !s.is_empty()).map(str::to_string);
+
+        match index {
+            None => non_empty(host_variable.as_ref().and_then(|v| v.value.as_deref()))
+                .or_else(|| non_empty(default.and_then(|d| d.value.as_deref())))
+                .map(|v| Resolved::Value(v, false)),
+            Some(index) => {
+                let (default_arg, secured) = match (default, index) {
+                    (Some(d), 1) => (d.arg1.as_deref(), d.secured1),
+                    (Some(d), 2) => (d.arg2.as_deref(), d.secured2),
+                    (Some(d), 3) => (d.arg3.as_deref(), d.secured3),
+                    (Some(d), 4) => (d.arg4.as_deref(), d.secured4),
+                    _ => (None, None),
+                };
+                let secured = secured.unwrap_or(false);
+
+                host_variable
+                    .as_ref()
+                    .and_then(|v| v.arg(index))
+                    .map(|(v, encrypted)| {
+                        if encrypted {
+                            Resolved::Encrypted
+                        } else {
+                            Resolved::Value(v.to_string(), secured)
+                        }
+                    })
+                    .or_else(|| non_empty(default_arg).map(|v| Resolved::Value(v, secured)))
+            }
+        }
+    }
+}
+
+/// The result of resolving a single macro.
+enum Resolved {
+    /// The value of the macro and whether it is secured.
+    Value(String, bool),
+    /// The macro refers to an encrypted argument of a host variable, whose value is not known.
+    Encrypted,
+}
+
+/// Returns all variables of a host with the given name, ordered by value.
+fn host_variables(host: &Host, name: &str) -> Vec<HostVariableRef> {
+    let mut variables: Vec<HostVariableRef> = host
+        .hostattributes
+        .as_ref()
+        .map(|attributes| {
+            attributes
+                .values()
+                .filter(|v| v.name == name)
+                .map(|v| (**v).clone())
+                .collect()
+        })
+        .unwrap_or_default();
+    variables.sort_by(|a, b| a.value.cmp(&b.value));
+    variables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn host_variable(id: u64, name: &str, value: &str, arg1: Option<&str>) -> HostVariableRef {
+        serde_json::from_value(json!({
+            "name": name,
+            "id": id,
+            "value": value,
+            "arg1": arg1,
+            "arg2": null,
+            "arg3": null,
+            "arg4": null
+        }))
+        .unwrap()
+    }
+
+    fn setup() -> (MacroExpander, Host) {
+        let mut disk = Variable::minimal("DISK").unwrap();
+        disk.arg1 = Some("80".to_string());
+        disk.arg2 = Some("90".to_string());
+
+        let mut credentials = Variable::minimal("CREDENTIALS").unwrap();
+        credentials.value = Some("default".to_string());
+        credentials.arg1 = Some("admin".to_string());
+        credentials.arg2 = Some("s3cret".to_string());
+        credentials.secured2 = Some(true);
+
+        let expander = MacroExpander::new(&ConfigObjectMap::from_iter(vec![disk, credentials]));
+
+        let mut host = Host::minimal("db01").unwrap();
+        host.ip = Some("10.0.0.5".to_string());
+        let mut attributes = ConfigRefMap::new();
+        attributes.add(host_variable(1, "DISK", "/var", Some("70")));
+        attributes.add(host_variable(2, "DISK", "/", None));
+        host.hostattributes = Some(attributes);
+
+        (expander, host)
+    }
+
+    #[test]
+    fn test_expand_args_with_defaults_and_masking() {
+        let (expander, host) = setup();
+        let expansion = expander.expand_args(
+            "-H %HOSTADDRESS% -u %CREDENTIALS:ARG1% -p %CREDENTIALS:ARG2% -x %NOPE% %NOPE%",
+            &host,
+        );
+
+        assert_eq!(
+            expansion.args,
+            "-H 10.0.0.5 -u admin -p s3cret -x %NOPE% %NOPE%"
+        );
+        assert_eq!(
+            expansion.masked_args,
+            "-H 10.0.0.5 -u admin -p ******** -x %NOPE% %NOPE%"
+        );
+        assert_eq!(expansion.unresolved, vec!["%NOPE%"]);
+    }
+
+    #[test]
+    fn test_expand_args_leaves_encrypted_host_arguments_unresolved() {
+        let mut host = Host::minimal("db01").unwrap();
+        let variable: HostVariableRef = serde_json::from_value(json!({
+            "name": "SNMP",
+            "value": "public",
+            "encrypted_arg1": "t0ps3cret"
+        }))
+        .unwrap();
+        host.hostattributes = Some(ConfigRefMap::from_iter(vec![variable]));
+
+        let expansion = MacroExpander::default().expand_args("-c %SNMP% -a %SNMP:ARG1%", &host);
+        assert_eq!(expansion.args, "-c public -a %SNMP:ARG1%");
+        assert_eq!(expansion.masked_args, "-c public -a ********");
+        assert_eq!(expansion.unresolved, vec!["%SNMP:ARG1%"]);
+    }
+
+    #[test]
+    fn test_expand_multi_valued_service_check() {
+        let (expander, host) = setup();
+
+        let mut check = ServiceCheck::minimal("Disk").unwrap();
+        check.plugin = Some(Plugin {
+            name: "check_disk".to_string(),
+            ..Default::default()
+        });
+        check.args = Some("-p %DISK% -w %DISK:ARG1% -c %DISK:ARG2%".to_string());
+        check.attribute = Some(VariableRef::from(Variable::minimal("DISK").unwrap()));
+
+        let expansions = expander.expand_service_check(&check, &host);
+
+        assert_eq!(expansions.len(), 2);
+        assert_eq!(expansions[0].service_name, "Disk: /");
+        assert_eq!(expansions[0].args, "-p / -w 80 -c 90");
+        assert_eq!(expansions[1].service_name, "Disk: /var");
+        assert_eq!(
+            expansions[1].command_line(),
+            "check_disk -p /var -w 70 -c 90"
+        );
+        assert!(expansions.iter().all(Expansion::is_fully_resolved));
+
+        let other_host = Host::minimal("web01").unwrap();
+        assert!(expander
+            .expand_service_check(&check, &other_host)
+            .is_empty());
+    }
+}
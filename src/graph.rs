@@ -0,0 +1,456 @@
+use crate::client::Params;
+use crate::prelude::*;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// Represents a performance metric that Opsview stores for graphing.
+///
+/// A metric is uniquely identified by the combination of host name, service check name and metric
+/// name, e.g. `opsview::Connectivity - LAN::rta`.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub struct PerformanceMetric {
+    /// The name of the [`Host`](crate::config::Host) the metric belongs to.
+    pub hostname: String,
+    /// The name of the [`ServiceCheck`](crate::config::ServiceCheck) the metric belongs to.
+    pub servicename: String,
+    /// The name of the metric, i.e. the label used in the perfdata.
+    pub metricname: String,
+    /// The unit of measurement of the metric, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uom: Option<String>,
+}
+
+impl PerformanceMetric {
+    /// Creates a new `PerformanceMetric` without a unit of measurement.
+    ///
+    /// # Arguments
+    /// * `hostname` - The name of the host.
+    /// * `servicename` - The name of the service check.
+    /// * `metricname` - The name of the metric.
+    pub fn new(hostname: &str, servicename: &str, metricname: &str) -> Self {
+        PerformanceMetric {
+            hostname: hostname.to_string(),
+            servicename: servicename.to_string(),
+            metricname: metricname.to_string(),
+            uom: None,
+        }
+    }
+
+    /// Returns the `host::service::metric` identifier used by the graph endpoint.
+    pub fn hsm(&self) -> String {
+        format!(
+            "{}::{}::{}",
+            self.hostname, self.servicename, self.metricname
+        )
+    }
+}
+
+impl fmt::Display for PerformanceMetric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.hsm())
+    }
+}
+
+/// The warning and critical thresholds of a metric, if known.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct MetricThresholds {
+    /// The warning threshold.
+    pub warning: Option<f64>,
+    /// The critical threshold.
+    pub critical: Option<f64>,
+}
+
+/// A single data point in a [`TimeSeries`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct DataPoint {
+    /// The time of the data point.
+    pub timestamp: DateTime<Utc>,
+    /// The value at the given time, or `None` if there is a gap in the data.
+    pub value: Option<f64>,
+}
+
+/// A time series of values for a single [`PerformanceMetric`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct TimeSeries {
+    /// The metric the time series belongs to.
+    pub metric: PerformanceMetric,
+    /// The unit of measurement of the values, if any.
+    pub unit: Option<String>,
+    /// The warning and critical thresholds of the metric.
+    pub thresholds: MetricThresholds,
+    /// The data points, ordered by time.
+    pub points: Vec<DataPoint>,
+}
+
+impl TimeSeries {
+    /// Returns an iterator over the data points that have a value.
+    pub fn values(&self) -> impl Iterator<Item = (DateTime<Utc>, f64)> + '_ {
+        self.points
+            .iter()
+            .filter_map(|p| p.value.map(|v| (p.timestamp, v)))
+    }
+
+    /// Returns the smallest value in the time series, if any.
+    pub fn min(&self) -> Option<f64> {
+        self.values().map(|(_, v)| v).reduce(f64::min)
+    }
+
+    /// Returns the largest value in the time series, if any.
+    pub fn max(&self) -> Option<f64> {
+        self.values().map(|(_, v)| v).reduce(f64::max)
+    }
+
+    /// Returns the mean of all values in the time series, if any.
+    pub fn mean(&self) -> Option<f64> {
+        let (sum, count) = self
+            .values()
+            .fold((0.0, 0u64), |(sum, count), (_, v)| (sum + v, count + 1));
+        match count {
+            0 => None,
+            _ => Some(sum / count as f64),
+        }
+    }
+
+    /// Returns a new `TimeSeries` where the data points have been averaged into buckets of the
+    /// given resolution.
+    ///
+    /// Each bucket is aligned to a multiple of `resolution` seconds since the unix epoch and the
+    /// resulting data point is stamped with the start of the bucket. Buckets without any values
+    /// are kept as gaps.
+    ///
+    /// # Arguments
+    /// * `resolution` - The width of each bucket in seconds. A resolution of 0 or 1 returns an
+    ///   unchanged copy.
+    pub fn downsample(&self, resolution: u64) -> TimeSeries {
+        if resolution <= 1 {
+            return self.clone();
+        }
+
+        let resolution = resolution as i64;
+        let mut points: Vec<DataPoint> = Vec::new();
+        let mut sums: Vec<(f64, u64)> = Vec::new();
+
+        for point in &self.points {
+            let bucket = point.timestamp.timestamp().div_euclid(resolution) * resolution;
+            let bucket = DateTime::<Utc>::from_timestamp(bucket, 0).unwrap_or(point.timestamp);
+
+            if points.last().map(|p| p.timestamp) != Some(bucket) {
+                points.push(DataPoint {
+                    timestamp: bucket,
+                    value: None,
+                });
+                sums.push((0.0, 0));
+            }
+
+            if let (Some(v), Some(sum)) = (point.value, sums.last_mut()) {
+                sum.0 += v;
+                sum.1 += 1;
+            }
+        }
+
+        for (point, (sum, count)) in points.iter_mut().zip(sums) {
+            if count > 0 {
+                point.value = Some(sum / count as f64);
+            }
+        }
+
+        TimeSeries {
+            metric: self.metric.clone(),
+            unit: self.unit.clone(),
+            thresholds: self.thresholds.clone(),
+            points,
+        }
+    }
+
+    /// Parses a single element of the `list` returned by the graph endpoint.
+    pub(crate) fn from_graph_value(
+        metric: &PerformanceMetric,
+        value: &Value,
+    ) -> Result<TimeSeries, OpsviewClientError> {
+        let unit = value
+            .get("uom")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+            .or_else(|| metric.uom.clone());
+
+        let thresholds_value = value.get("thresholds").unwrap_or(value);
+        let thresholds = MetricThresholds {
+            warning: thresholds_value.get("warning").and_then(value_as_f64),
+            critical: thresholds_value.get("critical").and_then(value_as_f64),
+        };
+
+        let data = value
+            .get("data")
+            .ok_or(OpsviewClientError::MissingResponseField("data".to_string()))?
+            .as_array()
+            .ok_or(OpsviewClientError::NotAnArray(format!(
+                "Graph data for '{}' is not an array",
+                metric
+            )))?;
+
+        let points = data
+            .iter()
+            .map(|pair| {
+                let parse_error = || {
+                    OpsviewClientError::TypeParseError(pair.to_string(), "DataPoint".to_string())
+                };
+                let ts = pair.get(0).and_then(value_as_f64).ok_or_else(parse_error)?;
+                let timestamp =
+                    DateTime::<Utc>::from_timestamp(ts as i64, 0).ok_or_else(parse_error)?;
+                Ok(DataPoint {
+                    timestamp,
+                    value: pair.get(1).and_then(value_as_f64),
+                })
+            })
+            .collect::<Result<Vec<DataPoint>, OpsviewClientError>>()?;
+
+        Ok(TimeSeries {
+            metric: PerformanceMetric {
+                uom: unit.clone(),
+                ..metric.clone()
+            },
+            unit,
+            thresholds,
+            points,
+        })
+    }
+}
+
+/// Interprets a JSON value that is either a number or a numeric string as `f64`.
+fn value_as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// A query for time series data from the Opsview graph endpoint.
+///
+/// Use [`MetricQuery::builder`] to construct a new query and pass it to
+/// [`OpsviewClient::get_time_series`](crate::client::OpsviewClient::get_time_series).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetricQuery {
+    /// The metrics to fetch.
+    pub metrics: Vec<PerformanceMetric>,
+    /// The start of the time range.
+    pub start_time: DateTime<Utc>,
+    /// The end of the time range.
+    pub end_time: DateTime<Utc>,
+    /// The resolution of the returned data in seconds, if any.
+    pub resolution: Option<u64>,
+}
+
+impl MetricQuery {
+    /// Returns a builder for constructing a `MetricQuery`.
+    pub fn builder() -> MetricQueryBuilder {
+        MetricQueryBuilder::default()
+    }
+
+    /// Returns the query as a list of parameters to be used with the `/rest/graph` endpoint.
+    pub fn params(&self) -> Params {
+        let mut params: Params = self
+            .metrics
+            .iter()
+            .map(|m| ("hsm".to_string(), m.hsm()))
+            .collect();
+
+        params.push(("start".to_string(), self.start_time.timestamp().to_string()));
+        params.push(("end".to_string(), self.end_time.timestamp().to_string()));
+
+        if let Some(resolution) = self.resolution {
+            params.push(("resolution".to_string(), resolution.to_string()));
+        }
+
+        params
+    }
+}
+
+/// Builder for [`MetricQuery`].
+#[derive(Clone, Debug, Default)]
+pub struct MetricQueryBuilder {
+    metrics: Vec<PerformanceMetric>,
+    start_time: Option<DateTime<Utc>>,
+    end_time: Option<DateTime<Utc>>,
+    resolution: Option<u64>,
+}
+
+impl MetricQueryBuilder {
+    /// Adds a metric to fetch. May be called multiple times to fetch several metrics at once.
+    ///
+    /// # Arguments
+    /// * `metric` - The [`PerformanceMetric`] to fetch.
+    pub fn metric(mut self, metric: PerformanceMetric) -> Self {
+        self.metrics.push(metric);
+        self
+    }
+
+    /// Sets the start of the time range.
+    ///
+    /// # Arguments
+    /// * `start_time` - The start of the time range.
+    pub fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Sets the end of the time range.
+    ///
+    /// # Arguments
+    /// * `end_time` - The end of the time range.
+    pub fn end_time(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Sets the resolution of the returned data.
+    ///
+    /// # Arguments
+    /// * `resolution` - The width in seconds of each data point in the returned time series.
+    pub fn resolution(mut self, resolution: u64) -> Self {
+        self.resolution = Some(resolution);
+        self
+    }
+
+    /// Builds a new `MetricQuery`.
+    ///
+    /// # Errors
+    /// Returns an error if no metrics have been added, if the start or end time is missing, if
+    /// the start time is after the end time, or if the resolution is 0.
+    pub fn build(self) -> Result<MetricQuery, OpsviewConfigError> {
+        if self.metrics.is_empty() {
+            return Err(OpsviewConfigError::RequiredFieldEmpty(
+                "metrics".to_string(),
+            ));
+        }
+
+        let start_time = self
+            .start_time
+            .ok_or(OpsviewConfigError::RequiredFieldEmpty(
+                "start_time".to_string(),
+            ))?;
+
+        let end_time = self.end_time.ok_or(OpsviewConfigError::RequiredFieldEmpty(
+            "end_time".to_string(),
+        ))?;
+
+        if start_time > end_time {
+            return Err(OpsviewConfigError::InvalidTimestamp(format!(
+                "start time {} is after end time {}",
+                start_time, end_time
+            )));
+        }
+
+        if self.resolution == Some(0) {
+            return Err(OpsviewConfigError::InvalidResolution(
+                "resolution must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(MetricQuery {
+            metrics: self.metrics,
+            start_time,
+            end_time,
+            resolution: self.resolution,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn metric() -> PerformanceMetric {
+        PerformanceMetric::new("opsview", "Connectivity - LAN", "rta")
+    }
+
+    #[test]
+    fn test_from_graph_value() {
+        let value = json!({
+            "description": "opsview::Connectivity - LAN::rta",
+            "uom": "ms",
+            "thresholds": {"warning": "100", "critical": 200},
+            "data": [[1709296200, 0.5], [1709296500, null], [1709296800, "0.7"]]
+        });
+
+        let series = TimeSeries::from_graph_value(&metric(), &value).unwrap();
+
+        assert_eq!(series.unit, Some("ms".to_string()));
+        assert_eq!(series.thresholds.warning, Some(100.0));
+        assert_eq!(series.thresholds.critical, Some(200.0));
+        assert_eq!(series.points.len(), 3);
+        assert_eq!(series.points[1].value, None);
+        assert_eq!(series.max(), Some(0.7));
+        assert_eq!(series.mean(), Some(0.6));
+    }
+
+    #[test]
+    fn test_downsample() {
+        let value = json!({
+            "data": [[0, 1.0], [300, 3.0], [600, null], [900, null], [1200, 5.0]]
+        });
+
+        let series = TimeSeries::from_graph_value(&metric(), &value)
+            .unwrap()
+            .downsample(600);
+
+        let values: Vec<Option<f64>> = series.points.iter().map(|p| p.value).collect();
+        assert_eq!(values, vec![Some(2.0), None, Some(5.0)]);
+        assert_eq!(series.points[1].timestamp.timestamp(), 600);
+    }
+
+    #[test]
+    fn test_metric_query_requires_metrics() {
+        let result = MetricQuery::builder()
+            .start_time(DateTime::<Utc>::from_timestamp(0, 0).unwrap())
+            .end_time(DateTime::<Utc>::from_timestamp(3600, 0).unwrap())
+            .build();
+
+        assert_eq!(
+            result,
+            Err(OpsviewConfigError::RequiredFieldEmpty(
+                "metrics".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_metric_query_rejects_zero_resolution() {
+        let result = MetricQuery::builder()
+            .metric(metric())
+            .start_time(DateTime::<Utc>::from_timestamp(0, 0).unwrap())
+            .end_time(DateTime::<Utc>::from_timestamp(3600, 0).unwrap())
+            .resolution(0)
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(OpsviewConfigError::InvalidResolution(_))
+        ));
+    }
+
+    #[test]
+    fn test_metric_query_params() {
+        let query = MetricQuery::builder()
+            .metric(metric())
+            .start_time(DateTime::<Utc>::from_timestamp(0, 0).unwrap())
+            .end_time(DateTime::<Utc>::from_timestamp(3600, 0).unwrap())
+            .resolution(300)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            query.params(),
+            vec![
+                ("hsm".to_string(), metric().hsm()),
+                ("start".to_string(), "0".to_string()),
+                ("end".to_string(), "3600".to_string()),
+                ("resolution".to_string(), "300".to_string()),
+            ]
+        );
+    }
+}
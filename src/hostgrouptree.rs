@@ -0,0 +1,633 @@
+use crate::{client::OpsviewClient, config::*, instance::OpsviewInstance, prelude::*};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The separator between the names of the host groups in a path.
+const PATH_SEPARATOR: char = ',';
+
+/// The hierarchy of [`HostGroup`] objects in an Opsview instance, navigable by path.
+///
+/// A path is the comma-separated list of host group names from the root down to a host group,
+/// such as `"Opsview,EU,Web"`. This is the same format as the `matpath` of a `HostGroup`, and a
+/// trailing comma is accepted so that a `matpath` can be used as a path directly.
+///
+/// The `HostGroup` objects held by the tree always have their `matpath` set to match their
+/// position in the tree.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::HostGroup;
+/// use opsview::hostgrouptree::HostGroupTree;
+/// use opsview::prelude::*;
+///
+/// let groups: ConfigObjectMap<HostGroup> = ["Opsview,", "Opsview,EU,", "Opsview,EU,Web,"]
+///     .iter()
+///     .map(|matpath| {
+///         let name = matpath.trim_end_matches(',').rsplit(',').next().unwrap();
+///         let mut group = HostGroup::minimal(name).unwrap();
+///         group.matpath = Some(matpath.to_string());
+///         group
+///     })
+///     .collect();
+///
+/// let tree = HostGroupTree::from_map(&groups);
+///
+/// assert_eq!(tree.get("Opsview,EU,Web").unwrap().name, "Web");
+/// assert_eq!(tree.ancestors("Opsview,EU,Web").len(), 2);
+/// assert_eq!(tree.descendants("Opsview").len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HostGroupTree {
+    groups: BTreeMap<String, HostGroup>,
+    hosts: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl HostGroupTree {
+    /// Builds a `HostGroupTree` from the host groups and hosts of an [`OpsviewInstance`].
+    ///
+    /// Hosts are placed in the tree both from the `hosts` of each `HostGroup` and from the
+    /// `hostgroup` of each [`Host`].
+    ///
+    /// # Arguments
+    /// * `instance` - The `OpsviewInstance` to build the tree from.
+    pub fn from_instance(instance: &OpsviewInstance) -> Self {
+        let mut tree = Self::from_map(&instance.host_groups);
+
+        for host in instance.hosts.values() {
+            let path = host
+                .hostgroup
+                .as_ref()
+                .and_then(|hostgroup| tree.path_of_ref(hostgroup));
+
+            if let Some(path) = path {
+                tree.hosts
+                    .entry(path)
+                    .or_default()
+                    .insert(host.name.clone());
+            }
+        }
+
+        tree
+    }
+
+    /// Builds a `HostGroupTree` from a [`ConfigObjectMap`] of `HostGroup` objects.
+    ///
+    /// The position of each host group is taken from its `matpath`. Host groups without a
+    /// `matpath` are placed below their `parent`, and host groups with neither are placed at the
+    /// root of the tree.
+    ///
+    /// # Arguments
+    /// * `host_groups` - The host groups to build the tree from.
+    pub fn from_map(host_groups: &ConfigObjectMap<HostGroup>) -> Self {
+        let groups: Vec<&HostGroup> = host_groups.values().map(|g| g.as_ref()).collect();
+        let mut tree = HostGroupTree::default();
+
+        for (index, group) in groups.iter().enumerate() {
+            let Some(path) = resolve_path(&groups, index, groups.len()) else {
+                continue;
+            };
+
+            let mut group = (*group).clone();
+            group.matpath = Some(to_matpath(&path));
+
+            let hosts = group
+                .hosts
+                .as_ref()
+                .map(|hosts| hosts.values().map(|h| h.name()).collect::<BTreeSet<_>>())
+                .unwrap_or_default();
+
+            if !hosts.is_empty() {
+                tree.hosts.entry(path.clone()).or_default().extend(hosts);
+            }
+
+            tree.groups.insert(path, group);
+        }
+
+        tree
+    }
+
+    /// Returns the number of host groups in the tree.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns `true` if the tree contains no host groups.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Returns `true` if the tree contains a host group at the given path.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the host group, such as `"Opsview,EU,Web"`.
+    pub fn contains(&self, path: &str) -> bool {
+        self.get(path).is_some()
+    }
+
+    /// Returns the host group at the given path, if any.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the host group, such as `"Opsview,EU,Web"`.
+    pub fn get(&self, path: &str) -> Option<&HostGroup> {
+        normalize_path(path)
+            .ok()
+            .and_then(|path| self.groups.get(&path))
+    }
+
+    /// Returns the paths of all host groups in the tree, ordered by path.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.groups.keys().map(|path| path.as_str())
+    }
+
+    /// Returns the host groups at the root of the tree.
+    pub fn roots(&self) -> Vec<&HostGroup> {
+        self.groups
+            .iter()
+            .filter(|(path, _)| parent_path(path).is_none())
+            .map(|(_, group)| group)
+            .collect()
+    }
+
+    /// Returns the parent of the host group at the given path, if any.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the host group, such as `"Opsview,EU,Web"`.
+    pub fn parent(&self, path: &str) -> Option<&HostGroup> {
+        normalize_path(path)
+            .ok()
+            .and_then(|path| parent_path(&path).map(str::to_string))
+            .and_then(|parent| self.groups.get(&parent))
+    }
+
+    /// Returns the direct children of the host group at the given path, ordered by name.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the host group, such as `"Opsview,EU,Web"`.
+    pub fn children(&self, path: &str) -> Vec<&HostGroup> {
+        let Ok(path) = normalize_path(path) else {
+            return Vec::new();
+        };
+
+        self.subtree(&path)
+            .filter(|(child, _)| parent_path(child) == Some(path.as_str()))
+            .map(|(_, group)| group)
+            .collect()
+    }
+
+    /// Returns the ancestors of the host group at the given path, starting with its parent and
+    /// ending with the root.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the host group, such as `"Opsview,EU,Web"`.
+    pub fn ancestors(&self, path: &str) -> Vec<&HostGroup> {
+        let Ok(path) = normalize_path(path) else {
+            return Vec::new();
+        };
+
+        let mut ancestors = Vec::new();
+        let mut current = parent_path(&path);
+
+        while let Some(ancestor) = current {
+            if let Some(group) = self.groups.get(ancestor) {
+                ancestors.push(group);
+            }
+            current = parent_path(ancestor);
+        }
+
+        ancestors
+    }
+
+    /// Returns all descendants of the host group at the given path, ordered by path.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the host group, such as `"Opsview,EU,Web"`.
+    pub fn descendants(&self, path: &str) -> Vec<&HostGroup> {
+        let Ok(path) = normalize_path(path) else {
+            return Vec::new();
+        };
+
+        self.subtree(&path).map(|(_, group)| group).collect()
+    }
+
+    /// Returns the names of the hosts placed directly in the host group at the given path,
+    /// ordered by name.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the host group, such as `"Opsview,EU,Web"`.
+    pub fn hosts(&self, path: &str) -> Vec<&str> {
+        normalize_path(path)
+            .ok()
+            .and_then(|path| self.hosts.get(&path))
+            .map(|hosts| hosts.iter().map(|h| h.as_str()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the names of the hosts in the host group at the given path and all of its
+    /// descendants, ordered by name.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the host group, such as `"Opsview,EU,Web"`.
+    pub fn subtree_hosts(&self, path: &str) -> Vec<&str> {
+        let Ok(path) = normalize_path(path) else {
+            return Vec::new();
+        };
+
+        let prefix = format!("{}{}", path, PATH_SEPARATOR);
+        let hosts: BTreeSet<&str> = self
+            .hosts
+            .iter()
+            .filter(|(group, _)| **group == path || group.starts_with(&prefix))
+            .flat_map(|(_, hosts)| hosts.iter().map(|h| h.as_str()))
+            .collect();
+
+        hosts.into_iter().collect()
+    }
+
+    /// Moves the host group at the given path, along with all of its descendants and their hosts,
+    /// below a new parent.
+    ///
+    /// The `parent`, `children`, `matpath` and `is_leaf` fields of the affected host groups are
+    /// updated to match the new position. The move is only made in the tree; use
+    /// [`Persistent::update`] on the moved host group to apply it to the Opsview instance.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the host group to move.
+    /// * `new_parent` - The path of the host group to move it below.
+    ///
+    /// # Returns
+    /// The new path of the moved host group.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError::InvalidHostGroupPath` if either path does not exist, if the
+    /// new parent is within the subtree being moved, or if the new parent already has a child with
+    /// the same name.
+    pub fn move_subtree(
+        &mut self,
+        path: &str,
+        new_parent: &str,
+    ) -> Result<String, OpsviewConfigError> {
+        let path = normalize_path(path)?;
+        let new_parent = normalize_path(new_parent)?;
+
+        let name = self
+            .groups
+            .get(&path)
+            .map(|group| group.name.clone())
+            .ok_or_else(|| not_found(&path))?;
+
+        if !self.groups.contains_key(&new_parent) {
+            return Err(not_found(&new_parent));
+        }
+
+        if new_parent == path || new_parent.starts_with(&format!("{}{}", path, PATH_SEPARATOR)) {
+            return Err(OpsviewConfigError::InvalidHostGroupPath(format!(
+                "cannot move '{}' below its own subtree '{}'",
+                path, new_parent
+            )));
+        }
+
+        let new_path = join_path(&new_parent, &name);
+        if self.groups.contains_key(&new_path) {
+            return Err(OpsviewConfigError::InvalidHostGroupPath(format!(
+                "'{}' already exists",
+                new_path
+            )));
+        }
+
+        let old_parent = parent_path(&path).map(str::to_string);
+        let moved: Vec<String> = std::iter::once(path.clone())
+            .chain(self.subtree(&path).map(|(p, _)| p.clone()))
+            .collect();
+
+        let mut affected = Vec::with_capacity(moved.len() + 2);
+        for old in moved {
+            let new = format!("{}{}", new_path, &old[path.len()..]);
+
+            if let Some(mut group) = self.groups.remove(&old) {
+                group.matpath = Some(to_matpath(&new));
+                self.groups.insert(new.clone(), group);
+            }
+            if let Some(hosts) = self.hosts.remove(&old) {
+                self.hosts.insert(new.clone(), hosts);
+            }
+
+            affected.push(new);
+        }
+
+        affected.extend(old_parent);
+        affected.push(new_parent);
+
+        for path in affected {
+            self.relink(&path);
+        }
+
+        Ok(new_path)
+    }
+
+    /// Makes sure that a host group exists at the given path, creating any missing host groups
+    /// along the path in order from the root down through the client.
+    ///
+    /// Host groups that are created are added to the tree with the `id`, `ref_` and `matpath`
+    /// returned by the Opsview API.
+    ///
+    /// # Arguments
+    /// * `client` - The `OpsviewClient` used to create the missing host groups.
+    /// * `path` - The path of the host group, such as `"Opsview,EU,Web"`.
+    ///
+    /// # Returns
+    /// The host group at the given path.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewError` if the path or one of the names in it is invalid, or if a host
+    /// group could not be created.
+    pub async fn ensure_path(
+        &mut self,
+        client: &OpsviewClient,
+        path: &str,
+    ) -> Result<HostGroup, OpsviewError> {
+        let path = normalize_path(path)?;
+        let mut current = String::new();
+
+        for name in path.split(PATH_SEPARATOR) {
+            let parent = (!current.is_empty()).then(|| current.clone());
+            current = match &parent {
+                Some(parent) => join_path(parent, name),
+                None => name.to_string(),
+            };
+
+            if self.groups.contains_key(&current) {
+                continue;
+            }
+
+            let mut builder = HostGroup::builder().name(name);
+            if let Some(parent_group) = parent.as_ref().and_then(|p| self.groups.get(p)) {
+                builder = builder.parent(parent_group.clone());
+            }
+            let group = builder.build()?;
+
+            let response = group.create(client).await?;
+            let mut created = match response.get("object") {
+                Some(object) => serde_json::from_value::<HostGroup>(object.clone())?,
+                None => group,
+            };
+            created.matpath = Some(to_matpath(&current));
+
+            self.groups.insert(current.clone(), created);
+            self.relink(&current);
+            if let Some(parent) = parent {
+                self.relink(&parent);
+            }
+        }
+
+        Ok(self.groups[&current].clone())
+    }
+
+    /// Returns an iterator over all descendants of the host group at the given normalized path.
+    fn subtree<'a>(&'a self, path: &str) -> impl Iterator<Item = (&'a String, &'a HostGroup)> {
+        let prefix = format!("{}{}", path, PATH_SEPARATOR);
+        self.groups
+            .range(prefix.clone()..)
+            .take_while(move |(p, _)| p.starts_with(&prefix))
+    }
+
+    /// Returns the path of the host group a [`HostGroupRef`] refers to, matching on the reference
+    /// string, then the materialized path, and finally the name if it is unique.
+    pub(crate) fn path_of_ref(&self, hostgroup: &HostGroupRef) -> Option<String> {
+        if let Some(ref_) = hostgroup.ref_() {
+            let found = self
+                .groups
+                .iter()
+                .find(|(_, group)| group.ref_.as_ref() == Some(&ref_));
+            if let Some((path, _)) = found {
+                return Some(path.clone());
+            }
+        }
+
+        if let Some(path) = hostgroup.matpath().and_then(|m| normalize_path(&m).ok()) {
+            if self.groups.contains_key(&path) {
+                return Some(path);
+            }
+        }
+
+        let mut named = self
+            .groups
+            .iter()
+            .filter(|(_, group)| group.name == hostgroup.name());
+        match (named.next(), named.next()) {
+            (Some((path, _)), None) => Some(path.clone()),
+            _ => None,
+        }
+    }
+
+    /// Updates the `parent`, `children` and `is_leaf` fields of the host group at the given
+    /// normalized path to match the tree.
+    fn relink(&mut self, path: &str) {
+        let parent = parent_path(path)
+            .and_then(|parent| self.groups.get(parent))
+            .map(|parent| HostGroupRef::from(parent.clone()));
+
+        let children: ConfigRefMap<HostGroupRef> = self
+            .subtree(path)
+            .filter(|(child, _)| parent_path(child) == Some(path))
+            .map(|(_, child)| HostGroupRef::from(child.clone()))
+            .collect();
+
+        if let Some(group) = self.groups.get_mut(path) {
+            if parent.is_some() {
+                group.parent = parent;
+            }
+            if group.is_leaf.is_some() {
+                group.is_leaf = Some(children.is_empty());
+            }
+            if group.children.is_some() || !children.is_empty() {
+                group.children = Some(children);
+            }
+        }
+    }
+}
+
+/// Normalizes a path by trimming the names in it and removing any trailing separator.
+fn normalize_path(path: &str) -> Result<String, OpsviewConfigError> {
+    let trimmed = path.trim().trim_end_matches(PATH_SEPARATOR);
+    let names: Vec<&str> = trimmed.split(PATH_SEPARATOR).map(str::trim).collect();
+
+    if trimmed.is_empty() || names.iter().any(|name| name.is_empty()) {
+        return Err(OpsviewConfigError::InvalidHostGroupPath(path.to_string()));
+    }
+
+    Ok(names.join(","))
+}
+
+/// Returns the path of the parent of the given normalized path, if any.
+fn parent_path(path: &str) -> Option<&str> {
+    path.rsplit_once(PATH_SEPARATOR).map(|(parent, _)| parent)
+}
+
+/// Returns the path of the child with the given name below the given normalized path.
+fn join_path(parent: &str, name: &str) -> String {
+    format!("{}{}{}", parent, PATH_SEPARATOR, name)
+}
+
+/// Returns the `matpath` of the host group at the given normalized path.
+fn to_matpath(path: &str) -> String {
+    format!("{}{}", path, PATH_SEPARATOR)
+}
+
+fn not_found(path: &str) -> OpsviewConfigError {
+    OpsviewConfigError::InvalidHostGroupPath(format!("'{}' not found", path))
+}
+
+/// Resolves the path of the host group at `index` from its `matpath`, or from its parent if it has
+/// none. Returns `None` if the parent chain is longer than `depth`, which only happens for cycles.
+fn resolve_path(groups: &[&HostGroup], index: usize, depth: usize) -> Option<String> {
+    let group = groups[index];
+
+    if let Some(path) = group
+        .matpath
+        .as_deref()
+        .and_then(|m| normalize_path(m).ok())
+    {
+        return Some(path);
+    }
+
+    let Some(parent) = &group.parent else {
+        return Some(group.name.clone());
+    };
+
+    if let Some(path) = parent.matpath().and_then(|m| normalize_path(&m).ok()) {
+        return Some(join_path(&path, &group.name));
+    }
+
+    if depth == 0 {
+        return None;
+    }
+
+    let parent_index = groups.iter().position(|candidate| {
+        parent
+            .ref_()
+            .is_some_and(|r| candidate.ref_.as_ref() == Some(&r))
+            || (parent.ref_().is_none() && candidate.name == parent.name())
+    })?;
+
+    resolve_path(groups, parent_index, depth - 1).map(|path| join_path(&path, &group.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn group(matpath: &str) -> HostGroup {
+        let name = matpath.trim_end_matches(',').rsplit(',').next().unwrap();
+        let mut group = HostGroup::minimal(name).unwrap();
+        group.matpath = Some(matpath.to_string());
+        group.is_leaf = Some(false);
+        group
+    }
+
+    fn tree() -> HostGroupTree {
+        let mut web = group("Opsview,EU,Web,");
+        web.hosts =
+            Some(serde_json::from_value(json!([{"name": "web01"}, {"name": "web02"}])).unwrap());
+
+        let mut db = group("Opsview,EU,DB,");
+        db.hosts = Some(serde_json::from_value(json!([{"name": "db01"}])).unwrap());
+
+        let groups: ConfigObjectMap<HostGroup> = vec![
+            group("Opsview,"),
+            group("Opsview,EU,"),
+            group("Opsview,US,"),
+            web,
+            db,
+        ]
+        .into_iter()
+        .collect();
+
+        HostGroupTree::from_map(&groups)
+    }
+
+    fn names(groups: Vec<&HostGroup>) -> Vec<&str> {
+        groups.iter().map(|g| g.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_navigation() {
+        let tree = tree();
+
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.get("Opsview,EU,Web,").unwrap().name, "Web");
+        assert_eq!(tree.get(" Opsview, EU ").unwrap().name, "EU");
+        assert!(tree.get("Opsview,,EU").is_none());
+        assert_eq!(names(tree.roots()), vec!["Opsview"]);
+        assert_eq!(tree.parent("Opsview,EU,DB").unwrap().name, "EU");
+        assert_eq!(names(tree.children("Opsview,EU")), vec!["DB", "Web"]);
+        assert_eq!(
+            names(tree.ancestors("Opsview,EU,Web")),
+            vec!["EU", "Opsview"]
+        );
+        assert_eq!(
+            names(tree.descendants("Opsview")),
+            vec!["EU", "DB", "Web", "US"]
+        );
+        assert_eq!(tree.hosts("Opsview,EU,Web"), vec!["web01", "web02"]);
+        assert_eq!(
+            tree.subtree_hosts("Opsview,EU"),
+            vec!["db01", "web01", "web02"]
+        );
+    }
+
+    #[test]
+    fn test_from_map_without_matpath() {
+        let root = HostGroup::minimal("Opsview").unwrap();
+        let eu = HostGroup::builder()
+            .name("EU")
+            .parent(root.clone())
+            .build()
+            .unwrap();
+        let web = HostGroup::builder()
+            .name("Web")
+            .parent(eu.clone())
+            .build()
+            .unwrap();
+
+        let groups: ConfigObjectMap<HostGroup> = vec![root, eu, web].into_iter().collect();
+        let tree = HostGroupTree::from_map(&groups);
+
+        assert_eq!(
+            tree.get("Opsview,EU,Web").unwrap().matpath.as_deref(),
+            Some("Opsview,EU,Web,")
+        );
+    }
+
+    #[test]
+    fn test_move_subtree() {
+        let mut tree = tree();
+
+        let new_path = tree.move_subtree("Opsview,EU", "Opsview,US").unwrap();
+        assert_eq!(new_path, "Opsview,US,EU");
+
+        assert!(tree.get("Opsview,EU").is_none());
+        assert_eq!(
+            tree.get("Opsview,US,EU,Web").unwrap().matpath.as_deref(),
+            Some("Opsview,US,EU,Web,")
+        );
+        assert_eq!(
+            tree.get("Opsview,US,EU")
+                .unwrap()
+                .parent
+                .as_ref()
+                .unwrap()
+                .name(),
+            "US"
+        );
+        assert_eq!(tree.get("Opsview,US").unwrap().is_leaf, Some(false));
+        assert_eq!(
+            tree.subtree_hosts("Opsview,US"),
+            vec!["db01", "web01", "web02"]
+        );
+        assert_eq!(names(tree.children("Opsview")), vec!["US"]);
+
+        assert!(tree.move_subtree("Opsview,US", "Opsview,US,EU").is_err());
+        assert!(tree.move_subtree("Opsview,Missing", "Opsview").is_err());
+        assert!(tree.move_subtree("Opsview,US,EU", "Opsview,US").is_err());
+    }
+}
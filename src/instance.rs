@@ -1,4 +1,5 @@
 use crate::client::OpsviewClient;
+use crate::integrity::{validate_instance, IntegrityFinding};
 use crate::{config::*, prelude::*};
 use serde::{Deserialize, Serialize};
 use tokio::join;
@@ -24,6 +25,9 @@ pub struct OpsviewInstance {
     /// A collection of all the Contacts in the Opsview instance.
     pub contacts: ConfigObjectMap<Contact>,
 
+    /// A collection of all the Hashtags in the Opsview instance.
+    pub hashtags: ConfigObjectMap<Hashtag>,
+
     /// A collection of all the Host Check Commands in the Opsview instance.
     pub host_check_commands: ConfigObjectMap<HostCheckCommand>,
 
@@ -51,6 +55,9 @@ pub struct OpsviewInstance {
     /// A collection of all the Plugins in the Opsview instance.
     pub plugins: ConfigObjectMap<Plugin>,
 
+    /// A collection of all the Roles in the Opsview instance.
+    pub roles: ConfigObjectMap<Role>,
+
     /// A collection of all the Service Checks in the Opsview instance.
     pub service_checks: ConfigObjectMap<ServiceCheck>,
 
@@ -77,6 +84,7 @@ impl Default for OpsviewInstance {
             bsm_components: ConfigObjectMap::<BSMComponent>::new(),
             bsm_services: ConfigObjectMap::<BSMService>::new(),
             contacts: ConfigObjectMap::<Contact>::new(),
+            hashtags: ConfigObjectMap::<Hashtag>::new(),
             host_check_commands: ConfigObjectMap::<HostCheckCommand>::new(),
             host_groups: ConfigObjectMap::<HostGroup>::new(),
             host_templates: ConfigObjectMap::<HostTemplate>::new(),
@@ -86,6 +94,7 @@ impl Default for OpsviewInstance {
             netflow_sources: ConfigObjectMap::<NetflowSource>::new(),
             notification_methods: ConfigObjectMap::<NotificationMethod>::new(),
             plugins: ConfigObjectMap::<Plugin>::new(),
+            roles: ConfigObjectMap::<Role>::new(),
             service_checks: ConfigObjectMap::<ServiceCheck>::new(),
             service_groups: ConfigObjectMap::<ServiceGroup>::new(),
             shared_notification_profiles: ConfigObjectMap::<SharedNotificationProfile>::new(),
@@ -115,6 +124,7 @@ impl OpsviewInstance {
         let bsm_components_future = client.get_all_bsmcomponent_configs(None);
         let bsm_services_future = client.get_all_bsmservice_configs(None);
         let contacts_future = client.get_all_contact_configs(None);
+        let hashtags_future = client.get_all_hashtag_configs(None);
         let host_check_commands_future = client.get_all_hostcheckcommand_configs(None);
         let host_groups_future = client.get_all_hostgroup_configs(None);
         let host_templates_future = client.get_all_hosttemplate_configs(None);
@@ -124,6 +134,7 @@ impl OpsviewInstance {
         let netflow_sources_future = client.get_all_netflowsource_configs(None);
         let notification_methods_future = client.get_all_notificationmethod_configs(None);
         let plugins_future = client.get_all_plugin_configs(None);
+        let roles_future = client.get_all_role_configs(None);
         let service_checks_future = client.get_all_servicecheck_configs(None);
         let service_groups_future = client.get_all_servicegroup_configs(None);
         let shared_notification_profiles_future =
@@ -136,6 +147,7 @@ impl OpsviewInstance {
             bsm_components,
             bsm_services,
             contacts,
+            hashtags,
             host_check_commands,
             host_groups,
             host_templates,
@@ -145,6 +157,7 @@ impl OpsviewInstance {
             netflow_sources,
             notification_methods,
             plugins,
+            roles,
             service_checks,
             service_groups,
             shared_notification_profiles,
@@ -155,6 +168,7 @@ impl OpsviewInstance {
             bsm_components_future,
             bsm_services_future,
             contacts_future,
+            hashtags_future,
             host_check_commands_future,
             host_groups_future,
             host_templates_future,
@@ -164,6 +178,7 @@ impl OpsviewInstance {
             netflow_sources_future,
             notification_methods_future,
             plugins_future,
+            roles_future,
             service_checks_future,
             service_groups_future,
             shared_notification_profiles_future,
@@ -176,6 +191,7 @@ impl OpsviewInstance {
             bsm_components: bsm_components.unwrap(),
             bsm_services: bsm_services.unwrap(),
             contacts: contacts.unwrap(),
+            hashtags: hashtags.unwrap(),
             host_check_commands: host_check_commands.unwrap(),
             host_groups: host_groups.unwrap(),
             host_templates: host_templates.unwrap(),
@@ -185,6 +201,7 @@ impl OpsviewInstance {
             netflow_sources: netflow_sources.unwrap(),
             notification_methods: notification_methods.unwrap(),
             plugins: plugins.unwrap(),
+            roles: roles.unwrap(),
             service_checks: service_checks.unwrap(),
             service_groups: service_groups.unwrap(),
             shared_notification_profiles: shared_notification_profiles.unwrap(),
@@ -193,6 +210,35 @@ impl OpsviewInstance {
             variables: variables.unwrap(),
         })
     }
+
+    /// Checks the references between the objects of the `OpsviewInstance`.
+    ///
+    /// Reports every reference to an object that does not exist, every reference whose `ref_` and
+    /// name point to different objects, including references in the notification profiles embedded
+    /// in contacts, hosts that are their own parent, cycles of host or host
+    /// group parents, and service checks whose plugin does not exist.
+    ///
+    /// # Returns
+    /// The [`IntegrityFinding`]s sorted by the path of the object they were found in, or an empty
+    /// `Vec` if the instance is consistent.
+    pub fn validate(&self) -> Vec<IntegrityFinding> {
+        validate_instance(self)
+    }
+}
+
+#[cfg(test)]
+impl OpsviewInstance {
+    /// Builds an `OpsviewInstance` for tests from a JSON object holding lists of objects in API
+    /// JSON, keyed by the name of the field of the instance, such as `hosts`. Other fields are
+    /// left empty.
+    pub(crate) fn from_fixture(objects: serde_json::Value) -> Self {
+        let mut instance = serde_json::to_value(OpsviewInstance::default()).unwrap();
+        for (field, value) in objects.as_object().unwrap() {
+            assert!(instance.get(field).is_some(), "unknown field {}", field);
+            instance[field] = value.clone();
+        }
+        serde_json::from_value(instance).unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -231,6 +277,7 @@ mod tests {
         // assert!(!instance.bsm_components.is_empty());
         // assert!(!instance.bsm_services.is_empty());
         // assert!(!instance.contacts.is_empty());
+        // assert!(!instance.hashtags.is_empty());
         // assert!(!instance.host_check_commands.is_empty());
         // assert!(!instance.host_groups.is_empty());
         // assert!(!instance.host_templates.is_empty());
@@ -240,6 +287,7 @@ mod tests {
         // assert!(!instance.netflow_collectors.is_empty());
         // assert!(!instance.netflow_sources.is_empty());
         // assert!(!instance.plugins.is_empty());
+        // assert!(!instance.roles.is_empty());
         // assert!(!instance.service_checks.is_empty());
         // assert!(!instance.service_groups.is_empty());
         // assert!(!instance.shared_notification_profiles.is_empty());
@@ -253,6 +301,7 @@ mod tests {
         println!("BSM Components: {}", instance.bsm_components.len());
         println!("BSM Services: {}", instance.bsm_services.len());
         println!("Contacts: {}", instance.contacts.len());
+        println!("Hashtags: {}", instance.hashtags.len());
         println!(
             "Host Check Commands: {}",
             instance.host_check_commands.len()
@@ -271,6 +320,7 @@ mod tests {
             instance.notification_methods.len()
         );
         println!("Plugins: {}", instance.plugins.len());
+        println!("Roles: {}", instance.roles.len());
         println!("Service Checks: {}", instance.service_checks.len());
         println!("Service Groups: {}", instance.service_groups.len());
         println!(
@@ -0,0 +1,827 @@
+use crate::{config::*, instance::OpsviewInstance, prelude::*};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+/// The location of a finding: an object of an [`OpsviewInstance`], and optionally a field of it
+/// and an item of that field.
+///
+/// Displayed as `<endpoint>/<name>[/<field>[/<item>]]`, such as `host/web01/parents/db01`.
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct ObjectPath {
+    /// The type of the object.
+    pub object_type: ConfigObjectType,
+    /// The name of the object.
+    pub name: String,
+    /// The field of the object, if the finding is about a field.
+    pub field: Option<String>,
+    /// The item of the field, such as the name of a referenced object in a list.
+    pub item: Option<String>,
+}
+
+impl ObjectPath {
+    /// Creates a new `ObjectPath` to an object.
+    pub fn new(object_type: ConfigObjectType, name: &str) -> Self {
+        ObjectPath {
+            object_type,
+            name: name.to_string(),
+            field: None,
+            item: None,
+        }
+    }
+
+    /// Returns the path to a field of the object.
+    pub fn field(mut self, field: &str) -> Self {
+        self.field = Some(field.to_string());
+        self
+    }
+
+    /// Returns the path to an item of the field.
+    pub fn item(mut self, item: &str) -> Self {
+        self.item = Some(item.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ObjectPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.object_type.endpoint(), self.name)?;
+        if let Some(ref field) = self.field {
+            write!(f, "/{}", field)?;
+        }
+        if let Some(ref item) = self.item {
+            write!(f, "/{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+/// A referential integrity problem found by [`OpsviewInstance::validate`].
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum IntegrityIssue {
+    /// The reference points to an object that does not exist, neither by `ref_` nor by name.
+    DanglingRef {
+        /// The type of the referenced object.
+        target: ConfigObjectType,
+        /// The name in the reference.
+        name: String,
+        /// The `ref_` in the reference, if any.
+        ref_: Option<String>,
+    },
+    /// The `ref_` of the reference points to an object with a different name.
+    NameMismatch {
+        /// The type of the referenced object.
+        target: ConfigObjectType,
+        /// The name in the reference.
+        name: String,
+        /// The `ref_` in the reference.
+        ref_: String,
+        /// The name of the object with the `ref_`.
+        actual: String,
+    },
+    /// The name of the reference belongs to an object with a different `ref_`.
+    RefMismatch {
+        /// The type of the referenced object.
+        target: ConfigObjectType,
+        /// The name in the reference.
+        name: String,
+        /// The `ref_` in the reference.
+        ref_: String,
+        /// The `ref_` of the object with the name.
+        actual: String,
+    },
+    /// The host is its own parent.
+    SelfParent,
+    /// The host is part of a cycle of parents, listed from the host onwards.
+    HostParentCycle(Vec<String>),
+    /// The host group is part of a cycle of parents, listed from the host group onwards.
+    HostGroupCycle(Vec<String>),
+    /// The plugin of the service check does not exist.
+    MissingPlugin(String),
+}
+
+impl fmt::Display for IntegrityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntegrityIssue::DanglingRef { target, name, ref_ } => {
+                write!(f, "{} '{}' does not exist", target, name)?;
+                if let Some(ref_) = ref_ {
+                    write!(f, " ({})", ref_)?;
+                }
+                Ok(())
+            }
+            IntegrityIssue::NameMismatch {
+                target,
+                name,
+                ref_,
+                actual,
+            } => write!(
+                f,
+                "{} '{}' refers to {}, which is named '{}'",
+                target, name, ref_, actual
+            ),
+            IntegrityIssue::RefMismatch {
+                target,
+                name,
+                ref_,
+                actual,
+            } => write!(
+                f,
+                "{} '{}' refers to {}, but the object with that name is {}",
+                target, name, ref_, actual
+            ),
+            IntegrityIssue::SelfParent => write!(f, "host is its own parent"),
+            IntegrityIssue::HostParentCycle(cycle) => {
+                write!(f, "cyclic host parents: {}", cycle_string(cycle))
+            }
+            IntegrityIssue::HostGroupCycle(cycle) => {
+                write!(f, "cyclic host group parents: {}", cycle_string(cycle))
+            }
+            IntegrityIssue::MissingPlugin(plugin) => {
+                write!(f, "plugin '{}' does not exist", plugin)
+            }
+        }
+    }
+}
+
+/// A referential integrity problem and where it was found.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::*;
+/// use opsview::instance::OpsviewInstance;
+/// use opsview::integrity::IntegrityIssue;
+/// use serde_json::json;
+///
+/// let mut instance = OpsviewInstance::default();
+/// let host: Host = serde_json::from_value(json!({
+///     "name": "web01",
+///     "hostgroup": { "name": "Missing" },
+///     "parents": [{ "name": "web01" }]
+/// }))
+/// .unwrap();
+/// instance.hosts.add(host);
+///
+/// let findings = instance.validate();
+/// assert_eq!(findings.len(), 2);
+/// assert_eq!(
+///     findings[0].to_string(),
+///     "host/web01/hostgroup: Host Group 'Missing' does not exist"
+/// );
+/// assert_eq!(findings[1].issue, IntegrityIssue::SelfParent);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct IntegrityFinding {
+    /// Where the problem was found.
+    pub path: ObjectPath,
+    /// The problem.
+    pub issue: IntegrityIssue,
+}
+
+impl fmt::Display for IntegrityFinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.issue)
+    }
+}
+
+/// The `ref_`s and names of the objects of one type.
+#[derive(Default)]
+struct Index {
+    by_ref: HashMap<String, String>,
+    by_name: HashMap<String, Vec<Option<String>>>,
+}
+
+impl Index {
+    fn new<T: Persistent>(objects: &ConfigObjectMap<T>) -> Self {
+        let mut index = Index::default();
+        for object in objects.as_vec() {
+            let Some(name) = Persistent::name(object.as_ref()) else {
+                continue;
+            };
+            let ref_ = Persistent::ref_(object.as_ref());
+            if let Some(ref ref_) = ref_ {
+                index.by_ref.insert(ref_.clone(), name.clone());
+            }
+            index.by_name.entry(name).or_default().push(ref_);
+        }
+        index
+    }
+}
+
+/// Checks the references between the objects of an instance.
+struct Validator<'a> {
+    instance: &'a OpsviewInstance,
+    indexes: HashMap<ConfigObjectType, Index>,
+    findings: Vec<IntegrityFinding>,
+}
+
+impl<'a> Validator<'a> {
+    fn new(instance: &'a OpsviewInstance) -> Self {
+        let indexes = HashMap::from([
+            (
+                ConfigObjectType::BSMComponent,
+                Index::new(&instance.bsm_components),
+            ),
+            (
+                ConfigObjectType::BSMService,
+                Index::new(&instance.bsm_services),
+            ),
+            (ConfigObjectType::Contact, Index::new(&instance.contacts)),
+            (ConfigObjectType::Hashtag, Index::new(&instance.hashtags)),
+            (ConfigObjectType::Host, Index::new(&instance.hosts)),
+            (
+                ConfigObjectType::HostCheckCommand,
+                Index::new(&instance.host_check_commands),
+            ),
+            (
+                ConfigObjectType::HostGroup,
+                Index::new(&instance.host_groups),
+            ),
+            (
+                ConfigObjectType::HostTemplate,
+                Index::new(&instance.host_templates),
+            ),
+            (
+                ConfigObjectType::MonitoringCluster,
+                Index::new(&instance.monitoring_clusters),
+            ),
+            (
+                ConfigObjectType::NotificationMethod,
+                Index::new(&instance.notification_methods),
+            ),
+            (ConfigObjectType::Role, Index::new(&instance.roles)),
+            (
+                ConfigObjectType::ServiceCheck,
+                Index::new(&instance.service_checks),
+            ),
+            (
+                ConfigObjectType::ServiceGroup,
+                Index::new(&instance.service_groups),
+            ),
+            (
+                ConfigObjectType::SharedNotificationProfile,
+                Index::new(&instance.shared_notification_profiles),
+            ),
+            (ConfigObjectType::Tenancy, Index::new(&instance.tenancies)),
+            (
+                ConfigObjectType::TimePeriod,
+                Index::new(&instance.time_periods),
+            ),
+            (ConfigObjectType::Variable, Index::new(&instance.variables)),
+        ]);
+
+        Validator {
+            instance,
+            indexes,
+            findings: Vec::new(),
+        }
+    }
+
+    fn report(&mut self, path: ObjectPath, issue: IntegrityIssue) {
+        self.findings.push(IntegrityFinding { path, issue });
+    }
+
+    /// Checks a single reference.
+    fn check<R: ConfigRef>(&mut self, path: ObjectPath, reference: &R) {
+        let Some(target) = ConfigObjectType::of::<R::FullObject>() else {
+            return;
+        };
+        let Some(index) = self.indexes.get(&target) else {
+            return;
+        };
+        let name = reference.name();
+        let ref_ = reference.ref_().filter(|r| !r.is_empty());
+
+        if let Some(actual) = ref_.as_ref().and_then(|r| index.by_ref.get(r)) {
+            if *actual != name {
+                let issue = IntegrityIssue::NameMismatch {
+                    target,
+                    name,
+                    ref_: ref_.unwrap_or_default(),
+                    actual: actual.clone(),
+                };
+                self.report(path, issue);
+            }
+            return;
+        }
+
+        let issue = match (index.by_name.get(&name), ref_) {
+            (None, ref_) => IntegrityIssue::DanglingRef { target, name, ref_ },
+            (Some(refs), Some(ref_)) => {
+                // The ref is unknown, so report it if every object with the name has another one.
+                let mut actual: Vec<&String> = refs.iter().flatten().collect();
+                if actual.len() < refs.len() {
+                    return;
+                }
+                actual.sort();
+                IntegrityIssue::RefMismatch {
+                    target,
+                    name,
+                    ref_,
+                    actual: actual[0].clone(),
+                }
+            }
+            (Some(_), None) => return,
+        };
+        self.report(path, issue);
+    }
+
+    /// Checks an optional single reference in a field.
+    fn check_field<R: ConfigRef>(&mut self, path: &ObjectPath, field: &str, reference: &Option<R>) {
+        if let Some(reference) = reference {
+            self.check(path.clone().field(field), reference);
+        }
+    }
+
+    /// Checks every reference in a field holding a list of references.
+    fn check_list<R: ConfigRef>(
+        &mut self,
+        path: &ObjectPath,
+        field: &str,
+        references: &Option<ConfigRefMap<R>>,
+    ) {
+        let Some(references) = references else {
+            return;
+        };
+        let mut references = references.as_vec();
+        references.sort_by_key(|r| r.name());
+        for reference in references {
+            let item = path.clone().field(field).item(&reference.name());
+            self.check(item, reference.as_ref());
+        }
+    }
+
+    fn check_refs(&mut self) {
+        let instance = self.instance;
+
+        for c in instance.bsm_components.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::BSMComponent, &c.name);
+            self.check_field(&path, "host_template", &c.host_template);
+            self.check_list(&path, "hosts", &c.hosts);
+            self.check_field(&path, "monitoring_cluster", &c.monitoring_cluster);
+        }
+
+        for s in instance.bsm_services.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::BSMService, &s.name);
+            self.check_list(&path, "components", &s.components);
+            self.check_field(&path, "monitoring_cluster", &s.monitoring_cluster);
+        }
+
+        for c in instance.contacts.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::Contact, &c.name);
+            self.check_field(&path, "role", &c.role);
+            self.check_list(
+                &path,
+                "sharednotificationprofiles",
+                &c.sharednotificationprofiles,
+            );
+
+            // Notification profiles are embedded in the contact, so their references are reported
+            // under the contact as `notificationprofiles/<profile>/<field>`.
+            for p in c.notificationprofiles.iter().flat_map(|p| p.as_vec()) {
+                let field = |name: &str| format!("notificationprofiles/{}/{}", p.name, name);
+                self.check_list(&path, &field("business_components"), &p.business_components);
+                self.check_list(&path, &field("business_services"), &p.business_services);
+                self.check_list(&path, &field("hostgroups"), &p.hostgroups);
+                self.check_list(&path, &field("keywords"), &p.keywords);
+                self.check_list(&path, &field("notificationmethods"), &p.notificationmethods);
+                self.check_field(&path, &field("notification_period"), &p.notification_period);
+                self.check_list(&path, &field("servicegroups"), &p.servicegroups);
+            }
+        }
+
+        for h in instance.hashtags.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::Hashtag, &h.name);
+            self.check_list(&path, "hosts", &h.hosts);
+            self.check_list(&path, "roles", &h.roles);
+            self.check_list(&path, "servicechecks", &h.servicechecks);
+        }
+
+        for h in instance.hosts.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::Host, &h.name);
+            self.check_list(&path, "business_components", &h.business_components);
+            self.check_field(&path, "check_command", &h.check_command);
+            self.check_field(&path, "check_period", &h.check_period);
+            self.check_field(&path, "hostgroup", &h.hostgroup);
+            self.check_list(&path, "hostattributes", &h.hostattributes);
+            self.check_list(&path, "hosttemplates", &h.hosttemplates);
+            self.check_list(&path, "keywords", &h.keywords);
+            self.check_field(&path, "monitored_by", &h.monitored_by);
+            self.check_field(&path, "notification_period", &h.notification_period);
+            self.check_list(&path, "parents", &h.parents);
+            self.check_list(&path, "servicechecks", &h.servicechecks);
+        }
+
+        for c in instance.host_check_commands.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::HostCheckCommand, &c.name);
+            self.check_list(&path, "hosts", &c.hosts);
+        }
+
+        for g in instance.host_groups.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::HostGroup, &g.name);
+            self.check_list(&path, "children", &g.children);
+            self.check_list(&path, "hosts", &g.hosts);
+            self.check_field(&path, "parent", &g.parent);
+        }
+
+        for t in instance.host_templates.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::HostTemplate, &t.name);
+            self.check_list(&path, "hosts", &t.hosts);
+            self.check_list(&path, "servicechecks", &t.servicechecks);
+        }
+
+        for c in instance.monitoring_clusters.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::MonitoringCluster, &c.name);
+            self.check_list(&path, "monitors", &c.monitors);
+            self.check_list(&path, "roles", &c.roles);
+        }
+
+        for c in instance.netflow_collectors.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::NetflowCollector, &c.name);
+            self.check_field(&path, "monitoring_server", &c.monitoring_server);
+        }
+
+        for p in instance.plugins.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::Plugin, &p.name);
+            self.check_list(&path, "servicechecks", &p.servicechecks);
+        }
+
+        for r in instance.roles.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::Role, &r.name);
+            self.check_list(&path, "access_hostgroups", &r.access_hostgroups);
+            self.check_list(&path, "access_keywords", &r.access_keywords);
+            self.check_list(&path, "access_servicegroups", &r.access_servicegroups);
+            self.check_list(&path, "contacts", &r.contacts);
+            self.check_list(&path, "hostgroups", &r.hostgroups);
+            self.check_list(
+                &path,
+                "remotely_managed_clusters",
+                &r.remotely_managed_clusters,
+            );
+            self.check_field(&path, "tenancy", &r.tenancy);
+        }
+
+        for s in instance.service_checks.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::ServiceCheck, &s.name);
+            self.check_field(&path, "attribute", &s.attribute);
+            self.check_field(&path, "cascaded_from", &s.cascaded_from);
+            self.check_field(&path, "check_period", &s.check_period);
+            self.check_list(&path, "dependencies", &s.dependencies);
+            self.check_list(&path, "hosts", &s.hosts);
+            self.check_list(&path, "hosttemplates", &s.hosttemplates);
+            self.check_list(&path, "keywords", &s.keywords);
+            self.check_field(&path, "notification_period", &s.notification_period);
+            self.check_field(&path, "servicegroup", &s.servicegroup);
+
+            if let Some(ref plugin) = s.plugin {
+                if !instance.plugins.contains(&plugin.name) {
+                    let issue = IntegrityIssue::MissingPlugin(plugin.name.clone());
+                    self.report(path.clone().field("plugin"), issue);
+                }
+            }
+        }
+
+        for g in instance.service_groups.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::ServiceGroup, &g.name);
+            self.check_list(&path, "servicechecks", &g.servicechecks);
+        }
+
+        for p in instance.shared_notification_profiles.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::SharedNotificationProfile, &p.name);
+            self.check_list(&path, "business_components", &p.business_components);
+            self.check_list(&path, "business_services", &p.business_services);
+            self.check_list(&path, "hostgroups", &p.hostgroups);
+            self.check_list(&path, "keywords", &p.keywords);
+            self.check_field(&path, "notification_period", &p.notification_period);
+            let methods = p.notificationmethods.as_ref().map(ConfigRefMap::from);
+            self.check_list(&path, "notificationmethods", &methods);
+            self.check_field(&path, "role", &p.role);
+            self.check_list(&path, "servicegroups", &p.servicegroups);
+        }
+
+        for t in instance.tenancies.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::Tenancy, &t.name);
+            self.check_field(&path, "primary_role", &t.primary_role);
+        }
+
+        for t in instance.time_periods.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::TimePeriod, &t.name);
+            self.check_list(&path, "host_check_periods", &t.host_check_periods);
+            self.check_list(
+                &path,
+                "host_notification_periods",
+                &t.host_notification_periods,
+            );
+            self.check_list(
+                &path,
+                "servicecheck_check_periods",
+                &t.servicecheck_check_periods,
+            );
+            self.check_list(
+                &path,
+                "servicecheck_notification_periods",
+                &t.servicecheck_notification_periods,
+            );
+        }
+
+        for v in instance.variables.as_vec() {
+            let path = ObjectPath::new(ConfigObjectType::Variable, &v.name);
+            self.check_list(&path, "servicechecks", &v.servicechecks);
+        }
+    }
+
+    /// Reports hosts that are their own parent, and cycles of host parents.
+    fn check_host_parents(&mut self) {
+        let mut parents: HashMap<String, Vec<String>> = HashMap::new();
+        for host in self.instance.hosts.as_vec() {
+            let mut names: Vec<String> = host
+                .parents
+                .as_ref()
+                .map(|p| p.keys().cloned().collect())
+                .unwrap_or_default();
+            names.sort();
+
+            if names.contains(&host.name) {
+                let path = ObjectPath::new(ConfigObjectType::Host, &host.name)
+                    .field("parents")
+                    .item(&host.name);
+                self.report(path, IntegrityIssue::SelfParent);
+                names.retain(|name| *name != host.name);
+            }
+            parents.insert(host.name.clone(), names);
+        }
+
+        for cycle in find_cycles(&parents) {
+            let path = ObjectPath::new(ConfigObjectType::Host, &cycle[0]).field("parents");
+            self.report(path, IntegrityIssue::HostParentCycle(cycle));
+        }
+    }
+
+    /// Reports cycles of host group parents.
+    fn check_host_group_parents(&mut self) {
+        let groups = &self.instance.host_groups;
+        let by_ref: HashMap<String, String> = groups
+            .iter()
+            .filter_map(|(key, g)| g.ref_.clone().map(|r| (r, key.clone())))
+            .collect();
+
+        let resolve = |parent: &HostGroupRef| -> Option<String> {
+            if let Some(key) = parent.ref_().and_then(|r| by_ref.get(&r)) {
+                return Some(key.clone());
+            }
+            let key = parent.unique_name();
+            if groups.contains(&key) {
+                return Some(key);
+            }
+            let mut named = groups.iter().filter(|(_, g)| g.name == parent.name());
+            match (named.next(), named.next()) {
+                (Some((key, _)), None) => Some(key.clone()),
+                _ => None,
+            }
+        };
+
+        let parents: HashMap<String, Vec<String>> = groups
+            .iter()
+            .map(|(key, g)| {
+                let parent = g.parent.as_ref().and_then(resolve);
+                (key.clone(), parent.into_iter().collect())
+            })
+            .collect();
+
+        for cycle in find_cycles(&parents) {
+            let names: Vec<String> = cycle
+                .iter()
+                .map(|key| groups.get(key).map_or(key.clone(), |g| g.name.clone()))
+                .collect();
+            let path = ObjectPath::new(ConfigObjectType::HostGroup, &names[0]).field("parent");
+            self.report(path, IntegrityIssue::HostGroupCycle(names));
+        }
+    }
+}
+
+/// Finds the cycles in a graph, each rotated to start with its smallest node.
+fn find_cycles(edges: &HashMap<String, Vec<String>>) -> BTreeSet<Vec<String>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Visit {
+        InProgress,
+        Done,
+    }
+
+    fn visit(
+        node: &str,
+        edges: &HashMap<String, Vec<String>>,
+        visits: &mut HashMap<String, Visit>,
+        stack: &mut Vec<String>,
+        cycles: &mut BTreeSet<Vec<String>>,
+    ) {
+        visits.insert(node.to_string(), Visit::InProgress);
+        stack.push(node.to_string());
+
+        for next in edges.get(node).into_iter().flatten() {
+            match visits.get(next) {
+                Some(Visit::InProgress) => {
+                    let start = stack.iter().position(|n| n == next).unwrap_or(0);
+                    let mut cycle = stack[start..].to_vec();
+                    let min = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap_or(0);
+                    cycle.rotate_left(min);
+                    cycles.insert(cycle);
+                }
+                Some(Visit::Done) => {}
+                None => visit(next, edges, visits, stack, cycles),
+            }
+        }
+
+        stack.pop();
+        visits.insert(node.to_string(), Visit::Done);
+    }
+
+    let mut nodes: Vec<&String> = edges.keys().collect();
+    nodes.sort();
+
+    let mut visits = HashMap::new();
+    let mut cycles = BTreeSet::new();
+    for node in nodes {
+        if !visits.contains_key(node) {
+            visit(node, edges, &mut visits, &mut Vec::new(), &mut cycles);
+        }
+    }
+    cycles
+}
+
+fn cycle_string(cycle: &[String]) -> String {
+    let mut names = cycle.to_vec();
+    names.extend(cycle.first().cloned());
+    names.join(" -> ")
+}
+
+/// Checks the references between the objects of an instance, returning the findings sorted by
+/// path.
+pub(crate) fn validate_instance(instance: &OpsviewInstance) -> Vec<IntegrityFinding> {
+    let mut validator = Validator::new(instance);
+    validator.check_refs();
+    validator.check_host_parents();
+    validator.check_host_group_parents();
+
+    let mut findings = validator.findings;
+    findings.sort();
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn instance() -> OpsviewInstance {
+        OpsviewInstance::from_fixture(json!({
+            "host_groups": [
+                {"name": "Opsview", "ref": "/rest/config/hostgroup/1", "matpath": "Opsview,"},
+                {
+                    "name": "A", "ref": "/rest/config/hostgroup/2", "matpath": "Opsview,A,",
+                    "parent": {"name": "B", "ref": "/rest/config/hostgroup/3"}
+                },
+                {
+                    "name": "B", "ref": "/rest/config/hostgroup/3", "matpath": "Opsview,B,",
+                    "parent": {"name": "A", "ref": "/rest/config/hostgroup/2"}
+                }
+            ],
+            "hosts": [
+                {
+                    "name": "a", "ref": "/rest/config/host/1",
+                    "hostgroup": {"name": "Opsview", "ref": "/rest/config/hostgroup/1"},
+                    "parents": [{"name": "b"}]
+                },
+                {
+                    "name": "b", "ref": "/rest/config/host/2",
+                    "hostgroup": {"name": "Renamed", "ref": "/rest/config/hostgroup/1"},
+                    "parents": [{"name": "c"}]
+                },
+                {
+                    "name": "c", "ref": "/rest/config/host/3",
+                    "hostgroup": {"name": "Opsview", "ref": "/rest/config/hostgroup/9"},
+                    "parents": [{"name": "a"}, {"name": "c"}]
+                }
+            ],
+            "plugins": [{"name": "check_ping"}],
+            "service_checks": [
+                {"name": "Ping", "plugin": {"name": "check_ping"}},
+                {"name": "Disk", "plugin": {"name": "check_disk"}, "servicegroup": {"name": "OS"}}
+            ]
+        }))
+    }
+
+    #[test]
+    fn test_validate() {
+        let findings: Vec<String> = instance()
+            .validate()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(
+            findings,
+            vec![
+                "host/a/parents: cyclic host parents: a -> b -> c -> a",
+                "host/b/hostgroup: Host Group 'Renamed' refers to /rest/config/hostgroup/1, which \
+                 is named 'Opsview'",
+                "host/c/hostgroup: Host Group 'Opsview' refers to /rest/config/hostgroup/9, but the \
+                 object with that name is /rest/config/hostgroup/1",
+                "host/c/parents/c: host is its own parent",
+                "hostgroup/A/parent: cyclic host group parents: A -> B -> A",
+                "servicecheck/Disk/plugin: plugin 'check_disk' does not exist",
+                "servicecheck/Disk/servicegroup: Service Group 'OS' does not exist",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_host_attributes() {
+        let instance = OpsviewInstance::from_fixture(json!({
+            "variables": [{"name": "DISK"}],
+            "hosts": [{
+                "name": "web01",
+                "hostattributes": [
+                    {"name": "DISK", "value": "/"},
+                    {"name": "SNMP_IF", "value": "eth0"}
+                ]
+            }]
+        }));
+
+        let findings: Vec<String> = instance
+            .validate()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            findings,
+            vec!["host/web01/hostattributes/SNMP_IF: Variable 'SNMP_IF' does not exist"]
+        );
+    }
+
+    #[test]
+    fn test_validate_embedded_notification_profiles() {
+        let instance = OpsviewInstance::from_fixture(json!({
+            "notification_methods": [{"name": "Email"}],
+            "contacts": [{
+                "name": "alice",
+                "notificationprofiles": [{
+                    "name": "Daytime",
+                    "hostgroups": [{"name": "EU"}],
+                    "servicegroups": [{"name": "Web"}],
+                    "keywords": [{"name": "prod"}],
+                    "notification_period": {"name": "workhours"},
+                    "notificationmethods": [{"name": "Email"}, {"name": "SMS"}]
+                }]
+            }],
+            "shared_notification_profiles": [{
+                "name": "On call",
+                "notificationmethods": [{"name": "Email"}, {"name": "Pager"}]
+            }]
+        }));
+
+        let findings: Vec<String> = instance
+            .validate()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            findings,
+            vec![
+                "contact/alice/notificationprofiles/Daytime/hostgroups/EU: Host Group 'EU' does \
+                 not exist",
+                "contact/alice/notificationprofiles/Daytime/keywords/prod: Hashtag 'prod' does \
+                 not exist",
+                "contact/alice/notificationprofiles/Daytime/notification_period: Time Period \
+                 'workhours' does not exist",
+                "contact/alice/notificationprofiles/Daytime/notificationmethods/SMS: Notification \
+                 Method 'SMS' does not exist",
+                "contact/alice/notificationprofiles/Daytime/servicegroups/Web: Service Group 'Web' \
+                 does not exist",
+                "sharednotificationprofile/On call/notificationmethods/Pager: Notification Method \
+                 'Pager' does not exist",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_cycles() {
+        let edges: HashMap<String, Vec<String>> = [
+            ("a", vec!["b"]),
+            ("b", vec!["a", "c"]),
+            ("c", vec!["d"]),
+            ("d", vec!["c"]),
+            ("e", vec!["a"]),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.into_iter().map(String::from).collect()))
+        .collect();
+
+        let cycles: Vec<Vec<String>> = find_cycles(&edges).into_iter().collect();
+        assert_eq!(
+            cycles,
+            vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["c".to_string(), "d".to_string()],
+            ]
+        );
+    }
+}
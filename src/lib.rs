@@ -68,6 +68,18 @@
 //! }
 //! ```
 
+/// The `auditlog` module contains the `AuditLogEntry` struct and the `AuditLogQuery` used to query
+/// the audit log of an Opsview instance.
+pub mod auditlog;
+
+/// The `bsm` module contains the `BSMSimulator` used to work out the operational state of BSM
+/// components and services offline.
+pub mod bsm;
+
+/// The `checkargs` module contains the `CheckArgs` tokenizer used to read, validate and rewrite the
+/// warning and critical thresholds in the arguments of service checks.
+pub mod checkargs;
+
 /// The `client` module contains the `OpsviewClient` struct and methods for interacting with the
 /// Opsview API using this Client.
 pub mod client;
@@ -76,18 +88,70 @@ pub mod client;
 /// via the REST API /config endpoint.
 pub mod config;
 
+/// The `effective` module contains the `ServiceCheckResolver` used to work out which service checks
+/// run on a host, and why.
+pub mod effective;
+
 /// The `error` module contains the `OpsviewClientError` enum , the `OpsviewConfigError`, and
 /// methods for handling errors
 pub mod error;
 
+/// The `event` module contains the `Event` struct and the `EventQuery` used to query the event
+/// history of an Opsview instance.
+pub mod event;
+
+/// The `expansion` module contains the `MacroExpander` used to expand the Opsview macros in
+/// service check arguments for a host.
+pub mod expansion;
+
+/// The `graph` module contains the types used to retrieve performance data stored by Opsview for
+/// graphing.
+pub mod graph;
+
+/// The `hostgrouptree` module contains the `HostGroupTree` struct used to navigate and reshape the
+/// host group hierarchy of an Opsview instance by path.
+pub mod hostgrouptree;
+
 /// The `instance` module contains the `OpsviewInstance` struct and methods for interacting with
 /// an Opsview instance at large.
 pub mod instance;
 
+/// The `integrity` module contains the `IntegrityFinding` struct returned when checking the
+/// references between the objects of an `OpsviewInstance`.
+pub mod integrity;
+
+/// The `notification` module contains the `NotificationSimulator` used to work out which contacts
+/// and notification methods a host or service check notification would be routed to.
+pub mod notification;
+
+/// The `opspack` module contains the `Opspack` struct used to read, write and compare Opspack
+/// archives offline.
+pub mod opspack;
+
+/// The `perfdata` module contains a parser and formatter for Nagios performance data, and functions
+/// for evaluating values against thresholds.
+pub mod perfdata;
+
+/// The `permissions` module contains the `PermissionEvaluator` used to work out what roles and
+/// contacts are allowed to see and do.
+pub mod permissions;
+
 /// The `prelude` module contains the most commonly used types and traits from the `opsview` crate.
 pub mod prelude;
 pub use prelude::*;
 
+/// The `rbac` module contains the `RbacMatrix` report of contacts, roles and their accesses, for
+/// access reviews and compliance audits.
+pub mod rbac;
+
+/// The `reload` module contains the `ReloadStatus` struct and the types used to report the progress
+/// and outcome of applying configuration changes.
+pub mod reload;
+
+/// The `testcheck` module contains the `ServiceCheckTest` struct used to test-run a plugin against a
+/// host before saving a service check.
+pub mod testcheck;
+
 /// The `util` module contains utility functions and types used throughout the `opsview` crate.
 pub mod util;
 
@@ -0,0 +1,1000 @@
+use crate::permissions::{tags_host, tags_service_check, PermissionEvaluator};
+use crate::{
+    config::*,
+    instance::OpsviewInstance,
+    prelude::*,
+    state::{HostState, ServiceCheckState},
+};
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::sync::Arc;
+
+/// The state change of a host or service check to simulate notifications for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NotificationState {
+    /// A host changed to this state.
+    Host(HostState),
+    /// A service check changed to this state.
+    Service(ServiceCheckState),
+}
+
+impl NotificationState {
+    /// Returns the letter used in notification options for this state, e.g. `d` for a host that is
+    /// `Down` and `r` for a recovery.
+    pub fn option(&self) -> char {
+        match self {
+            NotificationState::Host(HostState::Up) => 'r',
+            NotificationState::Host(HostState::Down) => 'd',
+            NotificationState::Host(HostState::Unreachable) => 'u',
+            NotificationState::Service(ServiceCheckState::Ok) => 'r',
+            NotificationState::Service(ServiceCheckState::Warning) => 'w',
+            NotificationState::Service(ServiceCheckState::Critical) => 'c',
+            NotificationState::Service(ServiceCheckState::Unknown) => 'u',
+        }
+    }
+
+    /// Returns the host notification option for this state, or `None` for a service check state.
+    pub fn host_option(&self) -> Option<HostNotificationOption> {
+        match self {
+            NotificationState::Host(HostState::Up) => Some(HostNotificationOption::Recovery),
+            NotificationState::Host(HostState::Down) => Some(HostNotificationOption::Down),
+            NotificationState::Host(HostState::Unreachable) => {
+                Some(HostNotificationOption::Unreachable)
+            }
+            NotificationState::Service(_) => None,
+        }
+    }
+
+    /// Returns the service check notification option for this state, or `None` for a host state.
+    pub fn service_option(&self) -> Option<ServiceCheckNotificationOption> {
+        match self {
+            NotificationState::Service(ServiceCheckState::Ok) => {
+                Some(ServiceCheckNotificationOption::Recovery)
+            }
+            NotificationState::Service(ServiceCheckState::Warning) => {
+                Some(ServiceCheckNotificationOption::Warning)
+            }
+            NotificationState::Service(ServiceCheckState::Critical) => {
+                Some(ServiceCheckNotificationOption::Critical)
+            }
+            NotificationState::Service(ServiceCheckState::Unknown) => {
+                Some(ServiceCheckNotificationOption::Unknown)
+            }
+            NotificationState::Host(_) => None,
+        }
+    }
+}
+
+/// A host or service check notification event to route to contacts.
+///
+/// # Example
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use opsview::notification::NotificationEvent;
+/// use opsview::state::ServiceCheckState;
+///
+/// let at = Utc.with_ymd_and_hms(2024, 3, 4, 10, 0, 0).unwrap();
+/// let event = NotificationEvent::service("web01", "HTTP", ServiceCheckState::Critical, at)
+///     .notification_number(3);
+///
+/// assert_eq!(event.host, "web01");
+/// assert_eq!(event.notification_number, 3);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationEvent {
+    /// The name of the host.
+    pub host: String,
+    /// The name of the service on the host, for service check events.
+    pub service: Option<String>,
+    /// The new state of the host or service check.
+    pub state: NotificationState,
+    /// The time of the notification.
+    pub at: DateTime<Utc>,
+    /// The number of the notification for this problem, starting at 1.
+    pub notification_number: u64,
+}
+
+impl NotificationEvent {
+    /// Creates a new event for a host changing state.
+    ///
+    /// # Arguments
+    /// * `host` - The name of the host.
+    /// * `state` - The new `HostState` of the host.
+    /// * `at` - The time of the notification.
+    pub fn host(host: &str, state: HostState, at: DateTime<Utc>) -> Self {
+        NotificationEvent {
+            host: host.to_string(),
+            service: None,
+            state: NotificationState::Host(state),
+            at,
+            notification_number: 1,
+        }
+    }
+
+    /// Creates a new event for a service check changing state.
+    ///
+    /// # Arguments
+    /// * `host` - The name of the host.
+    /// * `service` - The name of the service on the host, as listed by the
+    ///   [`ServiceCheckResolver`](crate::effective::ServiceCheckResolver).
+    /// * `state` - The new `ServiceCheckState` of the service check.
+    /// * `at` - The time of the notification.
+    pub fn service(host: &str, service: &str, state: ServiceCheckState, at: DateTime<Utc>) -> Self {
+        NotificationEvent {
+            host: host.to_string(),
+            service: Some(service.to_string()),
+            state: NotificationState::Service(state),
+            at,
+            notification_number: 1,
+        }
+    }
+
+    /// Sets the number of the notification for this problem, which is matched against the
+    /// notification levels of the profiles. Defaults to 1.
+    pub fn notification_number(mut self, number: u64) -> Self {
+        self.notification_number = number;
+        self
+    }
+}
+
+/// A notification that would be sent to a contact by a notification method.
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct Notification {
+    /// The name of the contact.
+    pub contact: String,
+    /// The name of the notification profile that matched.
+    pub profile: String,
+    /// Whether the profile is a `SharedNotificationProfile`.
+    pub shared: bool,
+    /// The name of the notification method.
+    pub method: String,
+}
+
+impl fmt::Display for Notification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} via {} ({}profile {})",
+            self.contact,
+            self.method,
+            if self.shared { "shared " } else { "" },
+            self.profile
+        )
+    }
+}
+
+/// The reason a notification is not sent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExclusionReason {
+    /// The notification options of the host or service check do not include the state.
+    ObjectOptions {
+        /// The notification options of the object.
+        options: String,
+        /// The notification option of the state.
+        option: char,
+    },
+    /// The time is outside the notification period of the host or service check.
+    ObjectPeriod(String),
+    /// A notification period could not be found in the instance.
+    TimePeriodNotFound(String),
+    /// A notification period could not be evaluated, with the name of the period and the error.
+    InvalidTimePeriod(String, String),
+    /// The contact has no role, or its role could not be found in the instance.
+    NoRole,
+    /// The role of the contact cannot see the host or service check.
+    NotVisible,
+    /// The contact has no notification profiles.
+    NoProfiles,
+    /// A shared notification profile of the contact could not be found in the instance.
+    ProfileNotFound,
+    /// The host or service check is not covered by the host groups, service groups or hashtags of
+    /// the profile.
+    OutOfScope,
+    /// The notification options of the profile do not include the state.
+    Options {
+        /// The notification options of the profile.
+        options: String,
+        /// The notification option of the state.
+        option: char,
+    },
+    /// The time is outside the notification period of the profile.
+    Period(String),
+    /// The notification number is outside the notification levels of the profile.
+    Level {
+        /// The notification number of the event.
+        number: u64,
+        /// The notification level at which the profile starts notifying.
+        start: u64,
+        /// The notification level after which the profile stops notifying, 0 meaning never.
+        stop: u64,
+    },
+    /// The profile has no notification methods.
+    NoMethods,
+    /// A notification method of the profile could not be found in the instance.
+    MethodNotFound,
+    /// The notification method is not active.
+    MethodInactive,
+}
+
+impl fmt::Display for ExclusionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExclusionReason::ObjectOptions { options, option } => write!(
+                f,
+                "the notification options '{}' of the object do not include '{}'",
+                options, option
+            ),
+            ExclusionReason::ObjectPeriod(period) => write!(
+                f,
+                "outside the notification period '{}' of the object",
+                period
+            ),
+            ExclusionReason::TimePeriodNotFound(period) => {
+                write!(f, "time period '{}' not found", period)
+            }
+            ExclusionReason::InvalidTimePeriod(period, error) => {
+                write!(f, "time period '{}' is invalid: {}", period, error)
+            }
+            ExclusionReason::NoRole => write!(f, "the contact has no role"),
+            ExclusionReason::NotVisible => {
+                write!(f, "the role of the contact cannot see the object")
+            }
+            ExclusionReason::NoProfiles => write!(f, "the contact has no notification profiles"),
+            ExclusionReason::ProfileNotFound => write!(f, "shared notification profile not found"),
+            ExclusionReason::OutOfScope => write!(
+                f,
+                "the object is not in the host groups, service groups or hashtags of the profile"
+            ),
+            ExclusionReason::Options { options, option } => write!(
+                f,
+                "the notification options '{}' of the profile do not include '{}'",
+                options, option
+            ),
+            ExclusionReason::Period(period) => write!(
+                f,
+                "outside the notification period '{}' of the profile",
+                period
+            ),
+            ExclusionReason::Level {
+                number,
+                start,
+                stop,
+            } => {
+                if *stop == 0 {
+                    write!(
+                        f,
+                        "notification {} is before notification level {}",
+                        number, start
+                    )
+                } else {
+                    write!(
+                        f,
+                        "notification {} is outside notification levels {} to {}",
+                        number, start, stop
+                    )
+                }
+            }
+            ExclusionReason::NoMethods => write!(f, "the profile has no notification methods"),
+            ExclusionReason::MethodNotFound => write!(f, "notification method not found"),
+            ExclusionReason::MethodInactive => write!(f, "the notification method is not active"),
+        }
+    }
+}
+
+/// A contact, profile or notification method that does not receive a notification, and why.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Exclusion {
+    /// The name of the contact.
+    pub contact: String,
+    /// The name of the notification profile, if the exclusion is specific to one.
+    pub profile: Option<String>,
+    /// The name of the notification method, if the exclusion is specific to one.
+    pub method: Option<String>,
+    /// The reason for the exclusion.
+    pub reason: ExclusionReason,
+}
+
+impl fmt::Display for Exclusion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.contact)?;
+        if let Some(profile) = &self.profile {
+            write!(f, " (profile {})", profile)?;
+        }
+        if let Some(method) = &self.method {
+            write!(f, " via {}", method)?;
+        }
+        write!(f, ": {}", self.reason)
+    }
+}
+
+/// The result of simulating a [`NotificationEvent`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NotificationSimulation {
+    /// Set if the host or service check itself suppresses the notification, in which case no
+    /// contacts are considered.
+    pub suppressed: Option<ExclusionReason>,
+    /// The notifications that would be sent, ordered by contact, profile and method.
+    pub notifications: Vec<Notification>,
+    /// The contacts, profiles and methods that would not be notified, ordered by contact.
+    pub exclusions: Vec<Exclusion>,
+}
+
+impl NotificationSimulation {
+    /// Returns `true` if any notification would be sent.
+    pub fn fires(&self) -> bool {
+        !self.notifications.is_empty()
+    }
+
+    /// Returns the names of the contacts that would be notified, ordered and without duplicates.
+    pub fn contacts(&self) -> Vec<&str> {
+        let mut contacts: Vec<&str> = self
+            .notifications
+            .iter()
+            .map(|n| n.contact.as_str())
+            .collect();
+        contacts.dedup();
+        contacts
+    }
+}
+
+impl fmt::Display for NotificationSimulation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(reason) = &self.suppressed {
+            return write!(f, "suppressed: {}", reason);
+        }
+        for notification in &self.notifications {
+            writeln!(f, "notify {}", notification)?;
+        }
+        for exclusion in &self.exclusions {
+            writeln!(f, "exclude {}", exclusion)?;
+        }
+        Ok(())
+    }
+}
+
+/// An offline simulator of how Opsview routes host and service check notifications to the contacts
+/// in an [`OpsviewInstance`].
+///
+/// A notification is sent to a contact by a notification method when all of the following hold:
+/// * The `notification_options` of the host or service check include the state, and the time is
+///   within its `notification_period`. Otherwise, nobody is notified.
+/// * The role of the contact can see the host or service check, as worked out by the
+///   [`PermissionEvaluator`].
+/// * One of the notification profiles of the contact, embedded or shared, covers the object: a
+///   host must be in one of its `hostgroups` (or a host group below them), and a service check must
+///   also be in one of its `servicegroups`. Alternatively, one of its `keywords` must tag the host
+///   and, for service check events, the service check. The `all_*` flags stand in for the lists.
+/// * The notification options of the profile include the state, the time is within its
+///   `notification_period`, and the notification number is within its notification levels.
+/// * The notification method is active.
+///
+/// Options and periods that are not set do not restrict notifications. Time periods are evaluated
+/// in their own time zone.
+///
+/// # Example
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use opsview::instance::OpsviewInstance;
+/// use opsview::notification::{NotificationEvent, NotificationSimulator};
+/// use opsview::state::HostState;
+///
+/// let instance = OpsviewInstance::default();
+/// let simulator = NotificationSimulator::new(&instance);
+///
+/// let at = Utc.with_ymd_and_hms(2024, 3, 4, 10, 0, 0).unwrap();
+/// let result = simulator.simulate(&NotificationEvent::host("web01", HostState::Down, at));
+///
+/// assert!(!result.fires());
+/// ```
+#[derive(Clone, Debug)]
+pub struct NotificationSimulator<'a> {
+    instance: &'a OpsviewInstance,
+    evaluator: PermissionEvaluator<'a>,
+}
+
+/// The fields shared by [`NotificationProfile`] and [`SharedNotificationProfile`].
+struct Profile<'p> {
+    name: &'p str,
+    shared: bool,
+    all_hostgroups: Option<bool>,
+    hostgroups: Option<&'p ConfigRefMap<HostGroupRef>>,
+    all_servicegroups: Option<bool>,
+    servicegroups: Option<&'p ConfigRefMap<ServiceGroupRef>>,
+    all_keywords: Option<bool>,
+    keywords: Option<&'p ConfigRefMap<HashtagRef>>,
+    host_notification_options: Option<&'p str>,
+    host_options: Result<Option<HostNotificationOptions>, OpsviewConfigError>,
+    service_notification_options: Option<&'p str>,
+    service_options: Result<Option<ServiceCheckNotificationOptions>, OpsviewConfigError>,
+    notification_period: Option<&'p TimePeriodRef>,
+    notification_level: Option<u64>,
+    notification_level_stop: Option<u64>,
+    /// The names of the methods with the methods embedded in the profile, if any.
+    methods: Vec<(String, Option<&'p NotificationMethod>)>,
+}
+
+impl<'p> From<&'p NotificationProfile> for Profile<'p> {
+    fn from(profile: &'p NotificationProfile) -> Self {
+        Profile {
+            name: &profile.name,
+            shared: false,
+            all_hostgroups: profile.all_hostgroups,
+            hostgroups: profile.hostgroups.as_ref(),
+            all_servicegroups: profile.all_servicegroups,
+            servicegroups: profile.servicegroups.as_ref(),
+            all_keywords: profile.all_keywords,
+            keywords: profile.keywords.as_ref(),
+            host_notification_options: profile.host_notification_options.as_deref(),
+            host_options: profile.host_notification_options_set(),
+            service_notification_options: profile.service_notification_options.as_deref(),
+            service_options: profile.service_notification_options_set(),
+            notification_period: profile.notification_period.as_ref(),
+            notification_level: profile.notification_level,
+            notification_level_stop: profile.notification_level_stop,
+            methods: profile
+                .notificationmethods
+                .iter()
+                .flat_map(|methods| methods.values())
+                .map(|method| (method.name(), None))
+                .collect(),
+        }
+    }
+}
+
+impl<'p> From<&'p SharedNotificationProfile> for Profile<'p> {
+    fn from(profile: &'p SharedNotificationProfile) -> Self {
+        Profile {
+            name: &profile.name,
+            shared: true,
+            all_hostgroups: profile.all_hostgroups,
+            hostgroups: profile.hostgroups.as_ref(),
+            all_servicegroups: profile.all_servicegroups,
+            servicegroups: profile.servicegroups.as_ref(),
+            all_keywords: profile.all_keywords,
+            keywords: profile.keywords.as_ref(),
+            host_notification_options: profile.host_notification_options.as_deref(),
+            host_options: profile.host_notification_options_set(),
+            service_notification_options: profile.service_notification_options.as_deref(),
+            service_options: profile.service_notification_options_set(),
+            notification_period: profile.notification_period.as_ref(),
+            notification_level: profile.notification_level,
+            notification_level_stop: profile.notification_level_stop,
+            methods: profile
+                .notificationmethods
+                .iter()
+                .flat_map(|methods| methods.values())
+                .map(|method| (method.name.clone(), Some(method.as_ref())))
+                .collect(),
+        }
+    }
+}
+
+impl<'a> NotificationSimulator<'a> {
+    /// Creates a new `NotificationSimulator`.
+    ///
+    /// # Arguments
+    /// * `instance` - The `OpsviewInstance` holding the contacts, profiles and monitored objects.
+    pub fn new(instance: &'a OpsviewInstance) -> Self {
+        NotificationSimulator {
+            instance,
+            evaluator: PermissionEvaluator::new(instance),
+        }
+    }
+
+    /// Simulates the routing of a notification event to the contacts of the instance.
+    ///
+    /// # Arguments
+    /// * `event` - The `NotificationEvent` to route.
+    pub fn simulate(&self, event: &NotificationEvent) -> NotificationSimulation {
+        let mut result = NotificationSimulation::default();
+
+        if let Err(reason) = self.check_object(event) {
+            result.suppressed = Some(reason);
+            return result;
+        }
+
+        let mut contacts: Vec<&Contact> = self
+            .instance
+            .contacts
+            .values()
+            .map(|contact| contact.as_ref())
+            .collect();
+        contacts.sort_by(|a, b| a.name.cmp(&b.name));
+
+        for contact in contacts {
+            self.simulate_contact(contact, event, &mut result);
+        }
+
+        result.notifications.sort();
+        result
+    }
+
+    fn simulate_contact(
+        &self,
+        contact: &Contact,
+        event: &NotificationEvent,
+        result: &mut NotificationSimulation,
+    ) {
+        let exclude = |profile: Option<&str>, method: Option<&str>, reason| Exclusion {
+            contact: contact.name.clone(),
+            profile: profile.map(str::to_string),
+            method: method.map(str::to_string),
+            reason,
+        };
+
+        if self.evaluator.role_of(contact).is_none() {
+            result
+                .exclusions
+                .push(exclude(None, None, ExclusionReason::NoRole));
+            return;
+        }
+
+        let visible = match &event.service {
+            Some(service) => self
+                .evaluator
+                .can_see_service_check(contact, &event.host, service),
+            None => self.evaluator.can_see_host(contact, &event.host),
+        };
+        if !visible {
+            result
+                .exclusions
+                .push(exclude(None, None, ExclusionReason::NotVisible));
+            return;
+        }
+
+        let mut profiles: Vec<Profile> = contact
+            .notificationprofiles
+            .iter()
+            .flat_map(|profiles| profiles.values())
+            .map(|profile| Profile::from(profile.as_ref()))
+            .collect();
+
+        for shared_ref in contact
+            .sharednotificationprofiles
+            .iter()
+            .flat_map(|profiles| profiles.values())
+        {
+            let name = shared_ref.name();
+            match self.find_shared_profile(&name) {
+                Some(shared) => profiles.push(Profile::from(shared)),
+                None => result.exclusions.push(exclude(
+                    Some(&name),
+                    None,
+                    ExclusionReason::ProfileNotFound,
+                )),
+            }
+        }
+
+        if profiles.is_empty() {
+            result
+                .exclusions
+                .push(exclude(None, None, ExclusionReason::NoProfiles));
+            return;
+        }
+
+        for profile in profiles {
+            if let Err(reason) = self.check_profile(&profile, event) {
+                result
+                    .exclusions
+                    .push(exclude(Some(profile.name), None, reason));
+                continue;
+            }
+
+            for (name, embedded) in &profile.methods {
+                let method = embedded.or_else(|| self.find_method(name));
+                let reason = match method {
+                    None => Some(ExclusionReason::MethodNotFound),
+                    Some(method) if method.active == Some(false) => {
+                        Some(ExclusionReason::MethodInactive)
+                    }
+                    Some(_) => None,
+                };
+
+                match reason {
+                    Some(reason) => {
+                        result
+                            .exclusions
+                            .push(exclude(Some(profile.name), Some(name), reason))
+                    }
+                    None => result.notifications.push(Notification {
+                        contact: contact.name.clone(),
+                        profile: profile.name.to_string(),
+                        shared: profile.shared,
+                        method: name.clone(),
+                    }),
+                }
+            }
+        }
+    }
+
+    /// Checks the notification options and period of the host or service check.
+    fn check_object(&self, event: &NotificationEvent) -> Result<(), ExclusionReason> {
+        let host = self.instance.hosts.get(&event.host);
+        let (options, included, period) = match &event.service {
+            Some(service) => {
+                let check = self.find_service_check(service);
+                (
+                    check.as_ref().and_then(|c| c.notification_options.clone()),
+                    includes(
+                        &check
+                            .as_ref()
+                            .map(|c| c.notification_options_set())
+                            .transpose()
+                            .map(Option::flatten),
+                        event.state.service_option(),
+                    ),
+                    check.as_ref().and_then(|c| c.notification_period.clone()),
+                )
+            }
+            None => (
+                host.as_ref().and_then(|h| h.notification_options.clone()),
+                includes(
+                    &host
+                        .as_ref()
+                        .map(|h| h.notification_options_set())
+                        .transpose()
+                        .map(Option::flatten),
+                    event.state.host_option(),
+                ),
+                host.as_ref().and_then(|h| h.notification_period.clone()),
+            ),
+        };
+
+        if !included {
+            return Err(ExclusionReason::ObjectOptions {
+                options: options.unwrap_or_default(),
+                option: event.state.option(),
+            });
+        }
+
+        if let Some(period) = period {
+            if !self.period_active(&period.name(), event.at)? {
+                return Err(ExclusionReason::ObjectPeriod(period.name()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the scope, options, period and levels of a notification profile.
+    fn check_profile(
+        &self,
+        profile: &Profile,
+        event: &NotificationEvent,
+    ) -> Result<(), ExclusionReason> {
+        if !self.in_scope(profile, event) {
+            return Err(ExclusionReason::OutOfScope);
+        }
+
+        let (options, included) = match event.service {
+            Some(_) => (
+                profile.service_notification_options,
+                includes(&profile.service_options, event.state.service_option()),
+            ),
+            None => (
+                profile.host_notification_options,
+                includes(&profile.host_options, event.state.host_option()),
+            ),
+        };
+        if !included {
+            return Err(ExclusionReason::Options {
+                options: options.unwrap_or_default().to_string(),
+                option: event.state.option(),
+            });
+        }
+
+        if let Some(period) = profile.notification_period {
+            if !self.period_active(&period.name(), event.at)? {
+                return Err(ExclusionReason::Period(period.name()));
+            }
+        }
+
+        let number = event.notification_number;
+        let start = profile.notification_level.unwrap_or(1);
+        let stop = profile.notification_level_stop.unwrap_or(0);
+        if number < start || (stop != 0 && number > stop) {
+            return Err(ExclusionReason::Level {
+                number,
+                start,
+                stop,
+            });
+        }
+
+        if profile.methods.is_empty() {
+            return Err(ExclusionReason::NoMethods);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the profile covers the host or service check of the event.
+    fn in_scope(&self, profile: &Profile, event: &NotificationEvent) -> bool {
+        let host = self.instance.hosts.get(&event.host);
+        let service_check = event
+            .service
+            .as_deref()
+            .and_then(|service| self.find_service_check(service));
+
+        let in_hostgroups = profile.all_hostgroups == Some(true)
+            || profile
+                .hostgroups
+                .iter()
+                .flat_map(|groups| groups.values())
+                .filter_map(|group| self.evaluator.tree().path_of_ref(group))
+                .any(|path| {
+                    self.evaluator
+                        .tree()
+                        .subtree_hosts(&path)
+                        .contains(&event.host.as_str())
+                });
+
+        let in_servicegroups = event.service.is_none()
+            || profile.all_servicegroups == Some(true)
+            || service_check
+                .as_ref()
+                .and_then(|check| check.servicegroup.as_ref())
+                .is_some_and(|group| {
+                    profile
+                        .servicegroups
+                        .iter()
+                        .flat_map(|groups| groups.values())
+                        .any(|g| g.name() == group.name())
+                });
+
+        if in_hostgroups && in_servicegroups {
+            return true;
+        }
+
+        let keywords: Vec<String> = profile
+            .keywords
+            .iter()
+            .flat_map(|keywords| keywords.values().map(|k| k.name()))
+            .collect();
+
+        self.instance
+            .hashtags
+            .values()
+            .filter(|hashtag| {
+                profile.all_keywords == Some(true) || keywords.contains(&hashtag.name)
+            })
+            .any(|hashtag| {
+                tags_host(hashtag, &event.host, host.as_deref())
+                    && match (&event.service, &service_check) {
+                        (None, _) => true,
+                        (Some(_), Some(check)) => {
+                            tags_service_check(hashtag, &check.name, Some(check))
+                        }
+                        (Some(service), None) => tags_service_check(hashtag, service, None),
+                    }
+            })
+    }
+
+    /// Returns the service check of a service, whose name may carry a variable value after `: `.
+    fn find_service_check(&self, service: &str) -> Option<Arc<ServiceCheck>> {
+        self.instance.service_checks.get(service).or_else(|| {
+            service
+                .split_once(": ")
+                .and_then(|(name, _)| self.instance.service_checks.get(name))
+        })
+    }
+
+    fn find_shared_profile(&self, name: &str) -> Option<&'a SharedNotificationProfile> {
+        self.instance
+            .shared_notification_profiles
+            .values()
+            .find(|profile| profile.name == name)
+            .map(|profile| profile.as_ref())
+    }
+
+    fn find_method(&self, name: &str) -> Option<&'a NotificationMethod> {
+        self.instance
+            .notification_methods
+            .values()
+            .find(|method| method.name == name)
+            .map(|method| method.as_ref())
+    }
+
+    /// Returns whether a time period in the instance is active at a given time.
+    fn period_active(&self, name: &str, at: DateTime<Utc>) -> Result<bool, ExclusionReason> {
+        let period = self
+            .instance
+            .time_periods
+            .get(name)
+            .ok_or_else(|| ExclusionReason::TimePeriodNotFound(name.to_string()))?;
+
+        period
+            .is_active_at(at)
+            .map_err(|e| ExclusionReason::InvalidTimePeriod(name.to_string(), e.to_string()))
+    }
+}
+
+/// Returns `true` if a set of notification options includes an option. Options that are not set
+/// include every option, and options that are not valid include none.
+fn includes<F: OptionFlag>(
+    options: &Result<Option<OptionFlags<F>>, OpsviewConfigError>,
+    option: Option<F>,
+) -> bool {
+    match options {
+        Ok(None) => true,
+        Ok(Some(options)) => option.is_some_and(|option| options.contains(option)),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn instance() -> OpsviewInstance {
+        OpsviewInstance::from_fixture(json!({
+            "host_groups": [
+                {"name": "Opsview", "matpath": "Opsview,"},
+                {"name": "EU", "matpath": "Opsview,EU,"},
+                {"name": "US", "matpath": "Opsview,US,"}
+            ],
+            "hosts": [
+                {
+                    "name": "web01",
+                    "hostgroup": {"name": "EU", "matpath": "Opsview,EU,"},
+                    "servicechecks": [{"name": "HTTP"}],
+                    "notification_options": "d,r"
+                },
+                {
+                    "name": "us01",
+                    "hostgroup": {"name": "US", "matpath": "Opsview,US,"},
+                    "servicechecks": [{"name": "HTTP"}],
+                    "notification_options": "d,r"
+                }
+            ],
+            "service_checks": [{"name": "HTTP", "servicegroup": {"name": "Web"}}],
+            "time_periods": [{"name": "workhours", "monday": "09:00-17:00"}],
+            "notification_methods": [
+                {"name": "Email", "active": "1"},
+                {"name": "SMS", "active": "0"}
+            ],
+            "roles": [{"name": "Operators", "accesses": [{"name": "VIEWALL"}]}],
+            "shared_notification_profiles": [{
+                "name": "US on call",
+                "hostgroups": [{"name": "US", "matpath": "Opsview,US,"}],
+                "all_servicegroups": "1",
+                "notificationmethods": [{"name": "Email", "active": "1"}]
+            }],
+            "contacts": [
+                {
+                    "name": "alice",
+                    "role": {"name": "Operators"},
+                    "notificationprofiles": [{
+                        "name": "EU daytime",
+                        "hostgroups": [{"name": "EU", "matpath": "Opsview,EU,"}],
+                        "servicegroups": [{"name": "Web"}],
+                        "host_notification_options": "d",
+                        "notification_period": {"name": "workhours"},
+                        "notificationmethods": [{"name": "Email"}, {"name": "SMS"}]
+                    }]
+                },
+                {
+                    "name": "bob",
+                    "role": {"name": "Operators"},
+                    "sharednotificationprofiles": [{"name": "US on call"}]
+                },
+                {"name": "carol"}
+            ]
+        }))
+    }
+
+    fn monday(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 4, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_routing() {
+        let instance = instance();
+        let simulator = NotificationSimulator::new(&instance);
+
+        let result = simulator.simulate(&NotificationEvent::host(
+            "web01",
+            HostState::Down,
+            monday(10),
+        ));
+        assert_eq!(
+            result.notifications,
+            vec![Notification {
+                contact: "alice".to_string(),
+                profile: "EU daytime".to_string(),
+                shared: false,
+                method: "Email".to_string(),
+            }]
+        );
+        assert!(result.exclusions.contains(&Exclusion {
+            contact: "alice".to_string(),
+            profile: Some("EU daytime".to_string()),
+            method: Some("SMS".to_string()),
+            reason: ExclusionReason::MethodInactive,
+        }));
+        assert!(result.exclusions.contains(&Exclusion {
+            contact: "bob".to_string(),
+            profile: Some("US on call".to_string()),
+            method: None,
+            reason: ExclusionReason::OutOfScope,
+        }));
+        assert!(result.exclusions.contains(&Exclusion {
+            contact: "carol".to_string(),
+            profile: None,
+            method: None,
+            reason: ExclusionReason::NoRole,
+        }));
+
+        let result = simulator.simulate(&NotificationEvent::service(
+            "us01",
+            "HTTP",
+            ServiceCheckState::Critical,
+            monday(22),
+        ));
+        assert_eq!(result.contacts(), vec!["bob"]);
+        assert!(result.notifications[0].shared);
+    }
+
+    #[test]
+    fn test_exclusions() {
+        let instance = instance();
+        let simulator = NotificationSimulator::new(&instance);
+
+        let result = simulator.simulate(&NotificationEvent::host(
+            "web01",
+            HostState::Unreachable,
+            monday(10),
+        ));
+        assert!(!result.fires());
+        assert_eq!(
+            result.suppressed,
+            Some(ExclusionReason::ObjectOptions {
+                options: "d,r".to_string(),
+                option: 'u',
+            })
+        );
+
+        let result = simulator.simulate(&NotificationEvent::host(
+            "web01",
+            HostState::Down,
+            monday(18),
+        ));
+        let alice: Vec<&Exclusion> = result
+            .exclusions
+            .iter()
+            .filter(|e| e.contact == "alice")
+            .collect();
+        assert_eq!(
+            alice[0].reason,
+            ExclusionReason::Period("workhours".to_string())
+        );
+
+        let result =
+            simulator.simulate(&NotificationEvent::host("web01", HostState::Up, monday(10)));
+        assert!(result.exclusions.iter().any(|e| e.reason
+            == ExclusionReason::Options {
+                options: "d".to_string(),
+                option: 'r'
+            }));
+        assert_eq!(
+            result.to_string().lines().last().unwrap(),
+            "exclude carol: the contact has no role"
+        );
+    }
+
+    #[test]
+    fn test_includes() {
+        let critical = NotificationState::Service(ServiceCheckState::Critical);
+        let options = |s: &str| s.parse::<ServiceCheckNotificationOptions>().map(Some);
+
+        assert!(includes(&options("w, c,r"), critical.service_option()));
+        assert!(!includes(&options("n"), critical.service_option()));
+        assert!(!includes(&options("x"), critical.service_option()));
+        assert!(includes(&Ok(None), critical.service_option()));
+        assert!(!includes(
+            &Ok(Some(HostNotificationOptions::all())),
+            critical.host_option()
+        ));
+    }
+}
@@ -0,0 +1,994 @@
+use crate::{config::*, instance::OpsviewInstance, prelude::*};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::io::{Read, Write};
+use std::path::{Component, Path};
+
+/// The name of the file in an Opspack archive containing the configuration objects.
+const CONFIG_FILE: &str = "config.json";
+
+/// The name of the file in an Opspack archive containing the metadata of the Opspack.
+const INFO_FILE: &str = "info";
+
+/// The name of the directory in an Opspack archive containing the plugin executables.
+const PLUGINS_DIR: &str = "plugins";
+
+/// The keys used for each object type in the `config.json` file of an Opspack.
+const HOST_TEMPLATE_KEY: &str = "hosttemplate";
+const PLUGIN_KEY: &str = "plugin";
+const SERVICE_CHECK_KEY: &str = "servicecheck";
+const VARIABLE_KEY: &str = "attribute";
+
+/// The metadata of an [`Opspack`], as stored in the `info` file of the archive.
+///
+/// The `info` file consists of `KEY=VALUE` lines. `NAME`, `VERSION` and `DESCRIPTION` are mapped
+/// to their own fields, and all other keys are preserved in `extra`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OpspackInfo {
+    /// The name of the Opspack, e.g. `application-apache`.
+    pub name: String,
+    /// The version of the Opspack, e.g. `2.1`.
+    pub version: Option<String>,
+    /// A description of the Opspack.
+    pub description: Option<String>,
+    /// Any other keys found in the `info` file.
+    pub extra: BTreeMap<String, String>,
+}
+
+impl OpspackInfo {
+    /// Creates a new `OpspackInfo` with the given name and version.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError::RequiredFieldEmpty` if the name is empty, or an
+    /// `OpsviewConfigError::InvalidOpspack` if the name contains characters that are not allowed
+    /// in the name of an archive directory.
+    pub fn new(name: &str, version: &str) -> Result<Self, OpsviewConfigError> {
+        validate_opspack_name(name)?;
+        Ok(OpspackInfo {
+            name: name.to_string(),
+            version: Some(version.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Parses the contents of an `info` file.
+    ///
+    /// Empty lines and lines starting with `#` are ignored.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError::InvalidOpspack` if a line is not of the form `KEY=VALUE`
+    /// or if the `NAME` key is missing.
+    pub fn parse(contents: &str) -> Result<Self, OpsviewConfigError> {
+        let mut info = OpspackInfo::default();
+        let mut name = None;
+
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(|| {
+                OpsviewConfigError::InvalidOpspack(format!("Invalid line in info file: '{}'", line))
+            })?;
+            let value = value.trim().to_string();
+
+            match key.trim() {
+                "NAME" => name = Some(value),
+                "VERSION" => info.version = Some(value),
+                "DESCRIPTION" => info.description = Some(value),
+                other => {
+                    info.extra.insert(other.to_string(), value);
+                }
+            }
+        }
+
+        info.name = name.ok_or_else(|| {
+            OpsviewConfigError::InvalidOpspack("Missing NAME in info file".to_string())
+        })?;
+        validate_opspack_name(&info.name)?;
+
+        Ok(info)
+    }
+}
+
+impl fmt::Display for OpspackInfo {
+    /// Formats the `OpspackInfo` as the contents of an `info` file.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "NAME={}", self.name)?;
+        if let Some(version) = &self.version {
+            writeln!(f, "VERSION={}", version)?;
+        }
+        if let Some(description) = &self.description {
+            writeln!(f, "DESCRIPTION={}", description)?;
+        }
+        for (key, value) in &self.extra {
+            writeln!(f, "{}={}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Represents an Opspack, a bundle of monitoring configuration that can be imported into Opsview.
+///
+/// An Opspack archive is a gzipped tarball containing a single top level directory named after
+/// the Opspack, which holds an `info` file, a `config.json` file with the configuration objects,
+/// and a `plugins` directory with the executables of any plugins shipped with the Opspack.
+///
+/// The objects are held in the same [`ConfigObjectMap`]s as used by [`OpsviewInstance`], so the
+/// contents of an `Opspack` can be inspected and modified offline before being written back to an
+/// archive.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::HostTemplate;
+/// use opsview::opspack::{Opspack, OpspackInfo};
+/// use opsview::prelude::*;
+///
+/// let mut opspack = Opspack::new(OpspackInfo::new("application-example", "1.0").unwrap());
+/// opspack
+///     .host_templates
+///     .add(HostTemplate::minimal("Application - Example").unwrap());
+/// opspack.add_plugin_file("check_example", b"#!/bin/sh\necho OK\n".to_vec());
+///
+/// let archive = opspack.to_bytes().unwrap();
+/// let read_back = Opspack::from_bytes(&archive).unwrap();
+///
+/// assert_eq!(opspack, read_back);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Opspack {
+    /// The metadata of the Opspack.
+    pub info: OpspackInfo,
+    /// The host templates in the Opspack.
+    pub host_templates: ConfigObjectMap<HostTemplate>,
+    /// The service checks in the Opspack.
+    pub service_checks: ConfigObjectMap<ServiceCheck>,
+    /// The plugins in the Opspack.
+    pub plugins: ConfigObjectMap<Plugin>,
+    /// The executables of the plugins shipped with the Opspack, keyed by plugin name.
+    pub plugin_files: BTreeMap<String, Vec<u8>>,
+    /// The variables in the Opspack.
+    pub variables: ConfigObjectMap<Variable>,
+    /// Any other sections of `config.json` that are not modelled by this struct, such as service
+    /// groups or hashtags. These are preserved as-is when the Opspack is written.
+    pub other_config: BTreeMap<String, Value>,
+}
+
+impl Opspack {
+    /// Creates a new, empty `Opspack` with the given metadata.
+    pub fn new(info: OpspackInfo) -> Self {
+        Opspack {
+            info,
+            ..Default::default()
+        }
+    }
+
+    /// Adds the executable of a plugin to the Opspack.
+    ///
+    /// A [`Plugin`] object with the same name is added to `plugins` if it does not already exist.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the plugin, which is also used as its file name.
+    /// * `contents` - The contents of the plugin executable.
+    pub fn add_plugin_file(&mut self, name: &str, contents: Vec<u8>) {
+        if !self.plugins.contains(name) {
+            self.plugins.add(Plugin {
+                name: name.to_string(),
+                ..Default::default()
+            });
+        }
+        self.plugin_files.insert(name.to_string(), contents);
+    }
+
+    /// Builds an `Opspack` from a subset of an [`OpsviewInstance`].
+    ///
+    /// The given host templates and service checks are included along with everything they
+    /// depend on: the service checks of each host template, and the plugin and variable of each
+    /// service check. Instance specific fields, such as IDs, refs and the hosts using an object,
+    /// are cleared.
+    ///
+    /// Since the instance does not hold the plugin executables, `plugin_files` is left empty. Use
+    /// [`Opspack::add_plugin_file`] to add them.
+    ///
+    /// # Arguments
+    /// * `instance` - The instance to take the objects from.
+    /// * `info` - The metadata of the new Opspack.
+    /// * `host_templates` - The names of the host templates to include.
+    /// * `service_checks` - The names of any additional service checks to include.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError::InvalidOpspack` if any of the named objects, or any of
+    /// their dependencies, cannot be found in the instance.
+    pub fn from_instance(
+        instance: &OpsviewInstance,
+        info: OpspackInfo,
+        host_templates: &[&str],
+        service_checks: &[&str],
+    ) -> Result<Self, OpsviewConfigError> {
+        let not_found = |kind: &str, name: &str| {
+            OpsviewConfigError::InvalidOpspack(format!("{} '{}' not found in instance", kind, name))
+        };
+
+        let mut opspack = Opspack::new(info);
+        let mut service_check_names: BTreeSet<String> =
+            service_checks.iter().map(|s| s.to_string()).collect();
+
+        for name in host_templates {
+            let mut template = (*instance
+                .host_templates
+                .get(name)
+                .ok_or_else(|| not_found("Host template", name))?)
+            .clone();
+
+            if let Some(checks) = &template.servicechecks {
+                service_check_names.extend(checks.keys().cloned());
+            }
+
+            template.clear_readonly();
+            template.hosts = None;
+            opspack.host_templates.add(template);
+        }
+
+        for name in &service_check_names {
+            let mut check = (*instance
+                .service_checks
+                .get(name)
+                .ok_or_else(|| not_found("Service check", name))?)
+            .clone();
+
+            if let Some(plugin) = &check.plugin {
+                let mut plugin = instance
+                    .plugins
+                    .get(&plugin.name)
+                    .map(|p| (*p).clone())
+                    .unwrap_or_else(|| plugin.clone());
+                plugin.hostcheckcommands = None;
+                plugin.servicechecks = None;
+                plugin.uncommitted = None;
+                opspack.plugins.add(plugin);
+            }
+
+            // Variables are keyed by name and ID in an instance, so look them up by name alone.
+            if let Some(variable) = &check.attribute {
+                let name = variable.name();
+                let mut variable = (**instance
+                    .variables
+                    .values()
+                    .find(|v| v.name == name)
+                    .ok_or_else(|| not_found("Variable", &name))?)
+                .clone();
+                variable.clear_readonly();
+                opspack.variables.add(variable);
+            }
+
+            check.clear_readonly();
+            check.hosts = None;
+            opspack.service_checks.add(check);
+        }
+
+        Ok(opspack)
+    }
+
+    /// Reads an `Opspack` from a gzipped tarball.
+    ///
+    /// # Arguments
+    /// * `reader` - A reader yielding the `.tar.gz` archive.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError::InvalidOpspack` if the archive cannot be read, if it does
+    /// not contain an `info` and a `config.json` file, or if any of the objects in `config.json`
+    /// cannot be parsed.
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, OpsviewConfigError> {
+        let mut archive = tar::Archive::new(GzDecoder::new(reader));
+        let mut files: BTreeMap<Vec<String>, Vec<u8>> = BTreeMap::new();
+
+        for entry in archive.entries().map_err(archive_error)? {
+            let mut entry = entry.map_err(archive_error)?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let path = entry.path().map_err(archive_error)?;
+            let components = path
+                .components()
+                .filter_map(|c| match c {
+                    Component::Normal(s) => Some(Ok(s.to_string_lossy().to_string())),
+                    Component::CurDir => None,
+                    _ => Some(Err(OpsviewConfigError::InvalidOpspack(format!(
+                        "Invalid path in archive: '{}'",
+                        path.display()
+                    )))),
+                })
+                .collect::<Result<Vec<String>, _>>()?;
+
+            let mut contents = Vec::new();
+            entry.read_to_end(&mut contents).map_err(archive_error)?;
+            files.insert(components, contents);
+        }
+
+        // Opspacks normally have a single top level directory, but accept flat archives as well.
+        let prefix_len = match files.keys().next() {
+            Some(first) if first.len() > 1 => {
+                let top = &first[0];
+                if files.keys().all(|k| k.len() > 1 && &k[0] == top) {
+                    1
+                } else {
+                    0
+                }
+            }
+            _ => 0,
+        };
+
+        let mut info = None;
+        let mut config = None;
+        let mut plugin_files = BTreeMap::new();
+
+        for (path, contents) in files {
+            match &path[prefix_len..] {
+                [file] if file == INFO_FILE => {
+                    let contents = String::from_utf8(contents).map_err(|_| {
+                        OpsviewConfigError::InvalidOpspack("info file is not UTF-8".to_string())
+                    })?;
+                    info = Some(OpspackInfo::parse(&contents)?);
+                }
+                [file] if file == CONFIG_FILE => {
+                    let value: Map<String, Value> =
+                        serde_json::from_slice(&contents).map_err(|e| {
+                            OpsviewConfigError::InvalidOpspack(format!(
+                                "Unable to parse {}: {}",
+                                CONFIG_FILE, e
+                            ))
+                        })?;
+                    config = Some(value);
+                }
+                [dir, file] if dir == PLUGINS_DIR => {
+                    plugin_files.insert(file.clone(), contents);
+                }
+                _ => {}
+            }
+        }
+
+        let info = info.ok_or_else(|| {
+            OpsviewConfigError::InvalidOpspack(format!("Missing {} file", INFO_FILE))
+        })?;
+        let mut config = config.ok_or_else(|| {
+            OpsviewConfigError::InvalidOpspack(format!("Missing {} file", CONFIG_FILE))
+        })?;
+
+        let mut opspack = Opspack {
+            info,
+            host_templates: take_section(&mut config, HOST_TEMPLATE_KEY)?,
+            service_checks: take_section(&mut config, SERVICE_CHECK_KEY)?,
+            plugins: take_section(&mut config, PLUGIN_KEY)?,
+            plugin_files: BTreeMap::new(),
+            variables: take_section(&mut config, VARIABLE_KEY)?,
+            other_config: config.into_iter().collect(),
+        };
+
+        for (name, contents) in plugin_files {
+            opspack.add_plugin_file(&name, contents);
+        }
+
+        Ok(opspack)
+    }
+
+    /// Reads an `Opspack` from the bytes of a gzipped tarball.
+    ///
+    /// See [`Opspack::from_reader`] for details.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OpsviewConfigError> {
+        Opspack::from_reader(bytes)
+    }
+
+    /// Reads an `Opspack` from a `.tar.gz` file.
+    ///
+    /// See [`Opspack::from_reader`] for details.
+    pub fn read_from_path<P: AsRef<Path>>(path: P) -> Result<Self, OpsviewConfigError> {
+        let file = std::fs::File::open(path).map_err(archive_error)?;
+        Opspack::from_reader(std::io::BufReader::new(file))
+    }
+
+    /// Returns the `config.json` file of the Opspack.
+    ///
+    /// Objects are sorted by name to keep the output stable between writes.
+    pub fn config_json(&self) -> Result<Value, OpsviewConfigError> {
+        let mut config: Map<String, Value> = self
+            .other_config
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        config.insert(
+            HOST_TEMPLATE_KEY.to_string(),
+            sorted_section(&self.host_templates)?,
+        );
+        config.insert(PLUGIN_KEY.to_string(), sorted_section(&self.plugins)?);
+        config.insert(
+            SERVICE_CHECK_KEY.to_string(),
+            sorted_section(&self.service_checks)?,
+        );
+        config.insert(VARIABLE_KEY.to_string(), sorted_section(&self.variables)?);
+
+        Ok(Value::Object(config))
+    }
+
+    /// Writes the `Opspack` as a gzipped tarball.
+    ///
+    /// All files are written with a modification time of 0 so that writing the same `Opspack`
+    /// twice yields identical archives, which keeps them friendly to version control.
+    ///
+    /// # Arguments
+    /// * `writer` - The writer to write the `.tar.gz` archive to.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError::InvalidOpspack` if the objects cannot be serialized or the
+    /// archive cannot be written.
+    pub fn to_writer<W: Write>(&self, writer: W) -> Result<(), OpsviewConfigError> {
+        validate_opspack_name(&self.info.name)?;
+
+        let config = serde_json::to_vec_pretty(&self.config_json()?)
+            .map_err(|e| OpsviewConfigError::InvalidOpspack(e.to_string()))?;
+
+        let mut builder = tar::Builder::new(GzEncoder::new(writer, Compression::default()));
+        let root = &self.info.name;
+
+        append_file(
+            &mut builder,
+            &format!("{}/{}", root, INFO_FILE),
+            self.info.to_string().as_bytes(),
+            0o644,
+        )?;
+        append_file(
+            &mut builder,
+            &format!("{}/{}", root, CONFIG_FILE),
+            &config,
+            0o644,
+        )?;
+
+        for (name, contents) in &self.plugin_files {
+            if name.contains('/') || name == ".." || name.is_empty() {
+                return Err(OpsviewConfigError::InvalidOpspack(format!(
+                    "Invalid plugin file name: '{}'",
+                    name
+                )));
+            }
+            append_file(
+                &mut builder,
+                &format!("{}/{}/{}", root, PLUGINS_DIR, name),
+                contents,
+                0o755,
+            )?;
+        }
+
+        builder
+            .into_inner()
+            .and_then(|encoder| encoder.finish())
+            .map_err(archive_error)?;
+
+        Ok(())
+    }
+
+    /// Returns the `Opspack` as the bytes of a gzipped tarball.
+    ///
+    /// See [`Opspack::to_writer`] for details.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, OpsviewConfigError> {
+        let mut bytes = Vec::new();
+        self.to_writer(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Writes the `Opspack` to a `.tar.gz` file.
+    ///
+    /// See [`Opspack::to_writer`] for details.
+    pub fn write_to_path<P: AsRef<Path>>(&self, path: P) -> Result<(), OpsviewConfigError> {
+        let file = std::fs::File::create(path).map_err(archive_error)?;
+        self.to_writer(std::io::BufWriter::new(file))
+    }
+
+    /// Compares this `Opspack` with another version of it.
+    ///
+    /// Objects are matched by name and compared with [`diff_config_objects`], ignoring the
+    /// [`READONLY_FIELDS`] that are specific to an Opsview instance, such as IDs and refs. A plugin is reported as changed if either its configuration or
+    /// its executable differs.
+    ///
+    /// # Arguments
+    /// * `other` - The newer version of the Opspack.
+    pub fn diff(&self, other: &Opspack) -> OpspackDiff {
+        let mut info = Vec::new();
+        let mut compare_info = |key: &str, old: Option<&String>, new: Option<&String>| {
+            if old != new {
+                info.push(InfoChange {
+                    key: key.to_string(),
+                    old: old.cloned(),
+                    new: new.cloned(),
+                });
+            }
+        };
+
+        compare_info("NAME", Some(&self.info.name), Some(&other.info.name));
+        compare_info(
+            "VERSION",
+            self.info.version.as_ref(),
+            other.info.version.as_ref(),
+        );
+        compare_info(
+            "DESCRIPTION",
+            self.info.description.as_ref(),
+            other.info.description.as_ref(),
+        );
+        let extra_keys: BTreeSet<&String> = self
+            .info
+            .extra
+            .keys()
+            .chain(other.info.extra.keys())
+            .collect();
+        for key in extra_keys {
+            compare_info(key, self.info.extra.get(key), other.info.extra.get(key));
+        }
+
+        let mut plugins = diff_section(&self.plugins, &other.plugins);
+        for name in self.plugins.keys() {
+            if other.plugins.contains(name)
+                && self.plugin_files.get(name) != other.plugin_files.get(name)
+                && !plugins.changed.contains(name)
+            {
+                plugins.changed.push(name.clone());
+            }
+        }
+        plugins.changed.sort();
+
+        OpspackDiff {
+            info,
+            host_templates: diff_section(&self.host_templates, &other.host_templates),
+            service_checks: diff_section(&self.service_checks, &other.service_checks),
+            plugins,
+            variables: diff_section(&self.variables, &other.variables),
+        }
+    }
+}
+
+/// An Opspack installed on an Opsview instance, as returned by
+/// [`OpsviewClient::list_opspacks`](crate::client::OpsviewClient::list_opspacks).
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct InstalledOpspack {
+    /// The name of the Opspack.
+    pub name: String,
+    /// The installed version of the Opspack, if known.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_version",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub version: Option<String>,
+    /// A description of the Opspack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// The result of a successful import of an Opspack, as returned by
+/// [`OpsviewClient::import_opspack`](crate::client::OpsviewClient::import_opspack).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OpspackImportResult {
+    /// The name of the imported Opspack.
+    pub name: String,
+    /// The version of the imported Opspack, if reported by the server.
+    pub version: Option<String>,
+    /// Any messages reported by the server during the import.
+    pub messages: Vec<String>,
+}
+
+impl OpspackImportResult {
+    /// Parses the response to an import request.
+    ///
+    /// Returns `Err` with the reason given by the server if the response reports a failure.
+    pub(crate) fn from_response(name: &str, response: &Value) -> Result<Self, String> {
+        let messages: Vec<String> = response
+            .get("messages")
+            .and_then(|m| m.as_array())
+            .map(|m| {
+                m.iter()
+                    .map(|v| match v {
+                        Value::String(s) => s.clone(),
+                        other => other
+                            .get("message")
+                            .or_else(|| other.get("detail"))
+                            .and_then(|m| m.as_str())
+                            .map(str::to_string)
+                            .unwrap_or_else(|| other.to_string()),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let success = match response.get("success") {
+            Some(Value::Bool(b)) => *b,
+            Some(Value::Number(n)) => n.as_u64() != Some(0),
+            Some(Value::String(s)) => s != "0" && s != "false",
+            _ => true,
+        };
+
+        if !success {
+            let reason = response
+                .get("message")
+                .and_then(|m| m.as_str())
+                .map(str::to_string)
+                .unwrap_or_else(|| messages.join("; "));
+            return Err(reason);
+        }
+
+        let version = response.get("version").and_then(|v| match v {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        });
+
+        Ok(OpspackImportResult {
+            name: response
+                .get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or(name)
+                .to_string(),
+            version,
+            messages,
+        })
+    }
+}
+
+/// A change to a key in the `info` file between two versions of an [`Opspack`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InfoChange {
+    /// The key that changed, e.g. `VERSION`.
+    pub key: String,
+    /// The value in the old version, if any.
+    pub old: Option<String>,
+    /// The value in the new version, if any.
+    pub new: Option<String>,
+}
+
+/// The names of the objects of one type that differ between two versions of an [`Opspack`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SectionDiff {
+    /// Objects only present in the new version.
+    pub added: Vec<String>,
+    /// Objects only present in the old version.
+    pub removed: Vec<String>,
+    /// Objects present in both versions, but with different configuration.
+    pub changed: Vec<String>,
+}
+
+impl SectionDiff {
+    /// Returns true if no objects were added, removed or changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// The differences between two versions of an [`Opspack`], as returned by [`Opspack::diff`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OpspackDiff {
+    /// Changes to the metadata in the `info` file.
+    pub info: Vec<InfoChange>,
+    /// Changes to the host templates.
+    pub host_templates: SectionDiff,
+    /// Changes to the service checks.
+    pub service_checks: SectionDiff,
+    /// Changes to the plugins, including their executables.
+    pub plugins: SectionDiff,
+    /// Changes to the variables.
+    pub variables: SectionDiff,
+}
+
+impl OpspackDiff {
+    /// Returns true if the two versions are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.info.is_empty()
+            && self.host_templates.is_empty()
+            && self.service_checks.is_empty()
+            && self.plugins.is_empty()
+            && self.variables.is_empty()
+    }
+}
+
+impl fmt::Display for OpspackDiff {
+    /// Formats the diff as a human readable summary with one line per change.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.info {
+            writeln!(
+                f,
+                "~ info {}: {} -> {}",
+                change.key,
+                change.old.as_deref().unwrap_or("<none>"),
+                change.new.as_deref().unwrap_or("<none>")
+            )?;
+        }
+
+        let sections = [
+            ("host template", &self.host_templates),
+            ("service check", &self.service_checks),
+            ("plugin", &self.plugins),
+            ("variable", &self.variables),
+        ];
+
+        for (kind, section) in sections {
+            for name in &section.added {
+                writeln!(f, "+ {} {}", kind, name)?;
+            }
+            for name in &section.removed {
+                writeln!(f, "- {} {}", kind, name)?;
+            }
+            for name in &section.changed {
+                writeln!(f, "~ {} {}", kind, name)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Deserializes a version that may be given as either a string or a number.
+fn deserialize_version<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(Value::Number(n)) => Ok(Some(n.to_string())),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "Invalid Opspack version: {}",
+            other
+        ))),
+    }
+}
+
+fn archive_error(e: std::io::Error) -> OpsviewConfigError {
+    OpsviewConfigError::InvalidOpspack(e.to_string())
+}
+
+/// Ensures the name of an Opspack can be used as the name of its top level directory.
+fn validate_opspack_name(name: &str) -> Result<(), OpsviewConfigError> {
+    if name.is_empty() {
+        return Err(OpsviewConfigError::RequiredFieldEmpty("name".to_string()));
+    }
+
+    if name == "." || name == ".." || name.contains(['/', '\\', '\0']) {
+        return Err(OpsviewConfigError::InvalidOpspack(format!(
+            "Invalid Opspack name: '{}'",
+            name
+        )));
+    }
+
+    Ok(())
+}
+
+/// Removes a section from the parsed `config.json` and deserializes it into a `ConfigObjectMap`.
+fn take_section<T: ConfigObject>(
+    config: &mut Map<String, Value>,
+    key: &str,
+) -> Result<ConfigObjectMap<T>, OpsviewConfigError> {
+    match config.remove(key) {
+        Some(value) => serde_json::from_value(value).map_err(|e| {
+            OpsviewConfigError::InvalidOpspack(format!("Unable to parse '{}' objects: {}", key, e))
+        }),
+        None => Ok(ConfigObjectMap::new()),
+    }
+}
+
+/// Serializes a `ConfigObjectMap` as an array sorted by name.
+fn sorted_section<T: ConfigObject>(map: &ConfigObjectMap<T>) -> Result<Value, OpsviewConfigError> {
+    let objects: BTreeMap<&String, &T> = map.iter().map(|(k, v)| (k, &**v)).collect();
+    let objects: Vec<&T> = objects.into_values().collect();
+    serde_json::to_value(objects).map_err(|e| OpsviewConfigError::InvalidOpspack(e.to_string()))
+}
+
+fn append_file<W: Write>(
+    builder: &mut tar::Builder<W>,
+    path: &str,
+    contents: &[u8],
+    mode: u32,
+) -> Result<(), OpsviewConfigError> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(contents.len() as u64);
+    header.set_mode(mode);
+    header.set_mtime(0);
+    header.set_cksum();
+    builder
+        .append_data(&mut header, path, contents)
+        .map_err(archive_error)
+}
+
+fn diff_section<T: ConfigObject>(
+    old: &ConfigObjectMap<T>,
+    new: &ConfigObjectMap<T>,
+) -> SectionDiff {
+    let old_names: BTreeSet<&String> = old.keys().collect();
+    let new_names: BTreeSet<&String> = new.keys().collect();
+    let options = DiffOptions {
+        ignore_readonly: true,
+    };
+
+    SectionDiff {
+        added: new_names
+            .difference(&old_names)
+            .map(|s| s.to_string())
+            .collect(),
+        removed: old_names
+            .difference(&new_names)
+            .map(|s| s.to_string())
+            .collect(),
+        changed: old_names
+            .intersection(&new_names)
+            .filter(|name| {
+                let (a, b) = (old.get(name).unwrap(), new.get(name).unwrap());
+                !diff_config_objects(&*a, &*b, &options).is_empty()
+            })
+            .map(|s| s.to_string())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_instance() -> OpsviewInstance {
+        let plugin = Plugin {
+            name: "check_http".to_string(),
+            ..Default::default()
+        };
+
+        let variable = Variable {
+            name: "URL".to_string(),
+            id: Some(12),
+            ..Default::default()
+        };
+
+        let mut check = ServiceCheck::minimal("HTTP").unwrap();
+        check.plugin = Some(plugin.clone());
+        check.args = Some("-u %URL%".to_string());
+        check.attribute = Some(VariableRef::from(variable.clone()));
+        check.id = Some(3);
+
+        let mut template = HostTemplate::minimal("Application - Web").unwrap();
+        template.id = Some(7);
+        let mut checks = ConfigRefMap::new();
+        checks.add(ServiceCheckHostRef::from(check.clone()));
+        template.servicechecks = Some(checks);
+
+        let mut instance = OpsviewInstance::default();
+        instance.plugins.add(plugin);
+        instance.variables.add(variable);
+        instance.service_checks.add(check);
+        instance
+            .service_checks
+            .add(ServiceCheck::minimal("Unrelated").unwrap());
+        instance.host_templates.add(template);
+        instance
+    }
+
+    #[test]
+    fn test_info_round_trip() {
+        let contents = "# comment\nNAME=application-web\nVERSION=1.2\nAUTHOR=Someone\n";
+        let info = OpspackInfo::parse(contents).unwrap();
+
+        assert_eq!(info.name, "application-web");
+        assert_eq!(info.version.as_deref(), Some("1.2"));
+        assert_eq!(
+            info.extra.get("AUTHOR").map(String::as_str),
+            Some("Someone")
+        );
+        assert_eq!(OpspackInfo::parse(&info.to_string()).unwrap(), info);
+
+        assert!(OpspackInfo::parse("VERSION=1.0").is_err());
+        assert!(OpspackInfo::parse("NAME=../evil").is_err());
+    }
+
+    #[test]
+    fn test_from_instance_includes_dependencies() {
+        let info = OpspackInfo::new("application-web", "1.0").unwrap();
+        let opspack =
+            Opspack::from_instance(&example_instance(), info, &["Application - Web"], &[]).unwrap();
+
+        assert_eq!(opspack.host_templates.len(), 1);
+        assert_eq!(opspack.service_checks.len(), 1);
+        assert!(opspack.plugins.contains("check_http"));
+        assert!(opspack.variables.contains("URL"));
+        assert_eq!(
+            opspack.host_templates.get("Application - Web").unwrap().id,
+            None
+        );
+        assert_eq!(opspack.variables.get("URL").unwrap().id, None);
+
+        let info = OpspackInfo::new("application-web", "1.0").unwrap();
+        assert!(Opspack::from_instance(&example_instance(), info, &["Missing"], &[]).is_err());
+    }
+
+    #[test]
+    fn test_archive_round_trip() {
+        let info = OpspackInfo::new("application-web", "1.0").unwrap();
+        let mut opspack =
+            Opspack::from_instance(&example_instance(), info, &["Application - Web"], &[]).unwrap();
+        opspack.add_plugin_file("check_http", b"#!/bin/sh\necho OK\n".to_vec());
+        opspack.other_config.insert(
+            "servicegroup".to_string(),
+            serde_json::json!([{"name": "Web"}]),
+        );
+
+        let bytes = opspack.to_bytes().unwrap();
+        assert_eq!(bytes, opspack.to_bytes().unwrap());
+
+        let read_back = Opspack::from_bytes(&bytes).unwrap();
+        assert_eq!(read_back, opspack);
+        assert!(opspack.diff(&read_back).is_empty());
+    }
+
+    #[test]
+    fn test_archive_is_deterministic() {
+        let names: Vec<String> = (0..16).map(|i| format!("Check {:02}", i)).collect();
+        let opspack = |names: &mut dyn Iterator<Item = &String>| {
+            let mut template = HostTemplate::minimal("Application - Web").unwrap();
+            let mut checks = ConfigRefMap::new();
+            for name in names {
+                checks.add(ServiceCheckHostRef::from(
+                    ServiceCheck::minimal(name).unwrap(),
+                ));
+            }
+            template.servicechecks = Some(checks);
+
+            let mut opspack = Opspack::new(OpspackInfo::new("application-web", "1.0").unwrap());
+            opspack.host_templates.add(template);
+            opspack.to_bytes().unwrap()
+        };
+
+        assert_eq!(opspack(&mut names.iter()), opspack(&mut names.iter().rev()));
+    }
+
+    #[test]
+    fn test_invalid_archive() {
+        assert!(Opspack::from_bytes(b"not an archive").is_err());
+        assert!(Opspack::from_bytes(&Opspack::default().to_bytes().unwrap_or_default()).is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = Opspack::from_instance(
+            &example_instance(),
+            OpspackInfo::new("application-web", "1.0").unwrap(),
+            &["Application - Web"],
+            &[],
+        )
+        .unwrap();
+
+        let mut new = old.clone();
+        new.info.version = Some("1.1".to_string());
+        new.service_checks
+            .add(ServiceCheck::minimal("HTTPS").unwrap());
+        new.variables.remove("URL");
+        let mut check = (*new.service_checks.get("HTTP").unwrap()).clone();
+        check.args = Some("-u %URL% -t 10".to_string());
+        new.service_checks.add(check);
+        new.add_plugin_file("check_http", b"#!/bin/sh\n".to_vec());
+        let mut template = (*new.host_templates.get("Application - Web").unwrap()).clone();
+        template.id = Some(99);
+        new.host_templates.add(template);
+
+        let diff = old.diff(&new);
+
+        assert_eq!(
+            diff.info,
+            vec![InfoChange {
+                key: "VERSION".to_string(),
+                old: Some("1.0".to_string()),
+                new: Some("1.1".to_string()),
+            }]
+        );
+        assert_eq!(diff.service_checks.added, vec!["HTTPS"]);
+        assert_eq!(diff.service_checks.changed, vec!["HTTP"]);
+        assert_eq!(diff.variables.removed, vec!["URL"]);
+        assert_eq!(diff.plugins.changed, vec!["check_http"]);
+        assert!(diff.host_templates.is_empty());
+        assert!(diff.to_string().contains("+ service check HTTPS"));
+    }
+}
@@ -0,0 +1,589 @@
+use crate::config::ServiceCheck;
+use crate::prelude::*;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+lazy_static! {
+    static ref PERFDATA_VALUE_REGEX: Regex =
+        Regex::new(r"^([-+]?(?:\d+(?:[.,]\d*)?|[.,]\d+)(?:[eE][-+]?\d+)?|U)([^;\d]*)$").unwrap();
+}
+
+/// Represents a
+/// [Nagios threshold range](https://nagios-plugins.org/doc/guidelines.html#THRESHOLDFORMAT) as used
+/// for the warning and critical thresholds of performance data.
+///
+/// A value raises an alert if it is outside of the range, or inside of it if the range is
+/// inverted with a leading `@`.
+///
+/// | Range    | Alert if value is          |
+/// |----------|----------------------------|
+/// | `10`     | < 0 or > 10                |
+/// | `10:`    | < 10                       |
+/// | `~:10`   | > 10                       |
+/// | `10:20`  | < 10 or > 20               |
+/// | `@10:20` | >= 10 and <= 20            |
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NagiosRange {
+    /// The lower bound of the range, or `None` for negative infinity.
+    pub start: Option<f64>,
+    /// The upper bound of the range, or `None` for positive infinity.
+    pub end: Option<f64>,
+    /// Whether to alert when the value is inside the range rather than outside of it.
+    pub inside: bool,
+}
+
+impl NagiosRange {
+    /// Returns true if the value is within the bounds of the range, ignoring `inside`.
+    pub fn contains(&self, value: f64) -> bool {
+        self.start.iter().all(|&s| value >= s) && self.end.iter().all(|&e| value <= e)
+    }
+
+    /// Returns true if the value should raise an alert according to this range.
+    pub fn alerts(&self, value: f64) -> bool {
+        self.contains(value) == self.inside
+    }
+}
+
+impl FromStr for NagiosRange {
+    type Err = OpsviewConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || OpsviewConfigError::InvalidRange(s.to_string());
+        let parse_number = |n: &str| n.replace(',', ".").parse::<f64>().map_err(|_| invalid());
+
+        let trimmed = s.trim();
+        let (inside, range) = match trimmed.strip_prefix('@') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+
+        if range.is_empty() {
+            return Err(invalid());
+        }
+
+        let (start, end) = match range.split_once(':') {
+            Some((start, end)) => {
+                let start = match start {
+                    "" => Some(0.0),
+                    "~" => None,
+                    n => Some(parse_number(n)?),
+                };
+                let end = match end {
+                    "" => None,
+                    n => Some(parse_number(n)?),
+                };
+                (start, end)
+            }
+            None => (Some(0.0), Some(parse_number(range)?)),
+        };
+
+        if let (Some(start), Some(end)) = (start, end) {
+            if start > end {
+                return Err(invalid());
+            }
+        }
+
+        Ok(NagiosRange { start, end, inside })
+    }
+}
+
+impl fmt::Display for NagiosRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.inside {
+            write!(f, "@")?;
+        }
+        match (self.start, self.end) {
+            (Some(0.0), Some(end)) => write!(f, "{}", end),
+            (Some(start), Some(end)) => write!(f, "{}:{}", start, end),
+            (Some(start), None) => write!(f, "{}:", start),
+            (None, Some(end)) => write!(f, "~:{}", end),
+            (None, None) => write!(f, "~:"),
+        }
+    }
+}
+
+impl Serialize for NagiosRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NagiosRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Represents a single item of Nagios performance data, e.g. `'rta'=0.5ms;100;200;0;`.
+///
+/// The grammar is `'label'=value[UOM];[warn];[crit];[min];[max]`, where the label only needs to be
+/// quoted if it contains spaces, `=` or `'`, and a literal `'` in a quoted label is written as `''`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PerfData {
+    /// The label of the metric.
+    pub label: String,
+    /// The value of the metric, or `None` if the value could not be determined (`U`).
+    pub value: Option<f64>,
+    /// The unit of measurement, if any.
+    pub uom: Option<String>,
+    /// The warning threshold range, if any.
+    pub warning: Option<NagiosRange>,
+    /// The critical threshold range, if any.
+    pub critical: Option<NagiosRange>,
+    /// The minimum possible value, if any.
+    pub min: Option<f64>,
+    /// The maximum possible value, if any.
+    pub max: Option<f64>,
+}
+
+impl PerfData {
+    /// Creates a new `PerfData` item with only a label and a value.
+    ///
+    /// # Arguments
+    /// * `label` - The label of the metric.
+    /// * `value` - The value of the metric.
+    pub fn new(label: &str, value: f64) -> Self {
+        PerfData {
+            label: label.to_string(),
+            value: Some(value),
+            uom: None,
+            warning: None,
+            critical: None,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Returns the [`ServiceCheckState`] of the value according to the warning and critical
+    /// ranges of this item.
+    ///
+    /// An undetermined value (`U`) is always `Unknown`.
+    pub fn state(&self) -> ServiceCheckState {
+        let value = match self.value {
+            Some(v) => v,
+            None => return ServiceCheckState::Unknown,
+        };
+
+        if self.critical.is_some_and(|r| r.alerts(value)) {
+            ServiceCheckState::Critical
+        } else if self.warning.is_some_and(|r| r.alerts(value)) {
+            ServiceCheckState::Warning
+        } else {
+            ServiceCheckState::Ok
+        }
+    }
+
+    /// Returns the [`ServiceCheckState`] of the value according to the `warning_comparison`,
+    /// `warning_value`, `critical_comparison` and `critical_value` fields of a [`ServiceCheck`].
+    ///
+    /// An undetermined value (`U`) is always `Unknown`. See [`evaluate_service_check`] for details.
+    pub fn evaluate_service_check(
+        &self,
+        check: &ServiceCheck,
+    ) -> Result<ServiceCheckState, OpsviewConfigError> {
+        match self.value {
+            Some(v) => evaluate_service_check(check, &format_number(v)),
+            None => Ok(ServiceCheckState::Unknown),
+        }
+    }
+
+    fn needs_quoting(&self) -> bool {
+        self.label.is_empty()
+            || self
+                .label
+                .chars()
+                .any(|c| c.is_whitespace() || c == '=' || c == '\'')
+    }
+}
+
+impl FromStr for PerfData {
+    type Err = OpsviewConfigError;
+
+    /// Parses a single item of performance data.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let items = parse_perfdata(s)?;
+        match <[PerfData; 1]>::try_from(items) {
+            Ok([item]) => Ok(item),
+            Err(_) => Err(OpsviewConfigError::InvalidPerfData(format!(
+                "expected exactly one item in '{}'",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for PerfData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = if self.needs_quoting() {
+            format!("'{}'", self.label.replace('\'', "''"))
+        } else {
+            self.label.clone()
+        };
+
+        let value = self.value.map_or("U".to_string(), format_number);
+
+        let fields = [
+            format!("{}{}", value, self.uom.as_deref().unwrap_or_default()),
+            self.warning.map(|r| r.to_string()).unwrap_or_default(),
+            self.critical.map(|r| r.to_string()).unwrap_or_default(),
+            self.min.map(format_number).unwrap_or_default(),
+            self.max.map(format_number).unwrap_or_default(),
+        ];
+
+        let used = fields
+            .iter()
+            .rposition(|field| !field.is_empty())
+            .unwrap_or(0);
+
+        write!(f, "{}={}", label, fields[..=used].join(";"))
+    }
+}
+
+/// The output of a plugin, split into its text and performance data parts.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct PluginOutput {
+    /// The first line of the output, without performance data.
+    pub text: String,
+    /// Any additional lines of output, without performance data.
+    pub long_text: Option<String>,
+    /// All performance data found in the output.
+    pub perfdata: Vec<PerfData>,
+}
+
+impl FromStr for PluginOutput {
+    type Err = OpsviewConfigError;
+
+    /// Parses the output of a plugin according to the Nagios plugin output format:
+    ///
+    /// ```text
+    /// TEXT OUTPUT | OPTIONAL PERFDATA
+    /// LONG TEXT LINE 1
+    /// LONG TEXT LINE 2 | PERFDATA LINE 2
+    /// PERFDATA LINE 3
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first_line, rest) = match s.split_once('\n') {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (s, None),
+        };
+
+        let (text, mut perfdata) = match first_line.split_once('|') {
+            Some((text, perf)) => (text.trim().to_string(), parse_perfdata(perf)?),
+            None => (first_line.trim().to_string(), Vec::new()),
+        };
+
+        let long_text = match rest {
+            None => None,
+            Some(rest) => {
+                let long_text = match rest.split_once('|') {
+                    Some((long_text, perf)) => {
+                        perfdata.extend(parse_perfdata(perf)?);
+                        long_text
+                    }
+                    None => rest,
+                };
+                Some(long_text.trim_end().to_string()).filter(|t| !t.is_empty())
+            }
+        };
+
+        Ok(PluginOutput {
+            text,
+            long_text,
+            perfdata,
+        })
+    }
+}
+
+/// Parses a string of whitespace separated performance data items.
+///
+/// # Arguments
+/// * `s` - The performance data, e.g. `'rta'=0.5ms;100;200;0; 'pl'=0%;20;60;0;100`.
+///
+/// # Errors
+/// Returns an `OpsviewConfigError::InvalidPerfData` if the string does not follow the perfdata
+/// grammar, or an `OpsviewConfigError::InvalidRange` if a threshold is not a valid range.
+pub fn parse_perfdata(s: &str) -> Result<Vec<PerfData>, OpsviewConfigError> {
+    let invalid =
+        |reason: &str| OpsviewConfigError::InvalidPerfData(format!("{}: '{}'", reason, s));
+    let mut items = Vec::new();
+    let mut chars = s.chars().peekable();
+
+    loop {
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut label = String::new();
+        if chars.peek() == Some(&'\'') {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('\'') if chars.peek() == Some(&'\'') => {
+                        chars.next();
+                        label.push('\'');
+                    }
+                    Some('\'') => break,
+                    Some(c) => label.push(c),
+                    None => return Err(invalid("unterminated quoted label")),
+                }
+            }
+            if chars.next() != Some('=') {
+                return Err(invalid("expected '=' after label"));
+            }
+        } else {
+            loop {
+                match chars.next() {
+                    Some('=') => break,
+                    Some(c) if c.is_whitespace() => return Err(invalid("missing '=' in item")),
+                    Some(c) => label.push(c),
+                    None => return Err(invalid("missing '=' in item")),
+                }
+            }
+        }
+
+        if label.is_empty() {
+            return Err(invalid("empty label"));
+        }
+
+        let mut data = String::new();
+        while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+            data.push(c);
+        }
+
+        items.push(parse_perfdata_fields(label, &data).map_err(|e| match e {
+            OpsviewConfigError::InvalidPerfData(reason) => invalid(&reason),
+            e => e,
+        })?);
+    }
+
+    Ok(items)
+}
+
+/// Formats a list of performance data items as a whitespace separated string.
+pub fn format_perfdata(items: &[PerfData]) -> String {
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn parse_perfdata_fields(label: String, data: &str) -> Result<PerfData, OpsviewConfigError> {
+    let mut fields = data.split(';');
+
+    let value_field = fields.next().unwrap_or_default();
+    let captures = PERFDATA_VALUE_REGEX.captures(value_field).ok_or_else(|| {
+        OpsviewConfigError::InvalidPerfData(format!("invalid value for {}", label))
+    })?;
+
+    let value = match &captures[1] {
+        "U" => None,
+        n => Some(parse_perfdata_number(n)?),
+    };
+
+    let uom = Some(captures[2].to_string()).filter(|u| !u.is_empty());
+
+    let range = |field: Option<&str>| -> Result<Option<NagiosRange>, OpsviewConfigError> {
+        match field.map(str::trim) {
+            None | Some("") => Ok(None),
+            Some(r) => Ok(Some(r.parse()?)),
+        }
+    };
+
+    let number = |field: Option<&str>| -> Result<Option<f64>, OpsviewConfigError> {
+        match field.map(str::trim) {
+            None | Some("") => Ok(None),
+            Some(n) => Ok(Some(parse_perfdata_number(n)?)),
+        }
+    };
+
+    let warning = range(fields.next())?;
+    let critical = range(fields.next())?;
+    let min = number(fields.next())?;
+    let max = number(fields.next())?;
+
+    if fields.any(|f| !f.is_empty()) {
+        return Err(OpsviewConfigError::InvalidPerfData(format!(
+            "too many fields for {}",
+            label
+        )));
+    }
+
+    Ok(PerfData {
+        label,
+        value,
+        uom,
+        warning,
+        critical,
+        min,
+        max,
+    })
+}
+
+fn parse_perfdata_number(n: &str) -> Result<f64, OpsviewConfigError> {
+    n.replace(',', ".")
+        .parse::<f64>()
+        .map_err(|_| OpsviewConfigError::InvalidPerfData(format!("'{}' is not a number", n)))
+}
+
+fn format_number(n: f64) -> String {
+    n.to_string()
+}
+
+/// Evaluates a value against the `critical_comparison`/`critical_value` and
+/// `warning_comparison`/`warning_value` fields of a [`ServiceCheck`].
+///
+/// This is a shorthand for [`ServiceCheck::thresholds`] followed by
+/// [`ServiceCheckThresholds::evaluate`](crate::config::ServiceCheckThresholds::evaluate), which
+/// describes how the value is compared.
+///
+/// # Arguments
+/// * `check` - The [`ServiceCheck`] to take the thresholds from.
+/// * `value` - The value to evaluate.
+///
+/// # Errors
+/// Returns an error if the thresholds of the service check are not valid.
+pub fn evaluate_service_check(
+    check: &ServiceCheck,
+    value: &str,
+) -> Result<ServiceCheckState, OpsviewConfigError> {
+    Ok(check.thresholds()?.evaluate(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_range() {
+        let r: NagiosRange = "10".parse().unwrap();
+        assert!(r.alerts(-1.0) && !r.alerts(0.0) && !r.alerts(10.0) && r.alerts(10.1));
+
+        let r: NagiosRange = "10:".parse().unwrap();
+        assert!(r.alerts(9.9) && !r.alerts(1000.0));
+
+        let r: NagiosRange = "~:10".parse().unwrap();
+        assert!(!r.alerts(-1000.0) && r.alerts(10.1));
+
+        let r: NagiosRange = "@10:20".parse().unwrap();
+        assert!(r.alerts(10.0) && r.alerts(20.0) && !r.alerts(9.9) && !r.alerts(20.1));
+
+        assert!("20:10".parse::<NagiosRange>().is_err());
+        assert!("abc".parse::<NagiosRange>().is_err());
+        assert!("@".parse::<NagiosRange>().is_err());
+    }
+
+    #[test]
+    fn test_range_round_trip() {
+        for range in ["10", "10:", "~:10", "10:20", "@10:20", "@~:0.5", "-5:5"] {
+            assert_eq!(range.parse::<NagiosRange>().unwrap().to_string(), range);
+        }
+    }
+
+    #[test]
+    fn test_parse_perfdata() {
+        let items =
+            parse_perfdata("'rta'=0.5ms;100;200;0; 'packet loss'=0%;20;60;0;100 x=U").unwrap();
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].label, "rta");
+        assert_eq!(items[0].value, Some(0.5));
+        assert_eq!(items[0].uom, Some("ms".to_string()));
+        assert_eq!(items[0].warning, Some("100".parse().unwrap()));
+        assert_eq!(items[0].critical, Some("200".parse().unwrap()));
+        assert_eq!(items[0].min, Some(0.0));
+        assert_eq!(items[0].max, None);
+        assert_eq!(items[1].label, "packet loss");
+        assert_eq!(items[1].max, Some(100.0));
+        assert_eq!(items[2].value, None);
+        assert_eq!(items[2].state(), ServiceCheckState::Unknown);
+    }
+
+    #[test]
+    fn test_parse_perfdata_escaped_quote() {
+        let item: PerfData = "'it''s'=1c".parse().unwrap();
+        assert_eq!(item.label, "it's");
+        assert_eq!(item.uom, Some("c".to_string()));
+        assert_eq!(item.to_string(), "'it''s'=1c");
+    }
+
+    #[test]
+    fn test_parse_invalid_perfdata() {
+        assert!(parse_perfdata("rta").is_err());
+        assert!(parse_perfdata("'rta=1").is_err());
+        assert!(parse_perfdata("rta=abc").is_err());
+        assert!(parse_perfdata("rta=1;2;3;4;5;6").is_err());
+        assert!(parse_perfdata("rta=1;5:2").is_err());
+    }
+
+    #[test]
+    fn test_format_perfdata() {
+        let items = parse_perfdata("'rta'=0.5ms;100;200;0; 'packet loss'=0%;@10:20;~:60").unwrap();
+        assert_eq!(
+            format_perfdata(&items),
+            "rta=0.5ms;100;200;0 'packet loss'=0%;@10:20;~:60"
+        );
+        assert_eq!(parse_perfdata(&format_perfdata(&items)).unwrap(), items);
+    }
+
+    #[test]
+    fn test_perfdata_state() {
+        let mut item: PerfData = "load=5;4;8".parse().unwrap();
+        assert_eq!(item.state(), ServiceCheckState::Warning);
+        item.value = Some(9.0);
+        assert_eq!(item.state(), ServiceCheckState::Critical);
+        item.value = Some(1.0);
+        assert_eq!(item.state(), ServiceCheckState::Ok);
+    }
+
+    #[test]
+    fn test_parse_plugin_output() {
+        let output: PluginOutput =
+            "PING OK - Packet loss = 0%, RTA = 0.50 ms|rta=0.5ms;100;200;0\nline 2\nline 3 | pl=0%"
+                .parse()
+                .unwrap();
+
+        assert_eq!(output.text, "PING OK - Packet loss = 0%, RTA = 0.50 ms");
+        assert_eq!(output.long_text, Some("line 2\nline 3".to_string()));
+        assert_eq!(output.perfdata.len(), 2);
+        assert_eq!(output.perfdata[1].label, "pl");
+    }
+
+    #[test]
+    fn test_evaluate_service_check() {
+        let mut check = ServiceCheck::minimal("Interface Status").unwrap();
+        check.warning_comparison = Some(">".to_string());
+        check.warning_value = Some("80".to_string());
+        check.critical_comparison = Some(">".to_string());
+        check.critical_value = Some("90".to_string());
+
+        assert_eq!(
+            evaluate_service_check(&check, "50"),
+            Ok(ServiceCheckState::Ok)
+        );
+        assert_eq!(
+            evaluate_service_check(&check, "85"),
+            Ok(ServiceCheckState::Warning)
+        );
+
+        let item: PerfData = "util=95%".parse().unwrap();
+        assert_eq!(
+            item.evaluate_service_check(&check),
+            Ok(ServiceCheckState::Critical)
+        );
+    }
+}
@@ -0,0 +1,560 @@
+use crate::effective::{EffectiveServiceCheck, HostServiceChecks, ServiceCheckResolver};
+use crate::hostgrouptree::HostGroupTree;
+use crate::{config::*, instance::OpsviewInstance, prelude::*};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The subject of a permission question: either a [`Role`] or a [`Contact`], whose permissions
+/// are those of its role.
+#[derive(Clone, Copy, Debug)]
+pub enum Subject<'a> {
+    /// A role.
+    Role(&'a Role),
+    /// A contact.
+    Contact(&'a Contact),
+}
+
+impl<'a> From<&'a Role> for Subject<'a> {
+    fn from(role: &'a Role) -> Self {
+        Subject::Role(role)
+    }
+}
+
+impl<'a> From<&'a Contact> for Subject<'a> {
+    fn from(contact: &'a Contact) -> Self {
+        Subject::Contact(contact)
+    }
+}
+
+/// An offline evaluator of the permissions granted by the [`Role`] objects in an
+/// [`OpsviewInstance`].
+///
+/// Visibility follows the rules Opsview documents for the `VIEWALL` and `VIEWSOME` accesses:
+/// * With `VIEWALL`, every host and service check is visible.
+/// * With `VIEWSOME`, a service check on a host is visible if the host is in one of the
+///   `access_hostgroups` of the role (or any host group below them) and the service check is in
+///   one of its `access_servicegroups`, or if the service check on the host is tagged with one of
+///   its `access_keywords`. The `all_hostgroups`, `all_servicegroups` and `all_keywords` flags
+///   stand in for the corresponding lists.
+/// * A host is visible if it is in one of the `access_hostgroups`, or if any service check on it
+///   is visible.
+/// * Without either access, nothing is visible.
+///
+/// A contact has the permissions of its role, and none if its role is not set or not found.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::{Access, Role};
+/// use opsview::instance::OpsviewInstance;
+/// use opsview::permissions::PermissionEvaluator;
+/// use opsview::prelude::*;
+///
+/// let mut role = Role::minimal("Operators").unwrap();
+/// role.accesses = Some([Access::ViewAll(None), Access::ActionAll(None)].into_iter().collect());
+///
+/// let mut instance = OpsviewInstance::default();
+/// instance.roles.add(role.clone());
+///
+/// let evaluator = PermissionEvaluator::new(&instance);
+///
+/// assert!(evaluator.has_access(&role, &Access::ActionSome(None)));
+/// assert!(!evaluator.has_access(&role, &Access::AdminAccess(None)));
+/// assert_eq!(evaluator.roles_granting(&Access::ViewSome(None)).len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PermissionEvaluator<'a> {
+    instance: &'a OpsviewInstance,
+    tree: HostGroupTree,
+    services: BTreeMap<String, HostServiceChecks>,
+}
+
+impl<'a> PermissionEvaluator<'a> {
+    /// Creates a new `PermissionEvaluator`, resolving the service checks of every host in the
+    /// instance up front.
+    ///
+    /// # Arguments
+    /// * `instance` - The `OpsviewInstance` holding the roles, contacts and monitored objects.
+    pub fn new(instance: &'a OpsviewInstance) -> Self {
+        PermissionEvaluator {
+            instance,
+            tree: HostGroupTree::from_instance(instance),
+            services: ServiceCheckResolver::new(instance).resolve_all(),
+        }
+    }
+
+    /// Returns the host group tree of the instance.
+    pub(crate) fn tree(&self) -> &HostGroupTree {
+        &self.tree
+    }
+
+    /// Returns the role of a subject. For a role that is the role itself, and for a contact it is
+    /// the role the contact refers to, if it can be found in the instance.
+    pub fn role_of<'s>(&self, subject: impl Into<Subject<'s>>) -> Option<&'s Role>
+    where
+        'a: 's,
+    {
+        match subject.into() {
+            Subject::Role(role) => Some(role),
+            Subject::Contact(contact) => {
+                let role_ref = contact.role.as_ref()?;
+                let ref_ = role_ref.ref_();
+                self.instance
+                    .roles
+                    .values()
+                    .find(|role| match &ref_ {
+                        Some(ref_) => role.ref_.as_ref() == Some(ref_),
+                        None => role.name == role_ref.name(),
+                    })
+                    .map(|role| role.as_ref())
+            }
+        }
+    }
+
+    /// Returns `true` if the subject has an access, directly or through an access that grants it.
+    ///
+    /// See [`Access::grants`] for which accesses grant others.
+    ///
+    /// # Arguments
+    /// * `subject` - The `Role` or `Contact` to check.
+    /// * `access` - The `Access` to check for. Its reference string is ignored.
+    pub fn has_access<'s>(&self, subject: impl Into<Subject<'s>>, access: &Access) -> bool
+    where
+        'a: 's,
+    {
+        self.role_of(subject)
+            .is_some_and(|role| role_grants(role, access))
+    }
+
+    /// Returns all roles in the instance that grant an access, ordered by name.
+    ///
+    /// # Arguments
+    /// * `access` - The `Access` to look for. Its reference string is ignored.
+    pub fn roles_granting(&self, access: &Access) -> Vec<&'a Role> {
+        let mut roles: Vec<&Role> = self
+            .instance
+            .roles
+            .values()
+            .filter(|role| role_grants(role, access))
+            .map(|role| role.as_ref())
+            .collect();
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+        roles
+    }
+
+    /// Returns the names of the hosts visible to the subject, ordered by name.
+    ///
+    /// # Arguments
+    /// * `subject` - The `Role` or `Contact` to check.
+    pub fn visible_hosts<'s>(&self, subject: impl Into<Subject<'s>>) -> Vec<String>
+    where
+        'a: 's,
+    {
+        let Some(role) = self.role_of(subject) else {
+            return Vec::new();
+        };
+
+        if role_grants(role, &Access::ViewAll(None)) {
+            return self.services.keys().cloned().collect();
+        }
+        if !role_grants(role, &Access::ViewSome(None)) {
+            return Vec::new();
+        }
+
+        let mut hosts = self.hostgroup_hosts(role);
+        hosts.extend(
+            self.visible_service_checks_of(role)
+                .into_iter()
+                .map(|(host, _)| host),
+        );
+        hosts.into_iter().collect()
+    }
+
+    /// Returns the service checks visible to the subject as pairs of host name and service name,
+    /// ordered by host and then service.
+    ///
+    /// # Arguments
+    /// * `subject` - The `Role` or `Contact` to check.
+    pub fn visible_service_checks<'s>(
+        &self,
+        subject: impl Into<Subject<'s>>,
+    ) -> Vec<(String, String)>
+    where
+        'a: 's,
+    {
+        match self.role_of(subject) {
+            Some(role) => self.visible_service_checks_of(role).into_iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the subject can see a host.
+    ///
+    /// Only the host itself is evaluated, so this is cheaper than looking the host up in
+    /// [`visible_hosts`](Self::visible_hosts).
+    ///
+    /// # Arguments
+    /// * `subject` - The `Role` or `Contact` to check.
+    /// * `host` - The name of the host.
+    pub fn can_see_host<'s>(&self, subject: impl Into<Subject<'s>>, host: &str) -> bool
+    where
+        'a: 's,
+    {
+        let Some(role) = self.role_of(subject) else {
+            return false;
+        };
+
+        if role_grants(role, &Access::ViewAll(None)) {
+            return self.services.contains_key(host);
+        }
+        if !role_grants(role, &Access::ViewSome(None)) {
+            return false;
+        }
+
+        let in_hostgroups = self.in_hostgroups(role, host);
+        let hashtags = self.accessible_hashtags(role);
+        in_hostgroups
+            || self.services.get(host).is_some_and(|checks| {
+                checks
+                    .checks
+                    .iter()
+                    .any(|check| self.sees_check(role, in_hostgroups, &hashtags, host, check))
+            })
+    }
+
+    /// Returns `true` if the subject can see a service check on a host.
+    ///
+    /// Only the one service check is evaluated, so this is cheaper than looking the pair up in
+    /// [`visible_service_checks`](Self::visible_service_checks).
+    ///
+    /// # Arguments
+    /// * `subject` - The `Role` or `Contact` to check.
+    /// * `host` - The name of the host.
+    /// * `service` - The name of the service on the host, as in
+    ///   [`EffectiveServiceCheck::service_name`](crate::effective::EffectiveServiceCheck::service_name).
+    pub fn can_see_service_check<'s>(
+        &self,
+        subject: impl Into<Subject<'s>>,
+        host: &str,
+        service: &str,
+    ) -> bool
+    where
+        'a: 's,
+    {
+        let check = self
+            .services
+            .get(host)
+            .and_then(|checks| checks.checks.iter().find(|c| c.service_name == service));
+        let (Some(role), Some(check)) = (self.role_of(subject), check) else {
+            return false;
+        };
+
+        if role_grants(role, &Access::ViewAll(None)) {
+            return true;
+        }
+        if !role_grants(role, &Access::ViewSome(None)) {
+            return false;
+        }
+
+        let in_hostgroups = self.in_hostgroups(role, host);
+        self.sees_check(
+            role,
+            in_hostgroups,
+            &self.accessible_hashtags(role),
+            host,
+            check,
+        )
+    }
+
+    fn visible_service_checks_of(&self, role: &Role) -> BTreeSet<(String, String)> {
+        let view_all = role_grants(role, &Access::ViewAll(None));
+        if !view_all && !role_grants(role, &Access::ViewSome(None)) {
+            return BTreeSet::new();
+        }
+
+        let hostgroup_hosts = self.hostgroup_hosts(role);
+        let hashtags = self.accessible_hashtags(role);
+
+        let mut visible = BTreeSet::new();
+        for (host_name, checks) in &self.services {
+            let in_hostgroups = hostgroup_hosts.contains(host_name);
+
+            for check in &checks.checks {
+                if view_all || self.sees_check(role, in_hostgroups, &hashtags, host_name, check) {
+                    visible.insert((host_name.clone(), check.service_name.clone()));
+                }
+            }
+        }
+
+        visible
+    }
+
+    /// Returns `true` if a role with the `VIEWSOME` access can see a service check on a host,
+    /// either through its host groups and service groups or through its hashtags.
+    ///
+    /// # Arguments
+    /// * `in_hostgroups` - Whether the host is in the host groups the role has access to.
+    /// * `hashtags` - The hashtags the role has access to.
+    fn sees_check(
+        &self,
+        role: &Role,
+        in_hostgroups: bool,
+        hashtags: &[&Hashtag],
+        host_name: &str,
+        check: &EffectiveServiceCheck,
+    ) -> bool {
+        let host = self.instance.hosts.get(host_name);
+        let service_check = self.instance.service_checks.get(&check.service_check);
+
+        let by_group = in_hostgroups && in_servicegroups(role, service_check.as_deref());
+        by_group
+            || hashtags.iter().any(|hashtag| {
+                tags_host(hashtag, host_name, host.as_deref())
+                    && tags_service_check(hashtag, &check.service_check, service_check.as_deref())
+            })
+    }
+
+    /// Returns `true` if a host is in the host groups the role has access to, or below them.
+    fn in_hostgroups(&self, role: &Role, host: &str) -> bool {
+        if role.all_hostgroups == Some(true) {
+            return self.services.contains_key(host);
+        }
+
+        role.access_hostgroups
+            .iter()
+            .flat_map(|groups| groups.values())
+            .filter_map(|group| self.tree.path_of_ref(group))
+            .any(|path| self.tree.subtree_hosts(&path).contains(&host))
+    }
+
+    /// Returns the hosts in the host groups the role has access to, including those below them.
+    fn hostgroup_hosts(&self, role: &Role) -> BTreeSet<String> {
+        if role.all_hostgroups == Some(true) {
+            return self.services.keys().cloned().collect();
+        }
+
+        role.access_hostgroups
+            .iter()
+            .flat_map(|groups| groups.values())
+            .filter_map(|group| self.tree.path_of_ref(group))
+            .flat_map(|path| {
+                self.tree
+                    .subtree_hosts(&path)
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Returns the hashtags the role has access to.
+    fn accessible_hashtags(&self, role: &Role) -> Vec<&'a Hashtag> {
+        let names: BTreeSet<String> = role
+            .access_keywords
+            .iter()
+            .flat_map(|keywords| keywords.values().map(|k| k.name()))
+            .collect();
+
+        self.instance
+            .hashtags
+            .values()
+            .filter(|hashtag| role.all_keywords == Some(true) || names.contains(&hashtag.name))
+            .map(|hashtag| hashtag.as_ref())
+            .collect()
+    }
+}
+
+/// Returns `true` if any access of the role grants `access`.
+fn role_grants(role: &Role, access: &Access) -> bool {
+    role.accesses
+        .iter()
+        .flatten()
+        .any(|granted| granted.grants(access))
+}
+
+/// Returns `true` if the service check is in one of the service groups the role has access to.
+fn in_servicegroups(role: &Role, service_check: Option<&ServiceCheck>) -> bool {
+    if role.all_servicegroups == Some(true) {
+        return true;
+    }
+
+    let Some(group) = service_check.and_then(|c| c.servicegroup.as_ref()) else {
+        return false;
+    };
+
+    role.access_servicegroups
+        .iter()
+        .flat_map(|groups| groups.values())
+        .any(|g| g.name() == group.name())
+}
+
+/// Returns `true` if the hashtag is applied to the host, either on the hashtag or on the host.
+pub(crate) fn tags_host(hashtag: &Hashtag, name: &str, host: Option<&Host>) -> bool {
+    hashtag.all_hosts == Some(true)
+        || hashtag
+            .hosts
+            .iter()
+            .flat_map(|hosts| hosts.values())
+            .any(|h| h.name() == name)
+        || host
+            .and_then(|h| h.keywords.as_ref())
+            .is_some_and(|keywords| keywords.values().any(|k| k.name() == hashtag.name))
+}
+
+/// Returns `true` if the hashtag is applied to the service check, either on the hashtag or on
+/// the service check.
+pub(crate) fn tags_service_check(
+    hashtag: &Hashtag,
+    name: &str,
+    service_check: Option<&ServiceCheck>,
+) -> bool {
+    hashtag.all_servicechecks == Some(true)
+        || hashtag
+            .servicechecks
+            .iter()
+            .flat_map(|checks| checks.values())
+            .any(|c| c.name() == name)
+        || service_check
+            .and_then(|c| c.keywords.as_ref())
+            .is_some_and(|keywords| keywords.values().any(|k| k.name() == hashtag.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn instance() -> OpsviewInstance {
+        OpsviewInstance::from_fixture(json!({
+            "host_groups": [
+                {"name": "Opsview", "matpath": "Opsview,"},
+                {"name": "EU", "matpath": "Opsview,EU,"},
+                {"name": "Web", "matpath": "Opsview,EU,Web,"},
+                {"name": "US", "matpath": "Opsview,US,"}
+            ],
+            "hosts": [
+                {
+                    "name": "web01",
+                    "hostgroup": {"name": "Web", "matpath": "Opsview,EU,Web,"},
+                    "servicechecks": [{"name": "HTTP"}, {"name": "Disk"}]
+                },
+                {
+                    "name": "us01",
+                    "hostgroup": {"name": "US", "matpath": "Opsview,US,"},
+                    "servicechecks": [{"name": "HTTP"}, {"name": "Disk"}],
+                    "keywords": [{"name": "payments"}]
+                }
+            ],
+            "service_checks": [
+                {"name": "HTTP", "servicegroup": {"name": "Web"}},
+                {"name": "Disk", "servicegroup": {"name": "OS"}}
+            ],
+            "hashtags": [{"name": "payments", "servicechecks": [{"name": "HTTP"}]}],
+            "roles": [
+                {
+                    "name": "EU Web",
+                    "accesses": [{"name": "VIEWSOME"}, {"name": "DOWNTIMEALL"}],
+                    "access_hostgroups": [{"name": "EU", "matpath": "Opsview,EU,"}],
+                    "access_servicegroups": [{"name": "Web"}]
+                },
+                {
+                    "name": "Payments",
+                    "accesses": [{"name": "VIEWSOME"}],
+                    "access_keywords": [{"name": "payments"}]
+                },
+                {
+                    "name": "Admin",
+                    "accesses": [{"name": "VIEWALL"}, {"name": "ADMINACCESS"}]
+                }
+            ],
+            "contacts": [{"name": "alice", "role": {"name": "Payments"}}]
+        }))
+    }
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(h, s)| (h.to_string(), s.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_visibility() {
+        let instance = instance();
+        let evaluator = PermissionEvaluator::new(&instance);
+
+        let eu_web = instance.roles.get("EU Web").unwrap();
+        assert_eq!(evaluator.visible_hosts(eu_web.as_ref()), vec!["web01"]);
+        assert_eq!(
+            evaluator.visible_service_checks(eu_web.as_ref()),
+            pairs(&[("web01", "HTTP")])
+        );
+
+        let alice = instance.contacts.get("alice").unwrap();
+        assert_eq!(evaluator.role_of(alice.as_ref()).unwrap().name, "Payments");
+        assert_eq!(evaluator.visible_hosts(alice.as_ref()), vec!["us01"]);
+        assert_eq!(
+            evaluator.visible_service_checks(alice.as_ref()),
+            pairs(&[("us01", "HTTP")])
+        );
+        assert!(!evaluator.can_see_host(alice.as_ref(), "web01"));
+
+        let admin = instance.roles.get("Admin").unwrap();
+        assert_eq!(evaluator.visible_service_checks(admin.as_ref()).len(), 4);
+    }
+
+    #[test]
+    fn test_single_checks_match_visibility() {
+        let instance = instance();
+        let evaluator = PermissionEvaluator::new(&instance);
+
+        for role in instance.roles.values() {
+            let role = role.as_ref();
+            let hosts = evaluator.visible_hosts(role);
+            let checks = evaluator.visible_service_checks(role);
+
+            for host in ["web01", "us01", "missing"] {
+                assert_eq!(
+                    evaluator.can_see_host(role, host),
+                    hosts.iter().any(|h| h == host),
+                    "{} {}",
+                    role.name,
+                    host
+                );
+                for service in ["HTTP", "Disk", "missing"] {
+                    assert_eq!(
+                        evaluator.can_see_service_check(role, host, service),
+                        checks.iter().any(|(h, s)| h == host && s == service),
+                        "{} {} {}",
+                        role.name,
+                        host,
+                        service
+                    );
+                }
+            }
+        }
+
+        let alice = instance.contacts.get("alice").unwrap();
+        assert!(evaluator.can_see_service_check(alice.as_ref(), "us01", "HTTP"));
+        assert!(!evaluator.can_see_service_check(alice.as_ref(), "us01", "Disk"));
+    }
+
+    #[test]
+    fn test_accesses() {
+        let instance = instance();
+        let evaluator = PermissionEvaluator::new(&instance);
+
+        let eu_web = instance.roles.get("EU Web").unwrap();
+        assert!(evaluator.has_access(eu_web.as_ref(), &Access::DowntimeSome(None)));
+        assert!(!evaluator.has_access(eu_web.as_ref(), &Access::ActionSome(None)));
+
+        let names: Vec<&str> = evaluator
+            .roles_granting(&Access::ViewSome(None))
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Admin", "EU Web", "Payments"]);
+
+        let orphan: Contact = serde_json::from_value(json!({"name": "bob"})).unwrap();
+        assert!(!evaluator.has_access(&orphan, &Access::ViewSome(None)));
+        assert!(evaluator.visible_hosts(&orphan).is_empty());
+    }
+}
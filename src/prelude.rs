@@ -21,6 +21,9 @@ use std::sync::Arc;
 ///
 /// # Fields
 /// * `objects` - A `HashMap` where keys are object names and values are `Arc<T>`.
+///
+/// The map is serialized as a list sorted by unique name, so that equal maps always serialize to
+/// the same JSON.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct ConfigObjectMap<T: ConfigObject> {
     objects: HashMap<String, Arc<T>>,
@@ -37,11 +40,26 @@ pub struct ConfigObjectMap<T: ConfigObject> {
 ///
 /// # Fields
 /// * `objects` - A `HashMap` where keys are object names and values are `Arc<T>` references to the objects.
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+///
+/// The collection is serialized as a list sorted by unique name, so that equal collections always
+/// serialize to the same JSON. The order in which the objects were added, or listed when the
+/// collection was deserialized, is kept in memory and available through
+/// [`ConfigRefMap::ordered_values`], for fields where Opsview gives the order a meaning, such as
+/// the host templates of a host. The order is not taken into account when comparing collections.
+#[derive(Clone, Debug, Default)]
 pub struct ConfigRefMap<T: ConfigRef> {
     pub objects: HashMap<String, Arc<T>>,
+    order: Vec<String>,
+}
+
+impl<T: ConfigRef + PartialEq> PartialEq for ConfigRefMap<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.objects == other.objects
+    }
 }
 
+impl<T: ConfigRef + Eq> Eq for ConfigRefMap<T> {}
+
 /// Trait for creating objects from a JSON string.
 ///
 /// This trait defines a method for deserializing a JSON string into an instance of a type that
@@ -419,7 +437,7 @@ impl<T: ConfigObject> ConfigObjectMap<T> {
     ///
     /// # Returns
     /// An iterator over items in the collection in arbitrary order.
-    pub fn iter(&self) -> std::collections::hash_map::Iter<String, Arc<T>> {
+    pub fn iter(&self) -> std::collections::hash_map::Iter<'_, String, Arc<T>> {
         self.objects.iter()
     }
 
@@ -427,7 +445,7 @@ impl<T: ConfigObject> ConfigObjectMap<T> {
     ///
     /// # Returns
     /// An iterator over the keys in the collection in arbitrary order.
-    pub fn keys(&self) -> std::collections::hash_map::Keys<String, Arc<T>> {
+    pub fn keys(&self) -> std::collections::hash_map::Keys<'_, String, Arc<T>> {
         self.objects.keys()
     }
 
@@ -458,7 +476,7 @@ impl<T: ConfigObject> ConfigObjectMap<T> {
         self.objects.values()
     }
 
-    pub fn drain(&mut self) -> std::collections::hash_map::Drain<String, Arc<T>> {
+    pub fn drain(&mut self) -> std::collections::hash_map::Drain<'_, String, Arc<T>> {
         self.objects.drain()
     }
 
@@ -472,8 +490,7 @@ impl<T: ConfigObject> Serialize for ConfigObjectMap<T> {
     where
         S: Serializer,
     {
-        let objects: Vec<&T> = self.objects.values().map(|arc_obj| &**arc_obj).collect();
-        objects.serialize(serializer)
+        serialize_sorted(&self.objects, serializer)
     }
 }
 
@@ -522,13 +539,14 @@ impl<T: ConfigRef> ConfigRefMap<T> {
     pub fn new() -> Self {
         ConfigRefMap {
             objects: HashMap::new(),
+            order: Vec::new(),
         }
     }
 
     /// Adds an object to the collection using the object's unique name as the key.
     /// The unique name is retrieved by calling the `unique_name()` method on the object.
     pub fn add(&mut self, object: T) {
-        self.objects.insert(object.unique_name(), Arc::new(object));
+        self.add_ref(Arc::new(object));
     }
 
     /// Adds a reference to an object to the collection using the object's unique name as the key.
@@ -537,7 +555,11 @@ impl<T: ConfigRef> ConfigRefMap<T> {
     /// # Arguments
     /// * `object` - An `Arc<T>` reference to the object to add.
     pub fn add_ref(&mut self, object: Arc<T>) {
-        self.objects.insert(object.unique_name(), object);
+        let name = object.unique_name();
+        if !self.order.contains(&name) {
+            self.order.push(name.clone());
+        }
+        self.objects.insert(name, object);
     }
 
     /// Creates a representation of the collection as a `Vec` of `Arc<T>`.
@@ -579,7 +601,7 @@ impl<T: ConfigRef> ConfigRefMap<T> {
     ///
     /// # Returns
     /// An iterator over items in the collection in arbitrary order.
-    pub fn iter(&self) -> std::collections::hash_map::Iter<String, Arc<T>> {
+    pub fn iter(&self) -> std::collections::hash_map::Iter<'_, String, Arc<T>> {
         self.objects.iter()
     }
 
@@ -587,7 +609,7 @@ impl<T: ConfigRef> ConfigRefMap<T> {
     ///
     /// # Returns
     /// An iterator over the keys in the collection in arbitrary order.
-    pub fn keys(&self) -> std::collections::hash_map::Keys<String, Arc<T>> {
+    pub fn keys(&self) -> std::collections::hash_map::Keys<'_, String, Arc<T>> {
         self.objects.keys()
     }
 
@@ -607,6 +629,7 @@ impl<T: ConfigRef> ConfigRefMap<T> {
     /// # Returns
     /// An `Option<Arc<T>>` representing the object if found, or `None` if not found.
     pub fn remove(&mut self, name: &str) -> Option<Arc<T>> {
+        self.order.retain(|n| n != name);
         self.objects.remove(name)
     }
 
@@ -617,6 +640,27 @@ impl<T: ConfigRef> ConfigRefMap<T> {
     pub fn values(&self) -> impl Iterator<Item = &Arc<T>> {
         self.objects.values()
     }
+
+    /// Retrieves an iterator over references to the objects in the order in which they were added
+    /// to the collection, or listed when it was deserialized.
+    ///
+    /// Objects inserted directly into `objects` come last, sorted by name.
+    ///
+    /// # Returns
+    /// An iterator over references to the objects in the collection.
+    pub fn ordered_values(&self) -> impl Iterator<Item = &Arc<T>> {
+        let mut unordered: Vec<&String> = self
+            .objects
+            .keys()
+            .filter(|name| !self.order.contains(name))
+            .collect();
+        unordered.sort();
+
+        self.order
+            .iter()
+            .chain(unordered)
+            .filter_map(|name| self.objects.get(name))
+    }
 }
 
 impl<T: ConfigRef> Serialize for ConfigRefMap<T> {
@@ -624,8 +668,7 @@ impl<T: ConfigRef> Serialize for ConfigRefMap<T> {
     where
         S: Serializer,
     {
-        let objects: Vec<&T> = self.objects.values().map(|arc_obj| &**arc_obj).collect();
-        objects.serialize(serializer)
+        serialize_sorted(&self.objects, serializer)
     }
 }
 
@@ -636,6 +679,7 @@ impl<'de, T: ConfigRef> Deserialize<'de> for ConfigRefMap<T> {
     {
         let vec: Vec<T> = Vec::deserialize(deserializer)?;
         let mut objects = HashMap::new();
+        let mut order = Vec::with_capacity(vec.len());
 
         for obj in vec {
             let name = obj.unique_name().clone();
@@ -645,13 +689,25 @@ impl<'de, T: ConfigRef> Deserialize<'de> for ConfigRefMap<T> {
                     name
                 )));
             }
+            order.push(name.clone());
             objects.insert(name, Arc::new(obj));
         }
 
-        Ok(ConfigRefMap { objects })
+        Ok(ConfigRefMap { objects, order })
     }
 }
 
+/// Serializes the objects of a map as a list sorted by their keys.
+fn serialize_sorted<T: Serialize, S: Serializer>(
+    objects: &HashMap<String, Arc<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut objects: Vec<(&String, &Arc<T>)> = objects.iter().collect();
+    objects.sort_by_key(|(name, _)| *name);
+    let objects: Vec<&T> = objects.into_iter().map(|(_, obj)| &**obj).collect();
+    objects.serialize(serializer)
+}
+
 // Custom deserialization for readonly fields
 pub fn deserialize_readonly<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
 where
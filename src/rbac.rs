@@ -0,0 +1,434 @@
+use crate::{config::*, instance::OpsviewInstance, prelude::*};
+use serde_json::{json, Value};
+use std::fmt;
+
+/// A finding that needs attention in an access review.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum RbacFlag {
+    /// The role has the `ADMINACCESS` access.
+    AdminAccess,
+    /// The role has the `CONFIGUREROLES` access, which allows changing the permissions of any
+    /// role.
+    ConfigureRoles,
+    /// The role grants access to all host groups.
+    AllHostGroups,
+}
+
+impl fmt::Display for RbacFlag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RbacFlag::AdminAccess => write!(f, "ADMINACCESS"),
+            RbacFlag::ConfigureRoles => write!(f, "CONFIGUREROLES"),
+            RbacFlag::AllHostGroups => write!(f, "ALL_HOSTGROUPS"),
+        }
+    }
+}
+
+/// A single row of an [`RbacMatrix`]: a contact, its role, and what the role grants.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RbacRow {
+    /// The name of the contact, or `None` for a role that no contact has.
+    pub contact: Option<String>,
+    /// The name of the role.
+    pub role: String,
+    /// The name of the tenancy of the role, if any.
+    pub tenancy: Option<String>,
+    /// The names of the accesses granted by the role, ordered by name.
+    pub accesses: Vec<String>,
+    /// Whether the role grants access to all host groups.
+    pub all_hostgroups: bool,
+    /// The host groups the role grants access to, by materialized path where known, ordered.
+    pub hostgroups: Vec<String>,
+    /// Whether the role grants access to all hashtags.
+    pub all_hashtags: bool,
+    /// The hashtags the role grants access to, ordered by name.
+    pub hashtags: Vec<String>,
+    /// The findings for this row.
+    pub flags: Vec<RbacFlag>,
+}
+
+impl RbacRow {
+    /// Returns the host group scope as a single string, `ALL` if the role has access to all host
+    /// groups.
+    fn hostgroup_scope(&self) -> String {
+        scope(self.all_hostgroups, &self.hostgroups)
+    }
+
+    /// Returns the hashtag scope as a single string, `ALL` if the role has access to all hashtags.
+    fn hashtag_scope(&self) -> String {
+        scope(self.all_hashtags, &self.hashtags)
+    }
+
+    fn cells(&self) -> [String; 7] {
+        [
+            self.contact.clone().unwrap_or_default(),
+            self.role.clone(),
+            self.tenancy.clone().unwrap_or_default(),
+            self.accesses.join(";"),
+            self.hostgroup_scope(),
+            self.hashtag_scope(),
+            self.flags
+                .iter()
+                .map(|f| f.to_string())
+                .collect::<Vec<_>>()
+                .join(";"),
+        ]
+    }
+}
+
+const HEADERS: [&str; 7] = [
+    "contact",
+    "role",
+    "tenancy",
+    "accesses",
+    "hostgroups",
+    "hashtags",
+    "flags",
+];
+
+/// A matrix of contacts, their roles, the accesses those roles grant, and the host groups and
+/// hashtags the roles are scoped to, for access reviews and compliance audits.
+///
+/// There is one row per contact, ordered by contact name, followed by one row for each role that
+/// no contact has, ordered by role name. Roles with `ADMINACCESS` or `CONFIGUREROLES` and roles
+/// granting all host groups are flagged.
+///
+/// # Example
+/// ```rust
+/// use opsview::config::{Access, Role};
+/// use opsview::instance::OpsviewInstance;
+/// use opsview::prelude::*;
+/// use opsview::rbac::{RbacFlag, RbacMatrix};
+///
+/// let mut role = Role::minimal("Admins").unwrap();
+/// role.accesses = Some([Access::AdminAccess(None)].into_iter().collect());
+///
+/// let mut instance = OpsviewInstance::default();
+/// instance.roles.add(role);
+///
+/// let matrix = RbacMatrix::from_instance(&instance);
+///
+/// assert_eq!(matrix.rows[0].flags, vec![RbacFlag::AdminAccess]);
+/// assert!(matrix.to_csv().starts_with("contact,role,tenancy,"));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RbacMatrix {
+    /// The rows of the matrix.
+    pub rows: Vec<RbacRow>,
+}
+
+impl RbacMatrix {
+    /// Builds an `RbacMatrix` from the contacts, roles and tenancies of an [`OpsviewInstance`].
+    ///
+    /// # Arguments
+    /// * `instance` - The `OpsviewInstance` to report on.
+    pub fn from_instance(instance: &OpsviewInstance) -> Self {
+        let mut roles: Vec<&Role> = instance.roles.values().map(|r| r.as_ref()).collect();
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut contacts: Vec<&Contact> = instance.contacts.values().map(|c| c.as_ref()).collect();
+        contacts.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut rows = Vec::new();
+        let mut used_roles = Vec::new();
+
+        for contact in contacts {
+            let role_ref = contact.role.as_ref();
+            let role = role_ref.and_then(|role_ref| {
+                roles.iter().copied().find(|role| match role_ref.ref_() {
+                    Some(ref_) => role.ref_.as_ref() == Some(&ref_),
+                    None => role.name == role_ref.name(),
+                })
+            });
+
+            let mut row = match role {
+                Some(role) => {
+                    used_roles.push(role.name.clone());
+                    role_row(instance, role)
+                }
+                None => RbacRow {
+                    contact: None,
+                    role: role_ref.map(|r| r.name()).unwrap_or_default(),
+                    tenancy: None,
+                    accesses: Vec::new(),
+                    all_hostgroups: false,
+                    hostgroups: Vec::new(),
+                    all_hashtags: false,
+                    hashtags: Vec::new(),
+                    flags: Vec::new(),
+                },
+            };
+            row.contact = Some(contact.name.clone());
+            rows.push(row);
+        }
+
+        for role in roles {
+            if !used_roles.contains(&role.name) {
+                rows.push(role_row(instance, role));
+            }
+        }
+
+        RbacMatrix { rows }
+    }
+
+    /// Returns the names of the roles that have a flag, without duplicates and ordered by name.
+    ///
+    /// # Arguments
+    /// * `flag` - The `RbacFlag` to look for.
+    pub fn roles_flagged(&self, flag: RbacFlag) -> Vec<&str> {
+        let mut roles: Vec<&str> = self
+            .rows
+            .iter()
+            .filter(|row| row.flags.contains(&flag))
+            .map(|row| row.role.as_str())
+            .collect();
+        roles.sort();
+        roles.dedup();
+        roles
+    }
+
+    /// Returns the names of the contacts whose role has a flag, ordered by name.
+    ///
+    /// # Arguments
+    /// * `flag` - The `RbacFlag` to look for.
+    pub fn contacts_flagged(&self, flag: RbacFlag) -> Vec<&str> {
+        self.rows
+            .iter()
+            .filter(|row| row.flags.contains(&flag))
+            .filter_map(|row| row.contact.as_deref())
+            .collect()
+    }
+
+    /// Renders the matrix as CSV with a header row. Lists within a cell are separated by `;`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = HEADERS.join(",");
+        csv.push('\n');
+
+        for row in &self.rows {
+            let cells: Vec<String> = row.cells().iter().map(|c| csv_escape(c)).collect();
+            csv.push_str(&cells.join(","));
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    /// Renders the matrix as a Markdown table.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!("| {} |\n", HEADERS.join(" | "));
+        markdown.push_str(&format!("|{}\n", " --- |".repeat(HEADERS.len())));
+
+        for row in &self.rows {
+            let cells: Vec<String> = row
+                .cells()
+                .iter()
+                .map(|c| c.replace('|', "\\|").replace(';', "; "))
+                .collect();
+            markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+
+        markdown
+    }
+
+    /// Renders the matrix as a JSON array with one object per row.
+    pub fn to_json(&self) -> Value {
+        Value::Array(
+            self.rows
+                .iter()
+                .map(|row| {
+                    json!({
+                        "contact": row.contact,
+                        "role": row.role,
+                        "tenancy": row.tenancy,
+                        "accesses": row.accesses,
+                        "all_hostgroups": row.all_hostgroups,
+                        "hostgroups": row.hostgroups,
+                        "all_hashtags": row.all_hashtags,
+                        "hashtags": row.hashtags,
+                        "flags": row.flags.iter().map(|f| f.to_string()).collect::<Vec<_>>(),
+                    })
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Builds the row for a role, without a contact.
+fn role_row(instance: &OpsviewInstance, role: &Role) -> RbacRow {
+    let mut accesses: Vec<&Access> = role.accesses.iter().flatten().collect();
+    accesses.sort_by_key(|a| a.name());
+
+    let mut hostgroups: Vec<String> = role
+        .access_hostgroups
+        .iter()
+        .flat_map(|groups| groups.values())
+        .map(|g| {
+            g.matpath()
+                .map(|m| m.trim_end_matches(',').to_string())
+                .unwrap_or_else(|| g.name())
+        })
+        .collect();
+    hostgroups.sort();
+
+    let mut hashtags: Vec<String> = role
+        .access_keywords
+        .iter()
+        .flat_map(|keywords| keywords.values().map(|k| k.name()))
+        .collect();
+    hashtags.sort();
+
+    let tenancy = role.tenancy.as_ref().map(|t| t.name()).or_else(|| {
+        instance
+            .tenancies
+            .values()
+            .find(|t| {
+                t.primary_role
+                    .as_ref()
+                    .is_some_and(|r| r.name() == role.name)
+            })
+            .map(|t| t.name.clone())
+    });
+
+    let all_hostgroups = role.all_hostgroups == Some(true);
+    let mut flags = Vec::new();
+    if accesses.iter().any(|a| matches!(a, Access::AdminAccess(_))) {
+        flags.push(RbacFlag::AdminAccess);
+    }
+    if accesses
+        .iter()
+        .any(|a| matches!(a, Access::ConfigureRoles(_)))
+    {
+        flags.push(RbacFlag::ConfigureRoles);
+    }
+    if all_hostgroups {
+        flags.push(RbacFlag::AllHostGroups);
+    }
+
+    RbacRow {
+        contact: None,
+        role: role.name.clone(),
+        tenancy,
+        accesses: accesses.iter().map(|a| a.name().to_string()).collect(),
+        all_hostgroups,
+        hostgroups,
+        all_hashtags: role.all_keywords == Some(true),
+        hashtags,
+        flags,
+    }
+}
+
+fn scope(all: bool, items: &[String]) -> String {
+    if all {
+        "ALL".to_string()
+    } else {
+        items.join(";")
+    }
+}
+
+fn csv_escape(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn instance() -> OpsviewInstance {
+        OpsviewInstance::from_fixture(json!({
+            "roles": [
+                {
+                    "name": "Admin",
+                    "accesses": [{"name": "VIEWALL"}, {"name": "ADMINACCESS"}, {"name": "CONFIGUREROLES"}],
+                    "all_hostgroups": "1",
+                    "all_keywords": "1"
+                },
+                {
+                    "name": "EU, Operators",
+                    "accesses": [{"name": "VIEWSOME"}, {"name": "ACTIONSOME"}],
+                    "access_hostgroups": [
+                        {"name": "Web", "matpath": "Opsview,EU,Web,"},
+                        {"name": "DB", "matpath": "Opsview,EU,DB,"}
+                    ],
+                    "access_keywords": [{"name": "payments"}],
+                    "tenancy": {"name": "EU"}
+                },
+                {"name": "Unused", "accesses": []}
+            ],
+            "contacts": [
+                {"name": "bob", "role": {"name": "EU, Operators"}},
+                {"name": "alice", "role": {"name": "Admin"}}
+            ]
+        }))
+    }
+
+    #[test]
+    fn test_matrix_rows_and_flags() {
+        let matrix = RbacMatrix::from_instance(&instance());
+
+        let rows: Vec<(Option<&str>, &str)> = matrix
+            .rows
+            .iter()
+            .map(|r| (r.contact.as_deref(), r.role.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (Some("alice"), "Admin"),
+                (Some("bob"), "EU, Operators"),
+                (None, "Unused")
+            ]
+        );
+
+        assert_eq!(
+            matrix.rows[0].flags,
+            vec![
+                RbacFlag::AdminAccess,
+                RbacFlag::ConfigureRoles,
+                RbacFlag::AllHostGroups
+            ]
+        );
+        assert_eq!(
+            matrix.rows[1].hostgroups,
+            vec!["Opsview,EU,DB", "Opsview,EU,Web"]
+        );
+        assert_eq!(matrix.rows[1].tenancy.as_deref(), Some("EU"));
+        assert_eq!(matrix.roles_flagged(RbacFlag::AdminAccess), vec!["Admin"]);
+        assert_eq!(
+            matrix.contacts_flagged(RbacFlag::AllHostGroups),
+            vec!["alice"]
+        );
+    }
+
+    #[test]
+    fn test_exports() {
+        let matrix = RbacMatrix::from_instance(&instance());
+
+        let csv = matrix.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "contact,role,tenancy,accesses,hostgroups,hashtags,flags"
+        );
+        assert_eq!(
+            lines[1],
+            "alice,Admin,,ADMINACCESS;CONFIGUREROLES;VIEWALL,ALL,ALL,ADMINACCESS;CONFIGUREROLES;ALL_HOSTGROUPS"
+        );
+        assert_eq!(
+            lines[2],
+            "bob,\"EU, Operators\",EU,ACTIONSOME;VIEWSOME,\"Opsview,EU,DB;Opsview,EU,Web\",payments,"
+        );
+
+        let markdown = matrix.to_markdown();
+        assert!(markdown.starts_with("| contact | role |"));
+        assert!(markdown.contains("| bob | EU, Operators | EU | ACTIONSOME; VIEWSOME |"));
+
+        let json = matrix.to_json();
+        assert_eq!(json[0]["flags"][0], "ADMINACCESS");
+        assert_eq!(json[2]["contact"], Value::Null);
+    }
+}
@@ -0,0 +1,390 @@
+use crate::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::time::Duration;
+
+/// Represents whether there are configuration changes waiting to be applied.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum ConfigurationStatus {
+    /// All configuration changes have been applied.
+    #[serde(rename = "uptodate")]
+    UpToDate,
+    /// There are configuration changes waiting to be applied.
+    #[serde(rename = "pending")]
+    Pending,
+}
+
+/// Represents the status of the Opsview orchestrator as reported by the `/rest/reload` endpoint.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ServerStatus {
+    /// The server is running without any warnings.
+    Running,
+    /// The server is currently reloading.
+    Reloading,
+    /// The server is not running.
+    NotRunning,
+    /// The last reload failed because of a configuration error.
+    ConfigurationError,
+    /// The server is running, but the last reload produced warnings.
+    RunningWithWarnings,
+}
+
+impl ServerStatus {
+    /// Returns the numeric code used by the Opsview API for this status.
+    pub fn code(&self) -> u64 {
+        match self {
+            ServerStatus::Running => 0,
+            ServerStatus::Reloading => 1,
+            ServerStatus::NotRunning => 2,
+            ServerStatus::ConfigurationError => 3,
+            ServerStatus::RunningWithWarnings => 4,
+        }
+    }
+}
+
+impl TryFrom<u64> for ServerStatus {
+    type Error = OpsviewClientError;
+
+    fn try_from(code: u64) -> Result<Self, Self::Error> {
+        match code {
+            0 => Ok(ServerStatus::Running),
+            1 => Ok(ServerStatus::Reloading),
+            2 => Ok(ServerStatus::NotRunning),
+            3 => Ok(ServerStatus::ConfigurationError),
+            4 => Ok(ServerStatus::RunningWithWarnings),
+            _ => Err(OpsviewClientError::TypeParseError(
+                code.to_string(),
+                "ServerStatus".to_string(),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ServerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ServerStatus::Running => write!(f, "Server running"),
+            ServerStatus::Reloading => write!(f, "Server reloading"),
+            ServerStatus::NotRunning => write!(f, "Server not running"),
+            ServerStatus::ConfigurationError => write!(f, "Configuration error"),
+            ServerStatus::RunningWithWarnings => write!(f, "Server running with warnings"),
+        }
+    }
+}
+
+impl Serialize for ServerStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.code().to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ServerStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = deserialize_string_or_number_to_u64(deserializer)?
+            .ok_or_else(|| serde::de::Error::custom("server_status must not be null"))?;
+        ServerStatus::try_from(code).map_err(serde::de::Error::custom)
+    }
+}
+
+/// The severity of a [`ReloadMessage`].
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageSeverity {
+    /// An informational message.
+    #[serde(alias = "information")]
+    Info,
+    /// A warning that did not prevent the reload from completing.
+    Warning,
+    /// An error that caused the reload to fail.
+    #[serde(alias = "critical")]
+    Error,
+}
+
+/// A message produced by a reload of the Opsview configuration.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ReloadMessage {
+    /// The severity of the message.
+    pub severity: MessageSeverity,
+    /// The text of the message.
+    #[serde(alias = "message")]
+    pub detail: String,
+    /// The monitoring server that produced the message, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monitoringserver: Option<String>,
+}
+
+impl fmt::Display for ReloadMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let severity = match self.severity {
+            MessageSeverity::Info => "INFO",
+            MessageSeverity::Warning => "WARNING",
+            MessageSeverity::Error => "ERROR",
+        };
+        match &self.monitoringserver {
+            Some(server) => write!(f, "{} [{}]: {}", severity, server, self.detail),
+            None => write!(f, "{}: {}", severity, self.detail),
+        }
+    }
+}
+
+/// The status of the Opsview configuration as returned by the `/rest/reload` endpoint.
+///
+/// Example response:
+/// ```json
+/// {
+///   "auditlog_entries": "0",
+///   "average_duration": "10",
+///   "configuration_status": "uptodate",
+///   "lastupdated": "1702453777",
+///   "messages": [],
+///   "server_status": "0"
+/// }
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ReloadStatus {
+    /// Whether there are changes waiting to be applied.
+    pub configuration_status: ConfigurationStatus,
+    /// The status of the orchestrator.
+    pub server_status: ServerStatus,
+    /// The average duration of a reload in seconds.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_string_or_number_to_u64",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub average_duration: Option<u64>,
+    /// The number of audit log entries since the last reload.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_string_or_number_to_u64",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub auditlog_entries: Option<u64>,
+    /// A unix timestamp of when the configuration was last updated.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_string_or_number_to_u64",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lastupdated: Option<u64>,
+    /// Any messages produced by the last reload.
+    #[serde(default)]
+    pub messages: Vec<ReloadMessage>,
+}
+
+impl ReloadStatus {
+    /// Returns true if there are configuration changes waiting to be applied.
+    pub fn has_pending_changes(&self) -> bool {
+        self.configuration_status == ConfigurationStatus::Pending
+    }
+
+    /// Returns true if a reload is currently in progress.
+    pub fn is_reloading(&self) -> bool {
+        self.server_status == ServerStatus::Reloading
+    }
+
+    /// Returns an iterator over the messages of the given severity.
+    pub fn messages_with_severity(
+        &self,
+        severity: MessageSeverity,
+    ) -> impl Iterator<Item = &ReloadMessage> {
+        self.messages.iter().filter(move |m| m.severity == severity)
+    }
+
+    /// Returns the structured outcome of the last reload.
+    ///
+    /// Returns `None` while a reload is still in progress. A reload that produced error messages
+    /// is reported as failed, even if the server reports that it is running.
+    pub fn outcome(&self) -> Option<ReloadOutcome> {
+        let collect = |severity| {
+            self.messages_with_severity(severity)
+                .cloned()
+                .collect::<Vec<ReloadMessage>>()
+        };
+
+        match self.server_status {
+            ServerStatus::Reloading => None,
+            ServerStatus::ConfigurationError | ServerStatus::NotRunning => {
+                Some(ReloadOutcome::Failed {
+                    status: self.server_status,
+                    errors: collect(MessageSeverity::Error),
+                    warnings: collect(MessageSeverity::Warning),
+                })
+            }
+            ServerStatus::Running | ServerStatus::RunningWithWarnings => {
+                let errors = collect(MessageSeverity::Error);
+                let warnings = collect(MessageSeverity::Warning);
+                if !errors.is_empty() {
+                    Some(ReloadOutcome::Failed {
+                        status: self.server_status,
+                        errors,
+                        warnings,
+                    })
+                } else if warnings.is_empty() && self.server_status == ServerStatus::Running {
+                    Some(ReloadOutcome::Success)
+                } else {
+                    Some(ReloadOutcome::SuccessWithWarnings(warnings))
+                }
+            }
+        }
+    }
+}
+
+/// The outcome of a finished reload.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReloadOutcome {
+    /// The reload completed without any warnings.
+    Success,
+    /// The reload completed, but produced warnings.
+    SuccessWithWarnings(Vec<ReloadMessage>),
+    /// The reload failed.
+    Failed {
+        /// The server status after the failed reload.
+        status: ServerStatus,
+        /// The error messages produced by the reload.
+        errors: Vec<ReloadMessage>,
+        /// The warning messages produced by the reload.
+        warnings: Vec<ReloadMessage>,
+    },
+}
+
+impl ReloadOutcome {
+    /// Returns true if the reload completed, with or without warnings.
+    pub fn is_success(&self) -> bool {
+        !matches!(self, ReloadOutcome::Failed { .. })
+    }
+}
+
+/// Progress of a reload started by
+/// [`OpsviewClient::apply_changes_and_wait`](crate::client::OpsviewClient::apply_changes_and_wait).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReloadProgress {
+    /// The reload is still in progress.
+    Reloading {
+        /// The time elapsed since the reload was requested.
+        elapsed: Duration,
+        /// The average duration of a reload as reported by the server, if known.
+        average_duration: Option<Duration>,
+    },
+    /// The reload has finished, successfully or not.
+    Finished {
+        /// The status reported by the server once the reload finished.
+        status: ReloadStatus,
+        /// The structured outcome of the reload.
+        outcome: ReloadOutcome,
+    },
+}
+
+impl ReloadProgress {
+    /// Returns the estimated completion of the reload as a fraction between 0.0 and 1.0, based on
+    /// the average reload duration reported by the server.
+    ///
+    /// Returns `None` if the average duration is unknown. The estimate never reaches 1.0 until the
+    /// reload has actually finished.
+    pub fn estimated_fraction(&self) -> Option<f64> {
+        match self {
+            ReloadProgress::Finished { .. } => Some(1.0),
+            ReloadProgress::Reloading {
+                elapsed,
+                average_duration: Some(average),
+            } if !average.is_zero() => {
+                Some((elapsed.as_secs_f64() / average.as_secs_f64()).min(0.99))
+            }
+            ReloadProgress::Reloading { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_reload_status() {
+        let json = r#"{
+            "auditlog_entries": "2",
+            "average_duration": "10",
+            "configuration_status": "pending",
+            "lastupdated": "1702453777",
+            "messages": [
+                {"severity": "warning", "detail": "Host 'foo' has no service checks", "monitoringserver": "Master"}
+            ],
+            "server_status": "4"
+        }"#;
+
+        let status: ReloadStatus = serde_json::from_str(json).unwrap();
+
+        assert!(status.has_pending_changes());
+        assert!(!status.is_reloading());
+        assert_eq!(status.server_status, ServerStatus::RunningWithWarnings);
+        assert_eq!(status.average_duration, Some(10));
+        assert_eq!(status.auditlog_entries, Some(2));
+        assert_eq!(
+            status.messages[0].to_string(),
+            "WARNING [Master]: Host 'foo' has no service checks"
+        );
+        assert_eq!(
+            status.outcome(),
+            Some(ReloadOutcome::SuccessWithWarnings(status.messages.clone()))
+        );
+    }
+
+    #[test]
+    fn test_reload_outcome_failed() {
+        let json = r#"{
+            "configuration_status": "pending",
+            "messages": [{"severity": "error", "detail": "Invalid host"}],
+            "server_status": 3
+        }"#;
+
+        let status: ReloadStatus = serde_json::from_str(json).unwrap();
+        let outcome = status.outcome().unwrap();
+
+        assert!(!outcome.is_success());
+        assert_eq!(
+            outcome,
+            ReloadOutcome::Failed {
+                status: ServerStatus::ConfigurationError,
+                errors: status.messages.clone(),
+                warnings: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_reload_outcome_running_with_errors() {
+        let json = r#"{
+            "configuration_status": "uptodate",
+            "messages": [
+                {"severity": "critical", "detail": "Collector unreachable"},
+                {"severity": "warning", "detail": "Host 'foo' has no service checks"}
+            ],
+            "server_status": "0"
+        }"#;
+
+        let status: ReloadStatus = serde_json::from_str(json).unwrap();
+        let outcome = status.outcome().unwrap();
+
+        assert!(!outcome.is_success());
+        assert_eq!(
+            outcome,
+            ReloadOutcome::Failed {
+                status: ServerStatus::Running,
+                errors: vec![status.messages[0].clone()],
+                warnings: vec![status.messages[1].clone()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_server_status() {
+        let json = r#"{"configuration_status": "uptodate", "server_status": "9"}"#;
+        assert!(serde_json::from_str::<ReloadStatus>(json).is_err());
+    }
+}
@@ -1,4 +1,7 @@
+use crate::error::OpsviewClientError;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 
 /// Represents the
 /// [state](https://docs.itrsgroup.com/docs/opsview/6.9.0/getting-started/important-concepts/index.html#states)
@@ -16,6 +19,34 @@ pub enum HostState {
     Unreachable,
 }
 
+impl fmt::Display for HostState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HostState::Up => write!(f, "UP"),
+            HostState::Down => write!(f, "DOWN"),
+            HostState::Unreachable => write!(f, "UNREACHABLE"),
+        }
+    }
+}
+
+impl FromStr for HostState {
+    type Err = OpsviewClientError;
+
+    /// Parses a `HostState` from either its numeric code (`"0"`, `"1"`, `"2"`) or its name in any
+    /// case (`"up"`, `"DOWN"`, `"Unreachable"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "0" | "up" => Ok(HostState::Up),
+            "1" | "down" => Ok(HostState::Down),
+            "2" | "unreachable" => Ok(HostState::Unreachable),
+            _ => Err(OpsviewClientError::TypeParseError(
+                s.to_string(),
+                "HostState".to_string(),
+            )),
+        }
+    }
+}
+
 /// Represents the
 /// [state](https://docs.itrsgroup.com/docs/opsview/6.9.0/getting-started/important-concepts/index.html#states)
 /// of a [`ServiceCheck`](crate::config::ServiceCheck) in Opsview.
@@ -34,3 +65,69 @@ pub enum ServiceCheckState {
     #[serde(rename = "3")]
     Unknown,
 }
+
+impl fmt::Display for ServiceCheckState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ServiceCheckState::Ok => write!(f, "OK"),
+            ServiceCheckState::Warning => write!(f, "WARNING"),
+            ServiceCheckState::Critical => write!(f, "CRITICAL"),
+            ServiceCheckState::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl FromStr for ServiceCheckState {
+    type Err = OpsviewClientError;
+
+    /// Parses a `ServiceCheckState` from either its numeric code (`"0"` to `"3"`) or its name in
+    /// any case (`"ok"`, `"WARNING"`, `"Critical"`, `"unknown"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "0" | "ok" => Ok(ServiceCheckState::Ok),
+            "1" | "warning" => Ok(ServiceCheckState::Warning),
+            "2" | "critical" => Ok(ServiceCheckState::Critical),
+            "3" | "unknown" => Ok(ServiceCheckState::Unknown),
+            _ => Err(OpsviewClientError::TypeParseError(
+                s.to_string(),
+                "ServiceCheckState".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_state_from_str() {
+        assert_eq!("0".parse::<HostState>(), Ok(HostState::Up));
+        assert_eq!("DOWN".parse::<HostState>(), Ok(HostState::Down));
+        assert_eq!(
+            "unreachable".parse::<HostState>(),
+            Ok(HostState::Unreachable)
+        );
+        assert!("3".parse::<HostState>().is_err());
+    }
+
+    #[test]
+    fn test_service_check_state_from_str() {
+        assert_eq!("ok".parse::<ServiceCheckState>(), Ok(ServiceCheckState::Ok));
+        assert_eq!(
+            "1".parse::<ServiceCheckState>(),
+            Ok(ServiceCheckState::Warning)
+        );
+        assert_eq!(
+            "Critical".parse::<ServiceCheckState>(),
+            Ok(ServiceCheckState::Critical)
+        );
+        assert_eq!(
+            ServiceCheckState::Unknown
+                .to_string()
+                .parse::<ServiceCheckState>(),
+            Ok(ServiceCheckState::Unknown)
+        );
+        assert!("up".parse::<ServiceCheckState>().is_err());
+    }
+}
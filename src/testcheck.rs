@@ -0,0 +1,328 @@
+use crate::expansion::MacroExpander;
+use crate::{config::*, perfdata::PluginOutput, prelude::*, state::ServiceCheckState};
+use serde_json::{json, Value};
+
+/// A request to test-run a plugin with arguments against a [`Host`] on a monitoring cluster,
+/// without saving any configuration.
+///
+/// Use [`ServiceCheckTest::builder`] to construct a new test from either a [`ServiceCheck`] or a
+/// [`Plugin`] with arguments, and pass it to
+/// [`OpsviewClient::test_service_check`](crate::client::OpsviewClient::test_service_check).
+///
+/// # Example
+/// ```rust
+/// use opsview::config::{Host, ServiceCheck};
+/// use opsview::prelude::*;
+/// use opsview::testcheck::ServiceCheckTest;
+///
+/// let mut host = Host::minimal("web01").unwrap();
+/// host.ip = Some("192.168.1.10".to_string());
+///
+/// let test = ServiceCheckTest::builder()
+///     .plugin_name("check_http")
+///     .args("-H %HOSTADDRESS% -u /health")
+///     .host(&host)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(test.command_args(), "-H 192.168.1.10 -u /health");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ServiceCheckTest {
+    /// The name of the plugin to run.
+    pub plugin: String,
+    /// The arguments to pass to the plugin, before host macros are substituted.
+    pub args: String,
+    /// The host to run the plugin against.
+    pub host: Host,
+    /// The name of the monitoring cluster to run the plugin on. Defaults to the cluster
+    /// monitoring the host.
+    pub monitoring_cluster: Option<String>,
+}
+
+impl ServiceCheckTest {
+    /// Returns a builder for constructing a `ServiceCheckTest`.
+    pub fn builder() -> ServiceCheckTestBuilder {
+        ServiceCheckTestBuilder::default()
+    }
+
+    /// Returns the arguments with the host macros substituted.
+    ///
+    /// Macros are expanded by a [`MacroExpander`] using only the host's own variables. Macros that
+    /// cannot be resolved, including those of encrypted arguments, are left untouched.
+    pub fn command_args(&self) -> String {
+        MacroExpander::default()
+            .expand_args(&self.args, &self.host)
+            .args
+    }
+
+    /// Returns the body of the request sent to the `/rest/testservicecheck` endpoint.
+    pub(crate) fn request_body(&self) -> Value {
+        let monitored_by = self
+            .monitoring_cluster
+            .clone()
+            .or_else(|| self.host.monitored_by.as_ref().map(|m| m.name()));
+
+        let mut body = json!({
+            "plugin": self.plugin,
+            "args": self.command_args(),
+            "hostname": self.host.name,
+        });
+
+        if let Some(monitored_by) = monitored_by {
+            body["monitored_by"] = json!(monitored_by);
+        }
+
+        body
+    }
+}
+
+/// Builder for [`ServiceCheckTest`].
+#[derive(Clone, Debug, Default)]
+pub struct ServiceCheckTestBuilder {
+    plugin: Option<String>,
+    args: Option<String>,
+    host: Option<Host>,
+    monitoring_cluster: Option<String>,
+}
+
+impl ServiceCheckTestBuilder {
+    /// Sets the plugin and arguments from a [`ServiceCheck`].
+    ///
+    /// # Arguments
+    /// * `service_check` - The `ServiceCheck` to test.
+    pub fn service_check(mut self, service_check: &ServiceCheck) -> Self {
+        self.plugin = service_check.plugin.as_ref().map(|p| p.name.clone());
+        self.args = service_check.args.clone();
+        self
+    }
+
+    /// Sets the plugin to run.
+    ///
+    /// # Arguments
+    /// * `plugin` - The `Plugin` to run.
+    pub fn plugin(mut self, plugin: &Plugin) -> Self {
+        self.plugin = Some(plugin.name.clone());
+        self
+    }
+
+    /// Sets the name of the plugin to run.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the plugin to run.
+    pub fn plugin_name(mut self, name: &str) -> Self {
+        self.plugin = Some(name.to_string());
+        self
+    }
+
+    /// Sets the arguments to pass to the plugin.
+    ///
+    /// # Arguments
+    /// * `args` - The arguments, which may contain host macros such as `%HOSTADDRESS%`.
+    pub fn args(mut self, args: &str) -> Self {
+        self.args = Some(args.to_string());
+        self
+    }
+
+    /// Sets the host to run the plugin against.
+    ///
+    /// # Arguments
+    /// * `host` - The `Host` to run the plugin against.
+    pub fn host(mut self, host: &Host) -> Self {
+        self.host = Some(host.clone());
+        self
+    }
+
+    /// Sets the monitoring cluster to run the plugin on.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the monitoring cluster.
+    pub fn monitoring_cluster(mut self, name: &str) -> Self {
+        self.monitoring_cluster = Some(name.to_string());
+        self
+    }
+
+    /// Builds a new `ServiceCheckTest`.
+    ///
+    /// # Errors
+    /// Returns an `OpsviewConfigError::RequiredFieldEmpty` if the plugin or host is not set.
+    pub fn build(self) -> Result<ServiceCheckTest, OpsviewConfigError> {
+        let plugin = self
+            .plugin
+            .filter(|p| !p.is_empty())
+            .ok_or_else(|| OpsviewConfigError::RequiredFieldEmpty("plugin".to_string()))?;
+
+        let host = self
+            .host
+            .ok_or_else(|| OpsviewConfigError::RequiredFieldEmpty("host".to_string()))?;
+
+        Ok(ServiceCheckTest {
+            plugin,
+            args: self.args.unwrap_or_default(),
+            host,
+            monitoring_cluster: self.monitoring_cluster,
+        })
+    }
+}
+
+/// The result of a test-run of a plugin, as returned by
+/// [`OpsviewClient::test_service_check`](crate::client::OpsviewClient::test_service_check).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceCheckTestResult {
+    /// The state corresponding to the exit code of the plugin. Exit codes outside of 0-3 are
+    /// reported as `Unknown`.
+    pub state: ServiceCheckState,
+    /// The exit code of the plugin.
+    pub exit_code: i64,
+    /// The output of the plugin, split into text and performance data.
+    pub output: PluginOutput,
+    /// The output of the plugin exactly as returned by the server.
+    pub raw_output: String,
+    /// The arguments the plugin was run with, after host macros were substituted.
+    pub args: String,
+}
+
+impl ServiceCheckTestResult {
+    /// Parses the response of the `/rest/testservicecheck` endpoint.
+    pub(crate) fn from_response(
+        args: String,
+        response: &Value,
+    ) -> Result<Self, OpsviewClientError> {
+        let exit_code = ["exit_code", "return_code", "rc"]
+            .iter()
+            .find_map(|key| response.get(*key))
+            .and_then(|v| match v {
+                Value::Number(n) => n.as_i64(),
+                Value::String(s) => s.parse().ok(),
+                _ => None,
+            })
+            .ok_or_else(|| OpsviewClientError::MissingResponseField("exit_code".to_string()))?;
+
+        let raw_output = ["output", "stdout"]
+            .iter()
+            .find_map(|key| response.get(*key).and_then(|v| v.as_str()))
+            .unwrap_or_default()
+            .to_string();
+
+        let state = match exit_code {
+            0 => ServiceCheckState::Ok,
+            1 => ServiceCheckState::Warning,
+            2 => ServiceCheckState::Critical,
+            _ => ServiceCheckState::Unknown,
+        };
+
+        // Plugins do not always produce valid perfdata, which should not hide their output.
+        let output = raw_output
+            .parse::<PluginOutput>()
+            .unwrap_or_else(|_| PluginOutput {
+                text: raw_output.lines().next().unwrap_or_default().to_string(),
+                ..Default::default()
+            });
+
+        Ok(ServiceCheckTestResult {
+            state,
+            exit_code,
+            output,
+            raw_output,
+            args,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn host() -> Host {
+        let mut host = Host::minimal("web01").unwrap();
+        host.ip = Some("10.0.0.1".to_string());
+
+        let mut variables = ConfigRefMap::new();
+        variables.add(
+            serde_json::from_value::<HostVariableRef>(json!({
+                "name": "URL",
+                "value": "/health",
+                "arg1": "8080",
+                "arg2": null,
+                "arg3": null,
+                "arg4": null
+            }))
+            .unwrap(),
+        );
+        host.hostattributes = Some(variables);
+        host
+    }
+
+    #[test]
+    fn test_command_args() {
+        let test = ServiceCheckTest::builder()
+            .plugin_name("check_http")
+            .args("-H %HOSTADDRESS% -u %URL% -p %URL:ARG1% -x %MISSING%")
+            .host(&host())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            test.command_args(),
+            "-H 10.0.0.1 -u /health -p 8080 -x %MISSING%"
+        );
+        assert_eq!(test.request_body()["hostname"], "web01");
+    }
+
+    #[test]
+    fn test_command_args_do_not_include_encrypted_arguments() {
+        let mut host = host();
+        host.hostattributes.as_mut().unwrap().add(
+            serde_json::from_value::<HostVariableRef>(json!({
+                "name": "CREDENTIALS",
+                "value": "admin",
+                "encrypted_arg1": "aGVsbG8gd29ybGQ="
+            }))
+            .unwrap(),
+        );
+
+        let test = ServiceCheckTest::builder()
+            .plugin_name("check_http")
+            .args("-a %CREDENTIALS%:%CREDENTIALS:ARG1%")
+            .host(&host)
+            .build()
+            .unwrap();
+
+        assert_eq!(test.command_args(), "-a admin:%CREDENTIALS:ARG1%");
+    }
+
+    #[test]
+    fn test_builder_requires_plugin_and_host() {
+        assert!(ServiceCheckTest::builder().host(&host()).build().is_err());
+        assert!(ServiceCheckTest::builder()
+            .plugin_name("check_ping")
+            .build()
+            .is_err());
+
+        let check = ServiceCheck::minimal("Ping").unwrap();
+        assert!(ServiceCheckTest::builder()
+            .service_check(&check)
+            .host(&host())
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_result_from_response() {
+        let response = json!({
+            "exit_code": "2",
+            "output": "CRITICAL - down|time=5s;1;2"
+        });
+
+        let result = ServiceCheckTestResult::from_response(String::new(), &response).unwrap();
+        assert_eq!(result.state, ServiceCheckState::Critical);
+        assert_eq!(result.output.text, "CRITICAL - down");
+        assert_eq!(result.output.perfdata[0].label, "time");
+
+        let response = json!({"return_code": 127, "stdout": "not found"});
+        let result = ServiceCheckTestResult::from_response(String::new(), &response).unwrap();
+        assert_eq!(result.state, ServiceCheckState::Unknown);
+
+        assert!(ServiceCheckTestResult::from_response(String::new(), &json!({})).is_err());
+    }
+}
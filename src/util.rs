@@ -1,5 +1,6 @@
 #![allow(missing_docs)]
 use crate::prelude::*;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use decimal_percentage::Percentage;
 use lazy_static::lazy_static;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
@@ -14,6 +15,35 @@ pub fn is_valid_past_unix_timestamp(ts: u64) -> bool {
     }
 }
 
+/// The format used by the Opsview API for timestamps in query parameters and filters.
+pub const API_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Parses a timestamp returned by the Opsview API, which may be either a unix timestamp (as a
+/// number or a string), an RFC 3339 timestamp, or a timestamp in the format `YYYY-MM-DD HH:MM:SS`
+/// (UTC).
+pub fn parse_api_timestamp(value: &serde_json::Value) -> Result<DateTime<Utc>, OpsviewClientError> {
+    let parse_error =
+        || OpsviewClientError::TypeParseError(value.to_string(), "DateTime<Utc>".to_string());
+
+    let from_unix = |ts: i64| DateTime::<Utc>::from_timestamp(ts, 0).ok_or_else(parse_error);
+
+    match value {
+        serde_json::Value::Number(n) => from_unix(n.as_i64().ok_or_else(parse_error)?),
+        serde_json::Value::String(s) => {
+            if let Ok(ts) = s.parse::<i64>() {
+                return from_unix(ts);
+            }
+            if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+                return Ok(dt.with_timezone(&Utc));
+            }
+            NaiveDateTime::parse_from_str(s, API_TIME_FORMAT)
+                .map(|dt| dt.and_utc())
+                .map_err(|_| parse_error())
+        }
+        _ => Err(parse_error()),
+    }
+}
+
 // Validation related functions and constants
 
 pub const BSM_COMPONENT_NAME_REGEX_STR: &str = r"^[\p{L}\p{N}][\p{L}\p{N}\p{S}\p{P} ]*$";
@@ -38,7 +68,7 @@ pub const SERVICECHECK_LABEL_REGEX_STR: &str = r"^[\p{L}\p{N}\-_]*$";
 pub const SERVICECHECK_NAME_REGEX_STR: &str = r"^[\p{L}\p{N}./\-_ ]+$";
 pub const SERVICECHECK_NOTIFICATION_OPTIONS_REGEX_STR: &str = r"^[wcurfsn]*(,[wcurfs])*$";
 pub const SERVICECHECK_OID_REGEX_STR: &str = r#"^ *[a-zA-Z0-9.:\-"/_]+$"#;
-pub const SERVICECHECK_STALKING_REGEX_STR: &str = r"^[wcuo](,[wcuo])*$";
+pub const SERVICECHECK_STALKING_REGEX_STR: &str = r"^([wcuo](,[wcuo])*)?$";
 pub const SERVICEGROUP_NAME_REGEX_STR: &str = r"^[\p{L}\p{N}][\p{L}\p{N} ./+\-_]*$";
 pub const TENANCY_NAME_REGEX_STR: &str = r"^[\p{L}\p{N}][\p{L}\p{N}\p{S}\p{P} ]*$";
 pub const TIMEPERIOD_ALIAS_REGEX_STR: &str = r"^[\p{L}\p{N} .,:/\-_]*$";
@@ -333,17 +363,6 @@ pub fn validate_and_trim_notificationprofile_bsm_service_options(
     )
 }
 
-pub fn validate_and_trim_other_addresses(
-    other_addresses: &str,
-) -> Result<String, OpsviewConfigError> {
-    let trimmed_other_addresses = other_addresses.trim_end();
-    let addresses = trimmed_other_addresses.split(',').map(str::trim);
-    for addr in addresses {
-        validate_and_trim_ip_or_hostname(addr)?;
-    }
-    Ok(trimmed_other_addresses.to_string())
-}
-
 pub fn validate_rancid_password(password: &str) -> Result<String, OpsviewConfigError> {
     contains_only_allowed_characters(
         &validate_untrimmed_string(password, 0, 255, &INLINE_FREE_TEXT_REGEX)?,
@@ -538,24 +557,6 @@ mod tests {
         assert!(validate_and_trim_variable_name(&"A".repeat(65)).is_err()); // Exceeds max length
     }
 
-    #[test]
-    fn test_valid_other_addresses_succeeds() {
-        let valid_addresses = [
-            "192.168.1.1,127.0.1.1",
-            "localhost,192.168.1.1",
-            "foo.bar,localhost",
-            "foo-bar,foo.bar,127.0.0.1",
-        ];
-
-        for &address in &valid_addresses {
-            assert!(
-                validate_and_trim_other_addresses(address).is_ok(),
-                "Expected '{}' to be valid",
-                address
-            );
-        }
-    }
-
     #[test]
     fn test_is_invalid_rancid_password() {
         let invalid_passwords = ["rancid}", " {foo", &"a".repeat(256)];
@@ -604,33 +605,6 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_invalid_other_addresses_fails() {
-        let invalid_addresses = [
-            "foo_bar,foo-bar,foo.bar,,",
-            "foo_bar,foo-bar,foo.bar, ",
-            "foo_bar,foo-bar,foo.bar, ,",
-            "foo_bar,foo-bar,foo.bar, , ",
-            ",foo_bar,foo-bar,foo.bar",
-            " ,foo_bar,foo-bar,foo.bar",
-            "192.168.1.1,https://example.com",
-            "127.0.0.1,foo?bar",
-            "localhost,foo@bar",
-            "",
-            " ",
-            " ,",
-            ",",
-        ];
-
-        for &address in &invalid_addresses {
-            assert!(
-                validate_and_trim_other_addresses(address).is_err(),
-                "Expected '{}' to be invalid",
-                address
-            );
-        }
-    }
-
     #[test]
     fn test_is_valid_notification_profile_name() {
         // Test valid names
@@ -74,6 +74,21 @@ pub const ALL_VARIABLE_CONFIGS_PAGE_1: &str = r#"{"summary":{"allrows":"179","to
 pub const ALL_VARIABLE_CONFIGS_PAGE_2: &str = r#"{"summary":{"totalpages":"4","rows":"50","totalrows":"179","page":"2","allrows":"179"},"list":[{"secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":"","uncommitted":"0","id":"138","name":"CAPACITY_PLANNER_TAG","label3":"","arg2":"","secured4":"0","servicechecks":[{"ref":"/rest/config/servicecheck/1194","name":"Cap Plan Export - Tag"}],"label1":"Tag Value","label4":"","arg1":"","value":"","ref":"/rest/config/attribute/138","arg4":""},{"arg1":"-w 5% -c 2%","label4":"","ref":"/rest/config/attribute/146","arg4":"","value":"","arg2":"","label3":"","name":"DISK","secured4":"0","servicechecks":[{"name":"Disk","ref":"/rest/config/servicecheck/1301"},{"name":"Read-only Partitions","ref":"/rest/config/servicecheck/1297"}],"label1":"Warning/Critical values","uncommitted":"0","id":"146","secured2":"0","arg3":"","label2":"","secured3":"0","secured1":"0"},{"label2":"","secured2":"0","arg3":"","secured1":"0","secured3":"0","label3":"","arg2":"","name":"DOCKER_CONTAINER","servicechecks":[{"ref":"/rest/config/servicecheck/514","name":"Docker - Container CPU usage"},{"name":"Docker - Container memory usage","ref":"/rest/config/servicecheck/513"},{"ref":"/rest/config/servicecheck/512","name":"Docker - Container size"},{"ref":"/rest/config/servicecheck/511","name":"Docker - Container top output"},{"name":"Docker - Container uptime","ref":"/rest/config/servicecheck/510"}],"label1":"","secured4":"0","arg1":"","label4":"","value":"ContainerName","ref":"/rest/config/attribute/72","arg4":"","uncommitted":"0","id":"72"},{"uncommitted":"0","id":"71","label4":"","arg1":"15","value":"Options can be amended on a per host basis or left as global.","ref":"/rest/config/attribute/71","arg4":"","label3":"URL Prefix","name":"DOCKER_OPTS","arg2":"4243","secured4":"0","servicechecks":[],"label1":"Timeout value","secured3":"0","secured1":"0","secured2":"0","arg3":"http://127.0.0.1","label2":"Port"},{"secured1":"0","secured3":"0","label2":"","arg3":"","secured2":"0","id":"76","uncommitted":"0","label1":"","servicechecks":[],"secured4":"0","label3":"","arg2":"","name":"ESPORT","value":"9200","arg4":"","ref":"/rest/config/attribute/76","label4":"","arg1":""},{"label2":"API Version","secured2":"0","arg3":"","secured1":"0","secured3":"0","arg2":"","label3":"","name":"ETCD","secured4":"0","servicechecks":[],"label1":"Port","arg1":"2379","label4":"","ref":"/rest/config/attribute/90","arg4":"","value":"default","uncommitted":"0","id":"90"},{"uncommitted":"0","id":"91","label4":"Key Path","arg1":"","value":"default","ref":"/rest/config/attribute/91","arg4":"","arg2":"","label3":"Certificate Path","name":"ETCD_TLS","servicechecks":[],"label1":"TLS Enabled Flag","secured4":"0","secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":"CA Path"},{"id":"68","uncommitted":"0","value":"GCP Bucket Name","arg4":"","ref":"/rest/config/attribute/68","arg1":"","label4":"","servicechecks":[],"secured4":"0","label1":"Bucket Name","label3":"","arg2":"","name":"GCP_BUCKET_NAME","secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":""},{"arg4":"","ref":"/rest/config/attribute/65","value":"Path to the Credentials JSON File","label4":"","arg1":"","label1":"Credentials File","servicechecks":[],"secured4":"0","name":"GCP_CREDENTIALS_FILE","arg2":"","label3":"","id":"65","uncommitted":"0","arg3":"","secured2":"0","label2":"","secured3":"0","secured1":"0"},{"arg3":"","secured2":"0","label2":"Instance Zone","secured3":"0","secured1":"0","arg4":"","ref":"/rest/config/attribute/67","value":"GCP Instance Details","label4":"","arg1":"","secured4":"0","servicechecks":[],"label1":"Instance Name","name":"GCP_INSTANCE_DETAILS","label3":"","arg2":"","id":"67","uncommitted":"0"},{"id":"70","uncommitted":"0","arg4":"","ref":"/rest/config/attribute/70","value":"GCP MySQL Database ID","arg1":"","label4":"","label1":"MySQL Database ID","servicechecks":[],"secured4":"0","arg2":"","name":"GCP_MYSQL_DATABASE_ID","label3":"","secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":""},{"secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":"","uncommitted":"0","id":"69","arg1":"","label4":"","ref":"/rest/config/attribute/69","arg4":"","value":"GCP PostgreSQL Database ID","name":"GCP_POSTGRESQL_DATABASE_ID","label3":"","arg2":"","label1":"PostgreSQL Database ID","servicechecks":[],"secured4":"0"},{"label2":"","secured2":"0","arg3":"","secured1":"0","secured3":"0","name":"GCP_PROJECT_ID","arg2":"","label3":"","secured4":"0","servicechecks":[],"label1":"Monitored Project ID","arg1":"","label4":"","value":"GCP Monitored Project ID","ref":"/rest/config/attribute/66","arg4":"","uncommitted":"0","id":"66"},{"secured1":"0","secured3":"0","label2":"Password","arg3":"80","secured2":"1","id":"84","uncommitted":"0","servicechecks":[],"label1":"Username","secured4":"0","name":"HAPROXY","label3":"Port","arg4":"haproxy?stats","ref":"/rest/config/attribute/84","value":"","arg1":"","label4":"Stats Path"},{"servicechecks":[],"label1":"CA Certificate Path","secured4":"0","label3":"Client Key Path","arg2":"","name":"HAPROXY_CERTIFICATES","value":"HAProxy certificates","arg4":"","ref":"/rest/config/attribute/86","label4":"Additional Arguments","arg1":"","id":"86","uncommitted":"0","label2":"Client Certificate Path","arg3":"","secured2":"0","secured1":"0","secured3":"0"},{"id":"85","uncommitted":"0","secured4":"0","servicechecks":[{"ref":"/rest/config/servicecheck/632","name":"Haproxy - Bytes"},{"ref":"/rest/config/servicecheck/631","name":"Haproxy - Connect Time"},{"ref":"/rest/config/servicecheck/630","name":"Haproxy - Currrent Queue"},{"ref":"/rest/config/servicecheck/629","name":"Haproxy - Denied Connection"},{"name":"Haproxy - Denied Requests","ref":"/rest/config/servicecheck/628"},{"ref":"/rest/config/servicecheck/627","name":"Haproxy - Denied Response"},{"name":"Haproxy - Denied Sessions","ref":"/rest/config/servicecheck/626"},{"ref":"/rest/config/servicecheck/625","name":"Haproxy - Error Connections"},{"ref":"/rest/config/servicecheck/624","name":"Haproxy - Error Requests"},{"ref":"/rest/config/servicecheck/623","name":"Haproxy - Error Response"},{"name":"Haproxy - Intercepted Requests","ref":"/rest/config/servicecheck/622"},{"ref":"/rest/config/servicecheck/621","name":"Haproxy - Queue Time"},{"name":"Haproxy - Request Rate","ref":"/rest/config/servicecheck/620"},{"name":"Haproxy - Request Time","ref":"/rest/config/servicecheck/619"},{"name":"Haproxy - Response Codes","ref":"/rest/config/servicecheck/618"},{"name":"Haproxy - Session Rate","ref":"/rest/config/servicecheck/617"},{"name":"Haproxy - Sessions Used","ref":"/rest/config/servicecheck/616"},{"ref":"/rest/config/servicecheck/615","name":"Haproxy - Status"},{"ref":"/rest/config/servicecheck/614","name":"Haproxy - Warnings Redispatched"},{"name":"Haproxy - Warnings Retried","ref":"/rest/config/servicecheck/613"}],"label1":"","arg2":"","name":"HAPROXY_PROXY","label3":"","value":"","arg4":"","ref":"/rest/config/attribute/85","arg1":"","label4":"","secured1":"0","secured3":"0","label2":"","arg3":"","secured2":"0"},{"uncommitted":"0","id":"112","arg1":"username","label4":"","ref":"/rest/config/attribute/112","arg4":"","value":"Hyper-V","name":"HYPER_V_CREDENTIALS","label3":"","secured4":"0","servicechecks":[],"label1":"Username","secured3":"0","secured1":"0","secured2":"1","arg3":"","label2":"Password"},{"uncommitted":"0","id":"183","label3":"","arg2":"","name":"INTERFACE","servicechecks":[{"name":"Discards","ref":"/rest/config/servicecheck/1454"},{"name":"Errors","ref":"/rest/config/servicecheck/1455"},{"name":"Interface","ref":"/rest/config/servicecheck/1456"}],"label1":"","secured4":"0","label4":"","arg1":"","ref":"/rest/config/attribute/183","arg4":"","value":"","secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":""},{"label2":"SQLITE DB ID","arg3":"","secured2":"0","secured1":"0","secured3":"0","secured4":"0","servicechecks":[{"ref":"/rest/config/servicecheck/1028","name":"Check Cisco IPSLA"}],"label1":"IP SLA Tag","arg2":"1","label3":"IP SLA ID","name":"IPSLA","arg4":"","ref":"/rest/config/attribute/130","value":"IP SLA Display Name","arg1":"ALL","label4":"","id":"130","uncommitted":"0"},{"id":"87","uncommitted":"0","servicechecks":[],"label1":"Username","secured4":"0","label3":"Url Path","name":"JENKINS_AUTHENTICATION","arg4":"","ref":"/rest/config/attribute/87","value":"Jenkins Name","label4":"","arg1":"","secured1":"0","secured3":"0","label2":"Password","arg3":"","secured2":"1"},{"id":"88","uncommitted":"0","secured4":"0","servicechecks":[{"ref":"/rest/config/servicecheck/648","name":"Jenkins - Job Status"}],"label1":"Failed Tests Warning Level","name":"JENKINS_JOB","label3":"","arg2":"500","value":"","arg4":"","ref":"/rest/config/attribute/88","arg1":"300","label4":"","secured1":"0","secured3":"0","label2":"Failed Tests Critical Level","arg3":"","secured2":"0"},{"secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":"","uncommitted":"0","id":"89","label3":"","name":"JENKINS_VIEW","arg2":"","servicechecks":[{"ref":"/rest/config/servicecheck/646","name":"Jenkins - View Status"}],"label1":"","secured4":"0","label4":"","arg1":"","value":"","ref":"/rest/config/attribute/89","arg4":""},{"uncommitted":"0","id":"45","label4":"","arg1":"","value":"","ref":"/rest/config/attribute/45","arg4":"","name":"KERBEROS_REALM","label3":"","arg2":"","servicechecks":[],"secured4":"0","label1":"","secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":""},{"arg1":"","label4":"","value":"Kubernetes SSL Certificates","ref":"/rest/config/attribute/96","arg4":"","label3":"Client Key Path","arg2":"","name":"KUBERNETES_CERTIFICATES","servicechecks":[],"label1":"CA Certificate Path","secured4":"0","uncommitted":"0","id":"96","secured2":"0","arg3":"","label2":"Client Certificate Path","secured3":"0","secured1":"0"},{"secured2":"1","arg3":"","label2":"Kubernetes API Bearer Token","secured3":"0","secured1":"0","label4":"","arg1":"","ref":"/rest/config/attribute/92","arg4":"","value":"Cluster Details","name":"KUBERNETES_CLUSTER_DETAILS","label3":"","label1":"Kubernetes API Server Address","servicechecks":[],"secured4":"0","uncommitted":"0","id":"92"},{"label3":"","name":"KUBERNETES_NAMESPACE","arg2":"","servicechecks":[],"secured4":"0","label1":"Kubernetes Namespace","label4":"","arg1":"default","ref":"/rest/config/attribute/93","arg4":"","value":"Namespace","uncommitted":"0","id":"93","label2":"","secured2":"0","arg3":"","secured1":"0","secured3":"0"},{"secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":"","uncommitted":"0","id":"94","label4":"","arg1":"","value":"Node Name","ref":"/rest/config/attribute/94","arg4":"","label3":"","arg2":"","name":"KUBERNETES_NODE_NAME","servicechecks":[],"secured4":"0","label1":"Kubernetes Node Name"},{"uncommitted":"0","id":"95","name":"KUBERNETES_POD_NAME","arg2":"","label3":"","servicechecks":[],"label1":"Kubernetes Pod Name","secured4":"0","arg1":"","label4":"","value":"Pod Name","ref":"/rest/config/attribute/95","arg4":"","secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":""},{"arg3":"","secured2":"1","label2":"Microsoft 365 Client Secret","secured3":"0","secured1":"0","arg4":"","ref":"/rest/config/attribute/101","value":"Microsoft 365 Credentials","arg1":"","label4":"","servicechecks":[],"secured4":"0","label1":"Microsoft 365 Client ID","label3":"Microsoft 365 Tenant ID","name":"MICROSOFT365_CREDENTIALS","id":"101","uncommitted":"0"},{"secured3":"0","secured1":"0","secured2":"1","arg3":"27017","label2":"Password","uncommitted":"0","id":"99","arg1":"guest","label4":"","value":"","ref":"/rest/config/attribute/99","arg4":"","label3":"Port","name":"MONGODB_CREDENTIALS","label1":"Username","servicechecks":[],"secured4":"0"},{"label2":"replicaset","arg3":"","secured2":"0","secured1":"0","secured3":"0","label1":"Collection","servicechecks":[],"secured4":"0","label3":"","arg2":"","name":"MONGODB_DATABASES","value":"","arg4":"","ref":"/rest/config/attribute/100","label4":"","arg1":"","id":"100","uncommitted":"0"},{"servicechecks":[],"label1":"MS Exchange Inactive Components","secured4":"0","label3":"","arg2":"","name":"MS_EXCHANGE_EXPECTED_INACTIVE","arg4":"","ref":"/rest/config/attribute/107","value":"Expected Inactive Components and Services","label4":"","arg1":"","id":"107","uncommitted":"0","label2":"MS Exchange Inactive Services","arg3":"","secured2":"0","secured1":"0","secured3":"0"},{"secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":"","id":"108","uncommitted":"0","arg4":"","ref":"/rest/config/attribute/108","value":".","label4":"","arg1":"","servicechecks":[{"name":"Exchange - Mailflow - Mailbox Queue","ref":"/rest/config/servicecheck/844"}],"label1":"","secured4":"0","label3":"","name":"MS_EXCHANGE_QUEUE_NAME","arg2":""},{"uncommitted":"0","id":"125","name":"MS_IIS_INSTANCE_NAME","label3":"","arg2":"","secured4":"0","servicechecks":[{"ref":"/rest/config/servicecheck/908","name":"Microsoft IIS Agentless - Bytes In/Out"},{"name":"Microsoft IIS Agentless - Connections","ref":"/rest/config/servicecheck/907"},{"ref":"/rest/config/servicecheck/906","name":"Microsoft IIS Agentless - Requests"},{"name":"Microsoft IIS Agentless - Uptime","ref":"/rest/config/servicecheck/905"}],"label1":"","label4":"","arg1":"","value":".","ref":"/rest/config/attribute/125","arg4":"","secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":null},{"secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":"","uncommitted":"0","id":"141","name":"MSSQL_DATABASE_NAME","label3":"","arg2":"","servicechecks":[{"name":"Microsoft SQL Database States Agentless","ref":"/rest/config/servicecheck/1216"}],"label1":"","secured4":"0","arg1":"","label4":"","ref":"/rest/config/attribute/141","arg4":"","value":""},{"uncommitted":"0","id":"142","arg1":"","label4":"","value":"","ref":"/rest/config/attribute/142","arg4":"","name":"MSSQL_INSTANCE_NAME","label3":"","arg2":"","servicechecks":[],"label1":"","secured4":"0","secured3":"0","secured1":"0","secured2":"0","arg3":null,"label2":""},{"uncommitted":"0","id":"98","label4":"","arg1":"","value":"Enter credentials here","ref":"/rest/config/attribute/98","arg4":"","label3":"","name":"MSSQLCREDENTIALS","label1":"Username","servicechecks":[],"secured4":"0","secured3":"0","secured1":"0","secured2":"1","arg3":"","label2":"Password"},{"secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":"","uncommitted":"0","id":"97","arg2":null,"name":"MSSQLDATABASE","label3":"","servicechecks":[{"name":"Microsoft SQL - Active transactions","ref":"/rest/config/servicecheck/702"},{"ref":"/rest/config/servicecheck/698","name":"Microsoft SQL - Database size"},{"ref":"/rest/config/servicecheck/692","name":"Microsoft SQL - Log cache hit"},{"name":"Microsoft SQL - Log flush time","ref":"/rest/config/servicecheck/691"},{"ref":"/rest/config/servicecheck/690","name":"Microsoft SQL - Log growths"},{"ref":"/rest/config/servicecheck/682","name":"Microsoft SQL - Time to connect"},{"ref":"/rest/config/servicecheck/681","name":"Microsoft SQL - Transactions per second"}],"secured4":"0","label1":"","label4":"","arg1":"","ref":"/rest/config/attribute/97","arg4":"","value":""},{"secured3":"0","secured1":"0","arg3":"0","secured2":"1","label2":"Password","id":"126","uncommitted":"0","arg4":"","ref":"/rest/config/attribute/126","value":"Enter credentials here","label4":"CA Path","arg1":"","secured4":"0","servicechecks":[],"label1":"Username","label3":"TLS Enabled","name":"MYSQLCREDENTIALS"},{"id":"10","uncommitted":"0","value":"","arg4":"","ref":"/rest/config/attribute/10","label4":"","arg1":"","label1":"Path to certificate","servicechecks":[],"secured4":"0","name":"NRPE_CERTIFICATES","arg2":"","label3":"Path to CA certificate","secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":"Path to private key"},{"id":"9","uncommitted":"0","arg4":"","ref":"/rest/config/attribute/9","value":"","label4":"","arg1":"ECDH+AESGCM:ECDH+AES256:ECDH+AES128:ADH-AES256-SHA:ADH-AES128-SHA:!MD5:!DSS","servicechecks":[],"secured4":"0","label1":"Cipher list","label3":"","name":"NRPE_CIPHERS","arg2":"","secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":""},{"secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":"","uncommitted":"0","id":"11","label3":"","arg2":"","name":"NRPE_EXTRA_FLAGS","servicechecks":[],"secured4":"0","label1":"","arg1":"","label4":"","value":"","ref":"/rest/config/attribute/11","arg4":""},{"uncommitted":"0","id":"7","label4":"","arg1":"","value":"5666","ref":"/rest/config/attribute/7","arg4":"","label3":"","name":"NRPE_PORT","arg2":"","secured4":"0","servicechecks":[],"label1":"","secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":""},{"uncommitted":"0","id":"54","label4":"","arg1":"","ref":"/rest/config/attribute/54","arg4":"","value":"OpenStack Auth Domain","label3":"","name":"OPENSTACK_AUTH_DOMAIN","arg2":"","servicechecks":[],"label1":"OpenStack Auth Domain Name","secured4":"0","secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":"OpenStack Auth Domain ID"},{"secured2":"0","arg3":"","label2":"","secured3":"0","secured1":"0","label4":"","arg1":"","value":"","ref":"/rest/config/attribute/61","arg4":"","name":"OPENSTACK_AVAILABILITY_ZONE","arg2":"","label3":"","servicechecks":[],"secured4":"0","label1":"","uncommitted":"0","id":"61"},{"label2":"Client Certificate Path","secured2":"0","arg3":"","secured1":"0","secured3":"0","label3":"Client Key Path","name":"OPENSTACK_CERTIFICATES","arg2":"","secured4":"0","servicechecks":[],"label1":"CA Certificate Path","arg1":"","label4":"","ref":"/rest/config/attribute/57","arg4":"","value":"OpenStack SSL Certificates","uncommitted":"0","id":"57"},{"uncommitted":"0","id":"53","label3":"","name":"OPENSTACK_CREDENTIALS","servicechecks":[],"secured4":"0","label1":"OpenStack Username","label4":"","arg1":"","value":"OpenStack Credentials","ref":"/rest/config/attribute/53","arg4":"","secured1":"0","secured3":"0","label2":"OpenStack Password","secured2":"1","arg3":""},{"label2":"","arg3":"","secured2":"0","secured1":"0","secured3":"0","servicechecks":[],"secured4":"0","label1":"","name":"OPENSTACK_HOST_AGGREGATE","label3":"","arg2":"","arg4":"","ref":"/rest/config/attribute/62","value":"","arg1":"","label4":"","id":"62","uncommitted":"0"},{"arg4":"","ref":"/rest/config/attribute/63","value":"","label4":"","arg1":"","servicechecks":[],"secured4":"0","label1":"","label3":"","name":"OPENSTACK_HYPERVISOR_NAME","arg2":"","id":"63","uncommitted":"0","arg3":"","secured2":"0","label2":"","secured3":"0","secured1":"0"},{"secured3":"0","secured1":"0","secured2":"0","arg3":"80","label2":"OpenStack Keystone Address","uncommitted":"0","id":"58","label4":"","arg1":"","ref":"/rest/config/attribute/58","arg4":"","value":"OpenStack Service","label3":"OpenStack Keystone Port","name":"OPENSTACK_KEYSTONE_ENDPOINT","arg2":"","label1":"OpenStack Keystone Protocol","servicechecks":[],"secured4":"0"}]}"#;
 pub const ALL_VARIABLE_CONFIGS_PAGE_3: &str = r#"{"list":[{"uncommitted":"0","id":"56","label4":"","arg1":"","ref":"/rest/config/attribute/56","arg4":"","value":"","label3":"","name":"OPENSTACK_PROJECT","arg2":"","secured4":"0","servicechecks":[],"label1":"","secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":""},{"name":"OPENSTACK_PROJECT_DOMAIN","arg2":"","label3":"","servicechecks":[],"label1":"OpenStack Project Domain Name","secured4":"0","arg1":"","label4":"","value":"OpenStack Project Domain","ref":"/rest/config/attribute/55","arg4":"","uncommitted":"0","id":"55","label2":"OpenStack Project Domain ID","secured2":"0","arg3":"","secured1":"0","secured3":"0"},{"secured2":"0","arg3":"","label2":"","secured3":"0","secured1":"0","label4":"","arg1":"","ref":"/rest/config/attribute/64","arg4":"","value":"","label3":"","name":"OPENSTACK_SERVER_NAME","arg2":"","servicechecks":[],"label1":"","secured4":"0","uncommitted":"0","id":"64"},{"id":"60","uncommitted":"0","arg4":"","ref":"/rest/config/attribute/60","value":"Service Blacklist","label4":"","arg1":"","servicechecks":[],"label1":"OpenStack Service Blacklist","secured4":"0","label3":"","name":"OPENSTACK_SERVICE_BLACKLIST","arg2":"","secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":""},{"label2":"","arg3":"","secured2":"0","secured1":"0","secured3":"0","servicechecks":[{"name":"OpenStack - Keystone - Endpoints Status","ref":"/rest/config/servicecheck/431"}],"label1":"","secured4":"0","arg2":"","label3":"","name":"OPENSTACK_SERVICE_INTERFACE","value":"public","arg4":"","ref":"/rest/config/attribute/59","arg1":"","label4":"","id":"59","uncommitted":"0"},{"label3":"","name":"OPSVIEW_COLLECTOR","arg2":"","servicechecks":[{"name":"Opsview - Collector Status","ref":"/rest/config/servicecheck/1029"}],"secured4":"0","label1":"","label4":"","arg1":"","value":"","ref":"/rest/config/attribute/37","arg4":"","uncommitted":"0","id":"37","label2":"","secured2":"0","arg3":"","secured1":"0","secured3":"0"},{"name":"OPSVIEW_DATASTORE_DATABASE","label3":"","arg2":"","secured4":"0","servicechecks":[{"ref":"/rest/config/servicecheck/1273","name":"Opsview - DataStore - Size"}],"label1":"","label4":"","arg1":"","ref":"/rest/config/attribute/143","arg4":"","value":"opsview-master","uncommitted":"0","id":"143","label2":"","secured2":"0","arg3":"","secured1":"0","secured3":"0"},{"id":"144","uncommitted":"0","secured4":"0","servicechecks":[],"label1":"Username","label3":"Port","name":"OPSVIEW_DATASTORE_SETTINGS","arg4":"couchdb@127.0.0.1","ref":"/rest/config/attribute/144","value":"default","arg1":"opsview","label4":"Node","secured1":"0","secured3":"0","label2":"Password","arg3":"5984","secured2":"1"},{"uncommitted":"0","id":"135","arg1":"opsview","label4":"Stats Path","ref":"/rest/config/attribute/135","arg4":"haproxy?stats","value":"default","name":"OPSVIEW_LOADBALANCER_CREDENTIALS","label3":"Port","label1":"Username","servicechecks":[],"secured4":"0","secured3":"0","secured1":"0","secured2":"1","arg3":"9000","label2":"Password"},{"label2":"","secured2":"0","arg3":"","secured1":"0","secured3":"0","label3":"","arg2":"","name":"OPSVIEW_LOADBALANCER_PROXY","servicechecks":[{"name":"Opsview - Load Balancer - Summary Stats","ref":"/rest/config/servicecheck/1170"}],"secured4":"0","label1":"","label4":"","arg1":"","ref":"/rest/config/attribute/136","arg4":"","value":"","uncommitted":"0","id":"136"},{"arg3":"","secured2":"0","label2":"","secured3":"0","secured1":"0","value":"default","arg4":"","ref":"/rest/config/attribute/131","label4":"","arg1":"-S","secured4":"0","servicechecks":[],"label1":"Opview login check TLS args","label3":"","name":"OPSVIEW_LOGIN_CHECK_TLS_ARGS","arg2":"","id":"131","uncommitted":"0"},{"secured1":"0","secured3":"0","label2":"Password","secured2":"1","arg3":"rabbit@127.0.0.1","uncommitted":"0","id":"145","label3":"Node","name":"OPSVIEW_MESSAGEQUEUE_CREDENTIALS","secured4":"0","servicechecks":[],"label1":"Username","arg1":"opsview","label4":"Port","value":"default","ref":"/rest/config/attribute/145","arg4":"15672"},{"secured3":"0","secured1":"0","arg3":"","secured2":"1","label2":"Password","id":"127","uncommitted":"0","value":"Enter user details below","arg4":"","ref":"/rest/config/attribute/127","label4":"","arg1":"","servicechecks":[],"label1":"Username","secured4":"0","name":"ORACREDENTIALS","label3":""},{"secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":"","uncommitted":"0","id":"6","label3":"","name":"PROCESSES","arg2":"","servicechecks":[],"secured4":"0","label1":"","label4":"","arg1":"","ref":"/rest/config/attribute/6","arg4":"","value":""},{"secured1":"0","secured3":"0","label2":"Password","secured2":"1","arg3":"","uncommitted":"0","id":"132","label3":"Node Name","name":"RABBITMQ_CREDENTIALS","secured4":"0","servicechecks":[{"ref":"/rest/config/servicecheck/1058","name":"RabbitMQ - Context Switch Rate"},{"ref":"/rest/config/servicecheck/1057","name":"RabbitMQ - Disk Free"},{"name":"RabbitMQ - Disk Free Alarm","ref":"/rest/config/servicecheck/1056"},{"name":"RabbitMQ - File Descriptors Left","ref":"/rest/config/servicecheck/1055"},{"ref":"/rest/config/servicecheck/1054","name":"RabbitMQ - File Descriptors Used - Percent"},{"name":"RabbitMQ - IO Read Avg Time","ref":"/rest/config/servicecheck/1053"},{"name":"RabbitMQ - IO Read Count","ref":"/rest/config/servicecheck/1052"},{"ref":"/rest/config/servicecheck/1051","name":"RabbitMQ - IO Seek Avg Time"},{"name":"RabbitMQ - IO sync Avg Time","ref":"/rest/config/servicecheck/1048"},{"name":"RabbitMQ - IO Write Avg Time","ref":"/rest/config/servicecheck/1050"},{"ref":"/rest/config/servicecheck/1049","name":"RabbitMQ - IO Write Count"},{"name":"RabbitMQ - Memory Alarm","ref":"/rest/config/servicecheck/1047"},{"name":"RabbitMQ - Memory Used","ref":"/rest/config/servicecheck/1046"},{"ref":"/rest/config/servicecheck/1045","name":"RabbitMQ - Node Running"},{"ref":"/rest/config/servicecheck/1044","name":"RabbitMQ - Sockets Left"},{"ref":"/rest/config/servicecheck/1043","name":"RabbitMQ - Sockets Used - percent"}],"label1":"Username","arg1":"guest","label4":"Port","value":"","ref":"/rest/config/attribute/132","arg4":"15672"},{"label3":"Client_Secret","name":"SALESFORCE_AUTH","label2":"Client_Key","secured4":"0","servicechecks":[],"label1":"Token","label4":"","secured2":"1","ref":"/rest/config/attribute/13","arg4":"","value":"test","uncommitted":"0","secured1":"1","id":"13","secured3":"1"},{"secured1":"1","secured3":"0","label2":"Password","arg3":"","secured2":"1","id":"14","uncommitted":"0","label1":"Username","servicechecks":[],"secured4":"0","name":"SALESFORCE_LOGIN","label3":"","value":"","ref":"/rest/config/attribute/14","arg4":"","label4":""},{"label2":"","arg3":"","secured2":"0","secured1":"0","secured3":"0","servicechecks":[{"ref":"/rest/config/servicecheck/2","name":"ServiceCloud"}],"label1":"SOQL query","secured4":"0","label3":"","arg2":"","name":"SALESFORCE_SC_QUERY","value":"Friendly name goes here","arg4":"","ref":"/rest/config/attribute/12","label4":"","arg1":"select count() from Case where Priority = 'S3 - Minor Issues' and status = 'Closed'","id":"12","uncommitted":"0"},{"secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":"Client Certificate Path","uncommitted":"0","id":"35","arg1":"","label4":"","value":"Self-monitoring SSL Certificates","ref":"/rest/config/attribute/35","arg4":"","arg2":"","name":"SELF_MONITORING_CERTIFICATES","label3":"Client Key Path","servicechecks":[],"label1":"CA Certificate Path","secured4":"0"},{"secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":"","uncommitted":"0","id":"36","arg2":"","name":"SELF_MONITORING_CONFIG","label3":"","label1":"Minimum message count","servicechecks":[],"secured4":"0","arg1":"10","label4":"","ref":"/rest/config/attribute/36","arg4":"","value":"Self Monitoring Config"},{"secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":"Message count critical threshold","id":"41","uncommitted":"0","arg4":"","ref":"/rest/config/attribute/41","value":"Message Count Thresholds","arg1":"5000","label4":"","secured4":"0","servicechecks":[],"label1":"Message count warning threshold","name":"SELF_MONITORING_COUNT_THRESHOLDS","arg2":"10000","label3":""},{"uncommitted":"0","id":"42","arg1":"50","label4":"","value":"CPU Thresholds","ref":"/rest/config/attribute/42","arg4":"","arg2":"70","label3":"","name":"SELF_MONITORING_CPU_THRESHOLDS","servicechecks":[],"label1":"CPU usage warning threshold","secured4":"0","secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":"CPU usage critical threshold"},{"value":"Deliver Thresholds","arg4":"","ref":"/rest/config/attribute/39","label4":"","arg1":"","servicechecks":[],"secured4":"0","label1":"Deliver Rate warning threshold","name":"SELF_MONITORING_DELIVER_THRESHOLDS","arg2":"","label3":"","id":"39","uncommitted":"0","arg3":"","secured2":"0","label2":"Deliver Rate critical threshold","secured3":"0","secured1":"0"},{"uncommitted":"0","id":"43","arg2":"70","name":"SELF_MONITORING_MEMORY_THRESHOLDS","label3":"Memory used warning threshold in bytes","secured4":"0","servicechecks":[],"label1":"Memory usage percentage warning threshold","label4":"Memory used critical threshold in bytes","arg1":"50","ref":"/rest/config/attribute/43","arg4":"","value":"Memory Thresholds","secured1":"0","secured3":"0","label2":"Memory usage percentage critical threshold","secured2":"0","arg3":""},{"arg4":"","ref":"/rest/config/attribute/38","value":"Publish Thresholds","label4":"","arg1":"","servicechecks":[],"secured4":"0","label1":"Publish Rate warning threshold","name":"SELF_MONITORING_PUBLISH_THRESHOLDS","arg2":"","label3":"","id":"38","uncommitted":"0","arg3":"","secured2":"0","label2":"Publish Rate critical threshold","secured3":"0","secured1":"0"},{"uncommitted":"0","id":"40","arg1":"2.5","label4":"","ref":"/rest/config/attribute/40","arg4":"","value":"Ratio Thresholds","name":"SELF_MONITORING_RATIO_THRESHOLDS","arg2":"5","label3":"","servicechecks":[],"label1":"Publish/Deliver ratio warning threshold","secured4":"0","secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":"Publish/Deliver ratio critical threshold"},{"arg3":"","secured2":"0","label2":"Uptime critical threshold","secured3":"0","secured1":"0","arg4":"","ref":"/rest/config/attribute/44","value":"Uptime Thresholds","arg1":"300:","label4":"","secured4":"0","servicechecks":[],"label1":"Uptime warning threshold","arg2":"45:","name":"SELF_MONITORING_UPTIME_THRESHOLDS","label3":"","id":"44","uncommitted":"0"},{"secured1":"0","secured3":"0","label2":"Password","arg3":"","secured2":"1","id":"8","uncommitted":"0","servicechecks":[],"label1":"Username","secured4":"0","name":"SERVICENOW_SETTINGS","label3":"Instance URL","value":"Service Now Settings","arg4":"","ref":"/rest/config/attribute/8","label4":"Config YML Name","arg1":""},{"id":"182","uncommitted":"0","servicechecks":[],"secured4":"0","label1":"","label3":"","arg2":"","name":"SLICEHOST_KEY","value":"","arg4":"","ref":"/rest/config/attribute/182","arg1":"","label4":"","secured1":"0","secured3":"0","label2":"","arg3":"","secured2":"0"},{"arg3":"","secured2":"0","label2":"","secured3":"0","secured1":"0","arg4":"","ref":"/rest/config/attribute/181","value":"","arg1":"","label4":"","secured4":"0","servicechecks":[{"ref":"/rest/config/servicecheck/1446","name":"Slicehost Bandwidth"},{"ref":"/rest/config/servicecheck/1447","name":"Slicehost Status"}],"label1":"","name":"SLICEHOST_NAME","label3":"","arg2":"","id":"181","uncommitted":"0"},{"secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":"","id":"147","uncommitted":"0","value":"/dev/sda","arg4":"","ref":"/rest/config/attribute/147","arg1":"","label4":"","secured4":"0","servicechecks":[{"name":"SMART HDD Status","ref":"/rest/config/servicecheck/1295"}],"label1":"","label3":"","name":"SMARTDISK","arg2":""},{"label2":"","secured2":"0","arg3":"","secured1":"0","secured3":"0","label3":"","name":"UCS_BLADE_NAME","arg2":"","label1":"","servicechecks":[],"secured4":"0","arg1":"","label4":"","ref":"/rest/config/attribute/77","arg4":"","value":"","uncommitted":"0","id":"77"},{"label2":"Client Certificate Path","arg3":"","secured2":"0","secured1":"0","secured3":"0","servicechecks":[],"label1":"CA Certificate Path","secured4":"0","label3":"Client Key Path","arg2":"","name":"UCS_CERTIFICATES","value":"UCS SSL Certificates","arg4":"","ref":"/rest/config/attribute/80","label4":"","arg1":"","id":"80","uncommitted":"0"},{"id":"78","uncommitted":"0","secured4":"0","servicechecks":[],"label1":"","name":"UCS_CHASSIS_NAME","arg2":"","label3":"","arg4":"","ref":"/rest/config/attribute/78","value":"","label4":"","arg1":"","secured1":"0","secured3":"0","label2":"","arg3":"","secured2":"0"},{"secured3":"1","secured1":"0","secured2":"0","label2":"UCS Username","uncommitted":"0","id":"79","arg1":"","label4":"","ref":"/rest/config/attribute/79","arg4":"","value":"UCS Credentials","label3":"UCS Password","name":"UCS_CREDENTIALS","arg2":"","servicechecks":[],"label1":"UCS API Server URL","secured4":"0"},{"secured2":"0","arg3":"","label2":"UCS Custom Field","secured3":"0","secured1":"0","arg1":"","label4":"","ref":"/rest/config/attribute/81","arg4":"","value":"","label3":"UCS Custom Unit","arg2":"","name":"UCS_CUSTOM_QUERY","servicechecks":[{"ref":"/rest/config/servicecheck/572","name":"UCS - Custom - Query"}],"secured4":"0","label1":"UCS Custom Name","uncommitted":"0","id":"81"},{"secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":"UCS Fault Limit","id":"82","uncommitted":"0","value":"UCS Fault Settings","arg4":"","ref":"/rest/config/attribute/82","arg1":"major","label4":"","servicechecks":[],"secured4":"0","label1":"UCS Fault Severity","name":"UCS_FAULT_SETTINGS","label3":"","arg2":"10"},{"secured1":"0","secured3":"0","label2":"","arg3":"","secured2":"0","id":"83","uncommitted":"0","secured4":"0","servicechecks":[],"label1":"","name":"UCS_RACK_NAME","label3":"","arg2":"","value":"","arg4":"","ref":"/rest/config/attribute/83","arg1":"","label4":""},{"id":"75","uncommitted":"0","value":"Uptrends Check Details","arg4":"","ref":"/rest/config/attribute/75","arg1":"","label4":"","servicechecks":[],"secured4":"0","label1":"Time Period","arg2":"","label3":"","name":"UPTRENDS_CHECK_DETAILS","secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":""},{"label2":"Password","arg3":"","secured2":"1","secured1":"0","secured3":"0","label1":"Username","servicechecks":[],"secured4":"0","name":"UPTRENDS_CREDENTIALS","label3":"","value":"Uptrends Credentials","arg4":"","ref":"/rest/config/attribute/73","arg1":"","label4":"","id":"73","uncommitted":"0"},{"secured1":"0","secured3":"0","label2":"","arg3":"","secured2":"0","id":"74","uncommitted":"0","label1":"","servicechecks":[],"secured4":"0","name":"UPTRENDS_MONITOR_GUID","label3":"","arg2":"","arg4":"","ref":"/rest/config/attribute/74","value":"","arg1":"","label4":""},{"arg2":"","label3":"","name":"URL","servicechecks":[],"secured4":"0","label1":"","label4":"","arg1":"","ref":"/rest/config/attribute/5","arg4":"","value":"","uncommitted":"0","id":"5","label2":"","secured2":"0","arg3":"","secured1":"0","secured3":"0"},{"secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":"","uncommitted":"0","id":"133","label3":"","name":"VARNISHCACHE_INSTANCE","arg2":"","servicechecks":[],"label1":"","secured4":"0","label4":"","arg1":"","value":"","ref":"/rest/config/attribute/133","arg4":""},{"secured1":"0","secured3":"0","label2":"Client Certificate Path","arg3":"","secured2":"0","id":"148","uncommitted":"0","servicechecks":[],"secured4":"0","label1":"CA Certificate Path","name":"VSPHERE_CERTIFICATES","label3":"Client Key Path","arg2":"","value":"vSphere SSL Certificates","arg4":"","ref":"/rest/config/attribute/148","label4":"","arg1":""},{"secured1":"0","secured3":"0","label2":"ESXi Password","secured2":"1","arg3":"","uncommitted":"0","id":"149","label3":"","name":"VSPHERE_ESXI_CREDENTIALS","label1":"ESXi Username","servicechecks":[],"secured4":"0","label4":"","arg1":"","ref":"/rest/config/attribute/149","arg4":"","value":"vSphere Credentials"},{"label2":"","arg3":"","secured2":"0","secured1":"0","secured3":"0","label1":"ESXi Datastore Name","servicechecks":[],"secured4":"0","label3":"","arg2":"","name":"VSPHERE_ESXI_DATASTORE_NAME","arg4":"","ref":"/rest/config/attribute/150","value":"","label4":"","arg1":"","id":"150","uncommitted":"0"},{"secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":"","id":"159","uncommitted":"0","value":"vSphere Expected Element Issues","arg4":"","ref":"/rest/config/attribute/159","arg1":"","label4":"","secured4":"0","servicechecks":[],"label1":"ESXi Expected Hardware Element Issues","name":"VSPHERE_ESXI_EXPECTED_ELEMENT_ISSUES","label3":"","arg2":""},{"secured1":"0","secured3":"0","label2":"","arg3":"","secured2":"0","id":"153","uncommitted":"0","servicechecks":[],"secured4":"0","label1":"ESXi Guest Name","label3":"","name":"VSPHERE_ESXI_GUEST_NAME","arg2":"","arg4":"","ref":"/rest/config/attribute/153","value":"","arg1":"","label4":""},{"label2":"","secured2":"0","arg3":"","secured1":"0","secured3":"0","name":"VSPHERE_ESXI_HOST_NAME","arg2":"","label3":"","label1":"ESXi Host Name","servicechecks":[],"secured4":"0","arg1":"","label4":"","value":"","ref":"/rest/config/attribute/152","arg4":"","uncommitted":"0","id":"152"},{"secured1":"0","secured3":"0","label2":"","arg3":"","secured2":"0","id":"157","uncommitted":"0","label1":"ESXi Expected Inactive NICs","servicechecks":[],"secured4":"0","name":"VSPHERE_ESXI_INACTIVE_NICS","label3":"","arg2":"","arg4":"","ref":"/rest/config/attribute/157","value":"vSphere Expected Inactive NICs","label4":"","arg1":""}],"summary":{"allrows":"179","totalrows":"179","page":"3","rows":"50","totalpages":"4"}}"#;
 pub const ALL_VARIABLE_CONFIGS_PAGE_4: &str = r#"{"summary":{"allrows":"179","page":"4","totalrows":"179","totalpages":"4","rows":"29"},"list":[{"secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":"","uncommitted":"0","id":"158","label4":"","arg1":"","value":"vSphere Expected Inactive VMs","ref":"/rest/config/attribute/158","arg4":"","label3":"","name":"VSPHERE_ESXI_INACTIVE_VMS","arg2":"","servicechecks":[],"label1":"ESXi Expected Inactive VMs","secured4":"0"},{"secured2":"0","arg3":"","label2":"","secured3":"0","secured1":"0","label4":"","arg1":"","ref":"/rest/config/attribute/151","arg4":"","value":"","name":"VSPHERE_ESXI_LINKED_DATASTORE_NAME","label3":"","arg2":"","label1":"ESXi Linked Datastore Name","servicechecks":[{"name":"vSphere - Guest - Datastore Latency","ref":"/rest/config/servicecheck/1338"},{"ref":"/rest/config/servicecheck/1337","name":"vSphere - Guest - Datastore Read/Write"},{"name":"vSphere - Guest - Datastore Usage","ref":"/rest/config/servicecheck/1340"},{"name":"vSphere - Guest - Datastore Used","ref":"/rest/config/servicecheck/1339"},{"ref":"/rest/config/servicecheck/1362","name":"vSphere - Host - Datastore Latency"},{"ref":"/rest/config/servicecheck/1360","name":"vSphere - Host - Datastore Read/Write"},{"name":"vSphere - Host - Datastore Usage","ref":"/rest/config/servicecheck/1364"},{"ref":"/rest/config/servicecheck/1363","name":"vSphere - Host - Datastore Used"},{"ref":"/rest/config/servicecheck/1361","name":"vSphere - Host - Datastore VM Observed Latency"}],"secured4":"0","uncommitted":"0","id":"151"},{"id":"161","uncommitted":"0","secured4":"0","servicechecks":[{"ref":"/rest/config/servicecheck/1323","name":"vSphere - Generic - MOB Path Metric"}],"label1":"Generic MOB Labels","arg2":"","label3":"Generic MOB UOMs","name":"VSPHERE_ESXI_MOB_GENERIC","arg4":"","ref":"/rest/config/attribute/161","value":"","label4":"","arg1":"","secured1":"0","secured3":"0","label2":"Generic MOB Paths","arg3":"","secured2":"0"},{"label4":"Generic PerfCounter UOMs","arg1":"","value":"","ref":"/rest/config/attribute/162","arg4":"","name":"VSPHERE_ESXI_PC_GENERIC","arg2":"","label3":"Generic PerfCounter Counters","label1":"Generic PerfCounter Entities","servicechecks":[{"ref":"/rest/config/servicecheck/1322","name":"vSphere - Generic - Perf Counter Metric"}],"secured4":"0","uncommitted":"0","id":"162","secured2":"0","arg3":"","label2":"Generic PerfCounter Labels","secured3":"0","secured1":"0"},{"label2":"","arg3":"","secured2":"0","secured1":"0","secured3":"0","secured4":"0","servicechecks":[{"ref":"/rest/config/servicecheck/1367","name":"vSphere - Host - Port Group Summary"}],"label1":"ESXi Port Group Name","arg2":"","name":"VSPHERE_ESXI_PORT_GROUP_NAME","label3":"","value":"","arg4":"","ref":"/rest/config/attribute/155","arg1":"","label4":"","id":"155","uncommitted":"0"},{"secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":"","uncommitted":"0","id":"154","arg2":"","name":"VSPHERE_ESXI_RES_POOL_NAME","label3":"","servicechecks":[],"secured4":"0","label1":"","arg1":"","label4":"","ref":"/rest/config/attribute/154","arg4":"","value":""},{"secured1":"0","secured3":"0","label2":"","secured2":"0","arg3":"","uncommitted":"0","id":"156","arg2":"","label3":"","name":"VSPHERE_ESXI_VSWITCH_NAME","servicechecks":[{"ref":"/rest/config/servicecheck/1366","name":"vSphere - Host - Virtual Switch Summary"}],"label1":"ESXi Virtual Switch Name","secured4":"0","label4":"","arg1":"","value":"","ref":"/rest/config/attribute/156","arg4":""},{"secured2":"0","arg3":"","label2":"","secured3":"0","secured1":"0","arg1":"","label4":"","ref":"/rest/config/attribute/170","arg4":"","value":"5","arg2":"","label3":"","name":"VSPHERE_VC_BACKUP_DISP_LIMIT","secured4":"0","servicechecks":[],"label1":"","uncommitted":"0","id":"170"},{"label2":"vCenter Ignored Clusters","secured2":"0","arg3":"","secured1":"0","secured3":"0","arg2":"","label3":"","name":"VSPHERE_VC_CLUSTER_NAME","servicechecks":[{"name":"vSphere - vCenter - Cluster Status","ref":"/rest/config/servicecheck/1320"}],"secured4":"0","label1":"vCenter Cluster Name","label4":"","arg1":"","ref":"/rest/config/attribute/163","arg4":"","value":"","uncommitted":"0","id":"163"},{"id":"160","uncommitted":"0","value":"vCenter Credentials","arg4":"","ref":"/rest/config/attribute/160","label4":"","arg1":"","label1":"vCenter Username","servicechecks":[],"secured4":"0","label3":"vCenter Instance Name","name":"VSPHERE_VC_CREDENTIALS","secured3":"0","secured1":"0","arg3":"","secured2":"1","label2":"vCenter Password"},{"secured2":"0","arg3":"","label2":"vCenter Ignored Datastores","secured3":"0","secured1":"0","arg1":"","label4":"","value":"","ref":"/rest/config/attribute/164","arg4":"","label3":"","name":"VSPHERE_VC_DATASTORE_NAME","arg2":"","secured4":"0","servicechecks":[{"ref":"/rest/config/servicecheck/1316","name":"vSphere - vCenter - Datastore Status"}],"label1":"vCenter Datastore Name","uncommitted":"0","id":"164"},{"arg4":"","ref":"/rest/config/attribute/167","value":"","label4":"","arg1":"","servicechecks":[{"ref":"/rest/config/servicecheck/1315","name":"vSphere - vCenter - Filesystem Status"}],"secured4":"0","label1":"vCenter Filesystem Name","label3":"","name":"VSPHERE_VC_FILESYSTEM_NAME","arg2":"","id":"167","uncommitted":"0","arg3":"","secured2":"0","label2":"vCenter Ignored Filesystems","secured3":"0","secured1":"0"},{"arg1":"","label4":"","value":"","ref":"/rest/config/attribute/165","arg4":"","arg2":"","label3":"","name":"VSPHERE_VC_HOST_NAME","label1":"vCenter Host Name","servicechecks":[{"name":"vSphere - vCenter - Host Status","ref":"/rest/config/servicecheck/1318"}],"secured4":"0","uncommitted":"0","id":"165","secured2":"0","arg3":"","label2":"vCenter Ignored Hosts","secured3":"0","secured1":"0"},{"id":"169","uncommitted":"0","arg4":"","ref":"/rest/config/attribute/169","value":"","arg1":"","label4":"","servicechecks":[{"name":"vSphere - vCenter - NIC Status","ref":"/rest/config/servicecheck/1313"}],"label1":"vCenter NIC Name","secured4":"0","arg2":"","name":"VSPHERE_VC_NIC_NAME","label3":"","secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":"vCenter Ignored NICs"},{"secured2":"0","arg3":"","label2":"vCenter Ignored Services","secured3":"0","secured1":"0","arg1":"","label4":"","ref":"/rest/config/attribute/168","arg4":"","value":"","name":"VSPHERE_VC_SERVICE_NAME","label3":"","arg2":"","secured4":"0","servicechecks":[{"name":"vSphere - vCenter - Service Status","ref":"/rest/config/servicecheck/1314"}],"label1":"vCenter Service Name","uncommitted":"0","id":"168"},{"secured3":"0","secured1":"0","arg3":"","secured2":"0","label2":"vCenter Ignored Virtual Machines","id":"166","uncommitted":"0","arg4":"","ref":"/rest/config/attribute/166","value":"","arg1":"","label4":"","servicechecks":[{"name":"vSphere - vCenter - Virtual Machine Status","ref":"/rest/config/servicecheck/1317"}],"secured4":"0","label1":"vCenter Virtual Machine Name","name":"VSPHERE_VC_VM_NAME","label3":"","arg2":""},{"uncommitted":"0","id":"176","arg1":"","label4":"","ref":"/rest/config/attribute/176","arg4":"","value":"Default Web Site","label3":"","name":"WEB_SERVER_INSTANCE_NAME","arg2":"","servicechecks":[{"name":"Windows WMI - IIS Server Agentless - Transfer stats","ref":"/rest/config/servicecheck/1415"},{"name":"Windows WMI - IIS Server Agentless - User stats","ref":"/rest/config/servicecheck/1414"},{"ref":"/rest/config/servicecheck/1413","name":"Windows WMI - IIS Server Agentless - Web server connections"},{"ref":"/rest/config/servicecheck/1412","name":"Windows WMI - IIS Server Agentless - Web server errors"},{"ref":"/rest/config/servicecheck/1411","name":"Windows WMI - IIS Server Agentless - Web server requests"}],"secured4":"0","label1":"","secured3":"0","secured1":"0","secured2":"0","arg3":null,"label2":""},{"label2":"Password","arg3":"","secured2":"1","secured1":"0","secured3":"0","secured4":"0","servicechecks":[],"label1":"Username","label3":"","name":"WINCREDENTIALS","arg4":"","ref":"/rest/config/attribute/48","value":"","label4":"","arg1":"","id":"48","uncommitted":"0"},{"secured4":"0","servicechecks":[{"name":"Windows Agentless - Drive Space Used","ref":"/rest/config/servicecheck/1397"}],"label1":"","name":"WINDOWS_BASE_DRIVE","label3":"","arg2":"","value":"","arg4":"","ref":"/rest/config/attribute/171","label4":"","arg1":"","id":"171","uncommitted":"0","label2":"","arg3":"","secured2":"0","secured1":"0","secured3":"0"},{"arg3":"","secured2":"0","label2":"","secured3":"0","secured1":"0","arg4":"","ref":"/rest/config/attribute/173","value":"","label4":"","arg1":"","servicechecks":[{"name":"Windows Agentless - File Size","ref":"/rest/config/servicecheck/1395"}],"secured4":"0","label1":"","name":"WINDOWS_BASE_FILE","label3":"","arg2":"","id":"173","uncommitted":"0"},{"uncommitted":"0","id":"172","label4":"","arg1":"","value":"","ref":"/rest/config/attribute/172","arg4":"","label3":"","name":"WINDOWS_BASE_PATH","arg2":"","servicechecks":[{"name":"Windows Agentless - Folder Size","ref":"/rest/config/servicecheck/1394"}],"secured4":"0","label1":"","secured3":"0","secured1":"0","secured2":"0","arg3":"","label2":""},{"arg1":"","label4":"","value":"","ref":"/rest/config/attribute/174","arg4":"","name":"WINDOWS_BASE_PROCESS","label3":"","arg2":"","label1":"","servicechecks":[{"name":"Windows Agentless - Process Count","ref":"/rest/config/servicecheck/1388"},{"name":"Windows Agentless - Process CPU Time","ref":"/rest/config/servicecheck/1389"}],"secured4":"0","uncommitted":"0","id":"174","secured2":"0","arg3":"","label2":"","secured3":"0","secured1":"0"},{"arg1":"MinWarnFree=10% MinCritFree=5%","label4":"","value":"","ref":"/rest/config/attribute/175","arg4":"","name":"WINDOWS_DRIVE","label3":"","arg2":"","servicechecks":[{"name":"Windows - Drive","ref":"/rest/config/servicecheck/1400"}],"label1":"","secured4":"0","uncommitted":"0","id":"175","secured2":"0","arg3":"","label2":"","secured3":"0","secured1":"0"},{"label3":"","name":"WINLDAP_CREDENTIALS","servicechecks":[],"label1":"Username","secured4":"0","label4":"","arg1":"","value":"Windows LDAP credentials","ref":"/rest/config/attribute/103","arg4":"","uncommitted":"0","id":"103","label2":"Password","secured2":"1","arg3":"","secured1":"0","secured3":"0"},{"secured1":"0","secured3":"0","label2":"","arg3":"","secured2":"0","id":"47","uncommitted":"0","servicechecks":[{"name":"Windows WMI - Base Agentless - CPU info per process","ref":"/rest/config/servicecheck/357"},{"ref":"/rest/config/servicecheck/356","name":"Windows WMI - Base Agentless - CPU usage per process"},{"name":"Windows WMI - Base Agentless - Memory usage per process","ref":"/rest/config/servicecheck/352"}],"secured4":"0","label1":"","name":"WINPROCESS","label3":"","arg2":"","value":"","arg4":"","ref":"/rest/config/attribute/47","arg1":"","label4":""},{"id":"46","uncommitted":"0","arg4":"","ref":"/rest/config/attribute/46","value":"basic","arg1":"","label4":"Extra Args","servicechecks":[],"secured4":"0","label1":"Username","name":"WINRM_TRANSPORT","label3":"Scheme","secured3":"0","secured1":"0","arg3":"https","secured2":"1","label2":"Password"},{"secured4":"0","servicechecks":[{"name":"Windows WMI - Base Agentless - Service status","ref":"/rest/config/servicecheck/348"}],"label1":"","label3":"","name":"WINSERVICE","arg2":"","arg4":"","ref":"/rest/config/attribute/49","value":"","arg1":"","label4":"","id":"49","uncommitted":"0","label2":"","arg3":"","secured2":"0","secured1":"0","secured3":"0"},{"secured1":"0","secured3":"0","label2":"","arg3":"","secured2":"0","id":"50","uncommitted":"0","servicechecks":[],"secured4":"0","label1":"","label3":"","name":"WMI_NETWORK_INTERFACE_NAME","arg2":"","value":".","arg4":"","ref":"/rest/config/attribute/50","label4":"","arg1":""},{"arg2":"","name":"ZOOKEEPER_PORT","label3":"","servicechecks":[],"secured4":"0","label1":"","arg1":"","label4":"","value":"2181","ref":"/rest/config/attribute/102","arg4":"","uncommitted":"0","id":"102","label2":"","secured2":"0","arg3":"","secured1":"0","secured3":"0"}]}"#;
+pub const EVENTS_PAGE_1: &str = r#"{"summary":{"rows":"2","allrows":"3","page":"1","totalpages":"2","totalrows":"3"},"list":[{"id":"101","time":"2024-03-01T12:30:00Z","hostname":"web01","servicename":"HTTP","host_group":"Web","objecttype":"service","state_type":"hard","state":"CRITICAL","prior_state":"OK","output":"Connection refused","acknowledged":"0","downtime":"0"},{"id":"102","time":"2024-03-01T12:45:00Z","hostname":"web01","servicename":"HTTP","host_group":"Web","objecttype":"service","state_type":"hard","state":"OK","prior_state":"CRITICAL","output":"HTTP OK: HTTP/1.1 200 OK","acknowledged":"1","downtime":"0"}]}"#;
+pub const EVENTS_PAGE_2: &str = r#"{"summary":{"rows":"1","allrows":"3","page":"2","totalpages":"2","totalrows":"3"},"list":[{"id":"103","time":"2024-03-01T13:00:00Z","hostname":"web01","servicename":null,"host_group":"Web","objecttype":"host","state_type":"hard","state":"DOWN","prior_state":"UP","output":"PING CRITICAL - Packet loss = 100%","acknowledged":"0","downtime":"1"}]}"#;
+pub const PERFORMANCE_METRICS: &str = r#"{"summary":{"rows":"2","allrows":"2","page":"1","totalpages":"1","totalrows":"2"},"list":[{"hostname":"web01","servicename":"HTTP","metricname":"time","uom":"s"},{"hostname":"web01","servicename":"HTTP","metricname":"size","uom":"B"}]}"#;
+pub const GRAPH_TIME_SERIES: &str = r#"{"list":[{"description":"web01::HTTP::time","uom":"s","data":[[1709296200,0.25],[1709296500,0.35],[1709296800,null],[1709297100,0.5]]}]}"#;
+pub const RELOAD_STATUS_PENDING: &str = r#"{"auditlog_entries":"3","average_duration":"10","configuration_status":"pending","lastupdated":"1702453777","messages":[],"server_status":"0"}"#;
+pub const RELOAD_STATUS_RELOADING: &str = r#"{"auditlog_entries":"3","average_duration":"10","configuration_status":"pending","lastupdated":"1702453777","messages":[],"server_status":"1"}"#;
+pub const RELOAD_STATUS_WITH_WARNINGS: &str = r#"{"auditlog_entries":"0","average_duration":"10","configuration_status":"uptodate","lastupdated":"1702453790","messages":[{"severity":"warning","detail":"Host web01 has no service checks","monitoringserver":"Master Monitoring Server"}],"server_status":"4"}"#;
+pub const AUDITLOG_PAGE: &str = r#"{"list":[{"datetime":"2024-03-01 12:00:00","id":"101","reloadid":null,"text":"Created host 'web01' (/rest/config/host/12)","username":"admin"},{"datetime":"2024-03-01 12:05:00","id":"102","reloadid":null,"text":"Updated role 'Operators' (/rest/config/role/4)","username":"admin"},{"datetime":"2024-03-01 12:10:00","id":"103","reloadid":"7","text":"Deleted host 'web02' (/rest/config/host/13)","username":"admin"}],"summary":{"allrows":"3","page":"1","rows":"3","totalpages":"1","totalrows":"3"}}"#;
+pub const OPSPACK_LIST: &str = r#"{"list":[{"description":"Monitor Apache","name":"application-apache","version":"2.1"},{"name":"os-linux-base","version":3}],"summary":{"allrows":"2","page":"1","rows":"all","totalpages":"1","totalrows":"2"}}"#;
+pub const OPSPACK_IMPORT_SUCCESS: &str = r#"{"success":1,"name":"application-example","version":"1.0","messages":["Imported 1 host template"]}"#;
+pub const OPSPACK_IMPORT_ALREADY_INSTALLED: &str =
+    r#"{"message":"Opspack application-example is already installed"}"#;
+pub const TEST_SERVICE_CHECK_RESULT: &str = r#"{"exit_code":"1","output":"HTTP WARNING: HTTP/1.1 200 OK - 1.2 second response time|time=1.2s;1;2;0"}"#;
+pub const CREATED_HOSTGROUP_EU: &str = r#"{"object":{"name":"EU","id":"12","ref":"/rest/config/hostgroup/12","matpath":"Opsview,EU,","is_leaf":"1","parent":{"name":"Opsview","matpath":"Opsview,","ref":"/rest/config/hostgroup/1"},"children":[],"hosts":[],"uncommitted":"1"}}"#;
+pub const CREATED_HOSTGROUP_WEB: &str = r#"{"object":{"name":"Web","id":"13","ref":"/rest/config/hostgroup/13","matpath":"Opsview,EU,Web,","is_leaf":"1","parent":{"name":"EU","matpath":"Opsview,EU,","ref":"/rest/config/hostgroup/12"},"children":[],"hosts":[],"uncommitted":"1"}}"#;
 // pub const OBJECT_ENDPOINTS: [&str; 18] = [
 //     "/config/bsmcomponent",
 //     "/config/bsmservice",
@@ -1350,3 +1350,538 @@ async fn test_get_all_variable_configs_mock() -> Result<(), OpsviewClientError>
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_get_events_mock() -> Result<(), OpsviewError> {
+    use futures::TryStreamExt;
+    use opsview::event::*;
+
+    let mut s = setup_mock_server().await;
+
+    s.mock("GET", "/rest/event?hostname=web01&statetype=hard")
+        .with_status(200)
+        .with_body(EVENTS_PAGE_1)
+        .create_async()
+        .await;
+
+    s.mock("GET", "/rest/event?hostname=web01&statetype=hard&page=2")
+        .with_status(200)
+        .with_body(EVENTS_PAGE_2)
+        .create_async()
+        .await;
+
+    let ov = OpsviewClient::builder()
+        .url(&s.url())
+        .username("username")
+        .password("password")
+        .ignore_cert(false)
+        .build()
+        .await?;
+
+    let query = EventQuery::builder()
+        .hostname("web01")
+        .state_type(StateType::Hard)
+        .build()?;
+
+    let events: Vec<Event> = ov.get_events(&query).try_collect().await?;
+
+    assert_eq!(events.len(), 3);
+    assert_eq!(
+        events[0].state_change,
+        StateChange::Service {
+            previous: Some(ServiceCheckState::Ok),
+            new: ServiceCheckState::Critical,
+        }
+    );
+    assert!(events[1].acknowledged);
+    assert_eq!(
+        events[2].state_change,
+        StateChange::Host {
+            previous: Some(HostState::Up),
+            new: HostState::Down,
+        }
+    );
+    assert!(events[2].downtime);
+
+    assert_eq!(ov.get_all_events(&query).await?, events);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_performance_metrics_and_time_series_mock() -> Result<(), OpsviewError> {
+    use chrono::DateTime;
+    use mockito::Matcher;
+    use opsview::graph::*;
+
+    let mut s = setup_mock_server().await;
+
+    s.mock("GET", "/rest/runtime/performancemetric")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("hostname".into(), "web01".into()),
+            Matcher::UrlEncoded("servicename".into(), "HTTP".into()),
+        ]))
+        .with_status(200)
+        .with_body(PERFORMANCE_METRICS)
+        .create_async()
+        .await;
+
+    s.mock("GET", "/rest/graph")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("hsm".into(), "web01::HTTP::time".into()),
+            Matcher::UrlEncoded("start".into(), "1709296200".into()),
+            Matcher::UrlEncoded("end".into(), "1709297100".into()),
+            Matcher::UrlEncoded("resolution".into(), "600".into()),
+        ]))
+        .with_status(200)
+        .with_body(GRAPH_TIME_SERIES)
+        .create_async()
+        .await;
+
+    // A time series for another metric is not mistaken for the requested one.
+    s.mock("GET", "/rest/graph")
+        .match_query(Matcher::UrlEncoded(
+            "hsm".into(),
+            "web01::HTTP::size".into(),
+        ))
+        .with_status(200)
+        .with_body(GRAPH_TIME_SERIES)
+        .create_async()
+        .await;
+
+    let ov = OpsviewClient::builder()
+        .url(&s.url())
+        .username("username")
+        .password("password")
+        .ignore_cert(false)
+        .build()
+        .await?;
+
+    let metrics = ov.get_performance_metrics("web01", Some("HTTP")).await?;
+
+    assert_eq!(metrics.len(), 2);
+    assert_eq!(metrics[0].hsm(), "web01::HTTP::time");
+    assert_eq!(metrics[0].uom, Some("s".to_string()));
+
+    let query = MetricQuery::builder()
+        .metric(metrics[0].clone())
+        .start_time(DateTime::from_timestamp(1709296200, 0).unwrap())
+        .end_time(DateTime::from_timestamp(1709297100, 0).unwrap())
+        .resolution(600)
+        .build()?;
+
+    let series = ov.get_time_series(&query).await?;
+
+    assert_eq!(series.len(), 1);
+    assert_eq!(series[0].unit, Some("s".to_string()));
+    assert_eq!(
+        series[0].points.iter().map(|p| p.value).collect::<Vec<_>>(),
+        vec![Some(0.3), Some(0.5)]
+    );
+
+    let query = MetricQuery::builder()
+        .metric(metrics[1].clone())
+        .start_time(DateTime::from_timestamp(1709296200, 0).unwrap())
+        .end_time(DateTime::from_timestamp(1709297100, 0).unwrap())
+        .build()?;
+
+    assert_eq!(
+        ov.get_time_series(&query).await,
+        Err(OpsviewClientError::MetricNotReturned(
+            "web01::HTTP::size".to_string()
+        ))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_apply_changes_and_wait_mock() -> Result<(), OpsviewClientError> {
+    use futures::TryStreamExt;
+    use opsview::reload::*;
+    use std::time::Duration;
+
+    let mut s = setup_mock_server().await;
+
+    s.mock("POST", "/rest/reload?asynchronous=1")
+        .with_status(200)
+        .with_body(RELOAD_STATUS_RELOADING)
+        .create_async()
+        .await;
+
+    s.mock("GET", "/rest/reload")
+        .with_status(200)
+        .with_body(RELOAD_STATUS_RELOADING)
+        .expect(1)
+        .create_async()
+        .await;
+
+    s.mock("GET", "/rest/reload")
+        .with_status(200)
+        .with_body(RELOAD_STATUS_WITH_WARNINGS)
+        .create_async()
+        .await;
+
+    let ov = OpsviewClient::builder()
+        .url(&s.url())
+        .username("username")
+        .password("password")
+        .ignore_cert(false)
+        .build()
+        .await?;
+
+    let progress: Vec<ReloadProgress> = ov
+        .apply_changes_and_wait(Duration::from_millis(10), Duration::from_secs(10))
+        .try_collect()
+        .await?;
+
+    assert_eq!(progress.len(), 3);
+    assert!(matches!(progress[0], ReloadProgress::Reloading { .. }));
+    assert!(matches!(progress[1], ReloadProgress::Reloading { .. }));
+
+    match &progress[2] {
+        ReloadProgress::Finished { status, outcome } => {
+            assert_eq!(status.server_status, ServerStatus::RunningWithWarnings);
+            assert!(outcome.is_success());
+            assert_eq!(
+                outcome,
+                &ReloadOutcome::SuccessWithWarnings(status.messages.clone())
+            );
+        }
+        p => panic!("Expected the reload to be finished, got {:?}", p),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_apply_changes_and_wait_before_reload_starts_mock() -> Result<(), OpsviewClientError> {
+    use futures::TryStreamExt;
+    use opsview::reload::*;
+    use std::time::Duration;
+
+    let mut s = setup_mock_server().await;
+
+    // The response to the request still describes the state before the reload.
+    s.mock("POST", "/rest/reload?asynchronous=1")
+        .with_status(200)
+        .with_body(RELOAD_STATUS_PENDING)
+        .create_async()
+        .await;
+
+    s.mock("GET", "/rest/reload")
+        .with_status(200)
+        .with_body(RELOAD_STATUS_RELOADING)
+        .expect(1)
+        .create_async()
+        .await;
+
+    s.mock("GET", "/rest/reload")
+        .with_status(200)
+        .with_body(RELOAD_STATUS_WITH_WARNINGS)
+        .create_async()
+        .await;
+
+    let ov = OpsviewClient::builder()
+        .url(&s.url())
+        .username("username")
+        .password("password")
+        .ignore_cert(false)
+        .build()
+        .await?;
+
+    let progress: Vec<ReloadProgress> = ov
+        .apply_changes_and_wait(Duration::from_millis(10), Duration::from_secs(10))
+        .try_collect()
+        .await?;
+
+    assert_eq!(progress.len(), 3);
+    assert!(matches!(progress[0], ReloadProgress::Reloading { .. }));
+    assert!(matches!(progress[1], ReloadProgress::Reloading { .. }));
+    assert!(matches!(
+        progress[2],
+        ReloadProgress::Finished {
+            outcome: ReloadOutcome::SuccessWithWarnings(_),
+            ..
+        }
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_apply_changes_and_wait_reload_never_starts_mock() -> Result<(), OpsviewClientError> {
+    use futures::TryStreamExt;
+    use opsview::reload::*;
+    use std::time::Duration;
+
+    let mut s = setup_mock_server().await;
+
+    s.mock("POST", "/rest/reload?asynchronous=1")
+        .with_status(200)
+        .with_body(RELOAD_STATUS_PENDING)
+        .create_async()
+        .await;
+
+    s.mock("GET", "/rest/reload")
+        .with_status(200)
+        .with_body(RELOAD_STATUS_PENDING)
+        .create_async()
+        .await;
+
+    let ov = OpsviewClient::builder()
+        .url(&s.url())
+        .username("username")
+        .password("password")
+        .ignore_cert(false)
+        .build()
+        .await?;
+
+    let max_wait = Duration::from_millis(50);
+    let result: Result<Vec<ReloadProgress>, OpsviewClientError> = ov
+        .apply_changes_and_wait(Duration::from_millis(10), max_wait)
+        .try_collect()
+        .await;
+
+    assert_eq!(result, Err(OpsviewClientError::ReloadTimedOut(max_wait)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_audit_log_mock() -> Result<(), OpsviewError> {
+    use opsview::auditlog::*;
+
+    let mut s = setup_mock_server().await;
+
+    s.mock("GET", "/rest/auditlog")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("order".into(), "datetime".into()),
+            mockito::Matcher::UrlEncoded(
+                "json_filter".into(),
+                r#"{"-and":[{"username":"admin"}]}"#.into(),
+            ),
+        ]))
+        .with_status(200)
+        .with_body(AUDITLOG_PAGE)
+        .create_async()
+        .await;
+
+    let ov = OpsviewClient::builder()
+        .url(&s.url())
+        .username("username")
+        .password("password")
+        .ignore_cert(false)
+        .build()
+        .await?;
+
+    let query = AuditLogQuery::builder()
+        .username("admin")
+        .object_type(ConfigObjectType::Host)
+        .build()?;
+
+    let entries = ov.get_all_audit_log_entries(&query).await?;
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].action, AuditAction::Create);
+    assert_eq!(entries[1].action, AuditAction::Delete);
+    assert_eq!(entries[1].reload_id, Some(7));
+    assert_eq!(
+        entries[1].object.as_ref().and_then(|o| o.ref_()),
+        Some("/rest/config/host/13".to_string())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_opspack_import_export_mock() -> Result<(), OpsviewError> {
+    use opsview::opspack::*;
+
+    let mut s = setup_mock_server().await;
+
+    let mut opspack = Opspack::new(OpspackInfo::new("application-example", "1.0")?);
+    opspack
+        .host_templates
+        .add(HostTemplate::minimal("Application - Example")?);
+    let archive = opspack.to_bytes()?;
+
+    s.mock("GET", "/rest/opspack?rows=all")
+        .with_status(200)
+        .with_body(OPSPACK_LIST)
+        .create_async()
+        .await;
+
+    s.mock("GET", "/rest/opspack/export?name=application-example")
+        .with_status(200)
+        .with_body(archive.clone())
+        .create_async()
+        .await;
+
+    s.mock("GET", "/rest/opspack/export?name=missing")
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let import_mock = s
+        .mock("POST", "/rest/opspack/import")
+        .with_status(200)
+        .with_body(OPSPACK_IMPORT_SUCCESS)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let ov = OpsviewClient::builder()
+        .url(&s.url())
+        .username("username")
+        .password("password")
+        .ignore_cert(false)
+        .build()
+        .await?;
+
+    let installed = ov.list_opspacks().await?;
+    assert_eq!(installed.len(), 2);
+    assert_eq!(installed[0].version.as_deref(), Some("2.1"));
+    assert_eq!(installed[1].version.as_deref(), Some("3"));
+
+    assert_eq!(
+        ov.export_opspack_archive("application-example").await?,
+        archive
+    );
+    assert_eq!(ov.export_opspack("application-example").await?, opspack);
+    assert_eq!(
+        ov.export_opspack("missing").await,
+        Err(OpsviewClientError::OpspackNotFound("missing".to_string()))
+    );
+
+    let result = ov.import_opspack(&opspack, false).await?;
+    assert_eq!(result.name, "application-example");
+    assert_eq!(result.version.as_deref(), Some("1.0"));
+    assert_eq!(result.messages, vec!["Imported 1 host template"]);
+    import_mock.assert_async().await;
+
+    s.mock("POST", "/rest/opspack/import")
+        .with_status(409)
+        .with_body(OPSPACK_IMPORT_ALREADY_INSTALLED)
+        .create_async()
+        .await;
+
+    assert_eq!(
+        ov.import_opspack(&opspack, false).await,
+        Err(OpsviewClientError::OpspackAlreadyInstalled(
+            "application-example".to_string()
+        ))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_test_service_check_mock() -> Result<(), OpsviewError> {
+    use opsview::state::ServiceCheckState;
+    use opsview::testcheck::*;
+
+    let mut s = setup_mock_server().await;
+
+    let mock = s
+        .mock("POST", "/rest/testservicecheck")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "plugin": "check_http",
+            "args": "-H 192.168.1.10 -w 1 -c 2",
+            "hostname": "web01",
+            "monitored_by": "Cluster 1"
+        })))
+        .with_status(200)
+        .with_body(TEST_SERVICE_CHECK_RESULT)
+        .create_async()
+        .await;
+
+    let ov = OpsviewClient::builder()
+        .url(&s.url())
+        .username("username")
+        .password("password")
+        .ignore_cert(false)
+        .build()
+        .await?;
+
+    let mut host = Host::minimal("web01")?;
+    host.ip = Some("192.168.1.10".to_string());
+
+    let test = ServiceCheckTest::builder()
+        .plugin_name("check_http")
+        .args("-H %HOSTADDRESS% -w 1 -c 2")
+        .host(&host)
+        .monitoring_cluster("Cluster 1")
+        .build()?;
+
+    let result = ov.test_service_check(&test).await?;
+    mock.assert_async().await;
+
+    assert_eq!(result.state, ServiceCheckState::Warning);
+    assert_eq!(result.exit_code, 1);
+    assert_eq!(result.output.perfdata.len(), 1);
+    assert_eq!(result.output.perfdata[0].value, Some(1.2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_hostgroup_tree_ensure_path_mock() -> Result<(), OpsviewError> {
+    use opsview::hostgrouptree::HostGroupTree;
+
+    let mut s = setup_mock_server().await;
+
+    let create_eu = s
+        .mock("POST", "/rest/config/hostgroup")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "object": {"name": "EU", "parent": {"name": "Opsview"}}
+        })))
+        .with_status(200)
+        .with_body(CREATED_HOSTGROUP_EU)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let create_web = s
+        .mock("POST", "/rest/config/hostgroup")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "object": {"name": "Web", "parent": {"name": "EU", "matpath": "Opsview,EU,"}}
+        })))
+        .with_status(200)
+        .with_body(CREATED_HOSTGROUP_WEB)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let ov = OpsviewClient::builder()
+        .url(&s.url())
+        .username("username")
+        .password("password")
+        .ignore_cert(false)
+        .build()
+        .await?;
+
+    let mut root = HostGroup::minimal("Opsview")?;
+    root.matpath = Some("Opsview,".to_string());
+    root.ref_ = Some("/rest/config/hostgroup/1".to_string());
+    let groups: ConfigObjectMap<HostGroup> = vec![root].into_iter().collect();
+    let mut tree = HostGroupTree::from_map(&groups);
+
+    let web = tree.ensure_path(&ov, "Opsview,EU,Web").await?;
+    create_eu.assert_async().await;
+    create_web.assert_async().await;
+
+    assert_eq!(web.id, Some(13));
+    assert_eq!(tree.len(), 3);
+    assert_eq!(
+        tree.get("Opsview,EU").unwrap().ref_.as_deref(),
+        Some("/rest/config/hostgroup/12")
+    );
+
+    // Nothing is created when the path already exists.
+    let existing = tree.ensure_path(&ov, "Opsview,EU,Web,").await?;
+    assert_eq!(existing, web);
+    create_web.assert_async().await;
+
+    Ok(())
+}
@@ -31,9 +31,9 @@ fn handle_join_error(e: JoinError) -> std::io::Error {
     if e.is_cancelled() {
         std::io::Error::new(std::io::ErrorKind::Interrupted, "Task was cancelled")
     } else if e.is_panic() {
-        std::io::Error::new(std::io::ErrorKind::Other, "Task panicked")
+        std::io::Error::other("Task panicked")
     } else {
-        std::io::Error::new(std::io::ErrorKind::Other, e)
+        std::io::Error::other(e)
     }
 }
 